@@ -7,6 +7,11 @@ extern crate alloc;
 use mixed_num::{Cartesian, Polar};
 
 use crate::vec::Vec;
+use crate::vec::spectral::{PsdScaling, PsdSides};
+use crate::vec::convolution::ConvolutionMode;
+use crate::vec::correlation::XcorrScaling;
+use crate::vec::noise::Prng;
+use crate::vec::waveform::{ChirpSweep, MultitonePhases};
 
 // Generic vector operations.
 pub trait Len {
@@ -69,6 +74,67 @@ pub trait Zeros<T> {
     fn zeros(len: usize) -> Vec<T>;
 }
 
+pub trait Wgn<T> {
+    /// Create a vector of White Gaussian Noise (WGN) of power `power`.
+    fn wgn( len: usize, power: T ) -> Self;
+    /// Create a vector of White Gaussian Noise (WGN) of power `power`, drawn from `rng`.
+    fn wgn_with_rng( len: usize, power: T, rng: &mut Prng ) -> Self;
+}
+
+pub trait ColoredNoise<T> {
+    /// Create a vector of pink noise of power `power`, with a Power Spectral Density (PSD) proportional to `1/f`, drawn from `rng`.
+    fn pink_noise( len: usize, power: T, rng: &mut Prng ) -> Self;
+    /// Create a vector of brown noise of power `power`, with a Power Spectral Density (PSD) proportional to `1/f²`, drawn from `rng`.
+    fn brown_noise( len: usize, power: T, rng: &mut Prng ) -> Self;
+    /// Create a vector of noise of power `power`, with a Power Spectral Density (PSD) shaped by `psd_mask`, drawn from `rng`.
+    fn shaped_noise( len: usize, psd_mask: &Vec<T>, power: T, rng: &mut Prng ) -> Self;
+}
+
+pub trait Chirp<T> {
+    /// Create a chirp of `len` samples, sweeping from the angular frequency `start_freq` to `stop_freq` in radians per sample.
+    fn chirp( len: usize, start_freq: T, stop_freq: T, sweep: ChirpSweep, phase_rad: T ) -> Self;
+}
+
+pub trait Multitone<T> {
+    /// Create a sum of tones with the angular frequencies `frequencies` in radians per sample, and the amplitudes `amplitudes`.
+    /// Schroeder's phases only give a low crest factor for equally spaced, harmonically related tones.
+    fn multitone( len: usize, frequencies: &Vec<T>, amplitudes: &Vec<T>, phases: MultitonePhases<T> ) -> Self;
+}
+
+pub trait BandLimitedWaveform<T> {
+    /// Create a square wave of unit amplitude, with the harmonics above the Nyquist frequency removed.
+    fn square( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self;
+    /// Create a sawtooth wave of unit amplitude, with the harmonics above the Nyquist frequency removed.
+    fn sawtooth( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self;
+    /// Create a triangle wave of unit amplitude, with the harmonics above the Nyquist frequency removed.
+    fn triangle( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self;
+}
+
+pub trait Pulses<T> {
+    /// Create a unit impulse at the index `position`.
+    fn impulse( len: usize, position: usize ) -> Self;
+    /// Create a unit step from the index `position`.
+    fn step( len: usize, position: usize ) -> Self;
+    /// Create a train of rectangular pulses of `width` samples, every `period` samples.
+    fn pulse_train( len: usize, period: usize, width: usize ) -> Self;
+}
+
+pub trait PnSequence<T> {
+    /// Map the bits `bits` to the bipolar values `0 → 1` and `1 → -1`.
+    fn from_bits( bits: &Vec<u8> ) -> Self;
+    /// Create one period of the bipolar maximal-length sequence (m-sequence) of degree `degree`.
+    fn m_sequence( degree: u32 ) -> Self;
+    /// Create one period of the bipolar Gold code `index` of degree `degree`.
+    fn gold( degree: u32, index: usize ) -> Self;
+    /// Create one period of the bipolar code `index` of the small Kasami family of degree `degree`.
+    fn kasami( degree: u32, index: usize ) -> Self;
+}
+
+pub trait ZadoffChu<T> {
+    /// Create the Zadoff–Chu sequence of length `len` and root `root`.
+    fn zadoff_chu( len: usize, root: usize ) -> Self;
+}
+
 pub trait LinRange<T>{
     /// Generate a 1D vector of evenly spaced numbers of type T.
     fn lin_range( start:T, stop:T, num:usize ) -> Self;
@@ -129,6 +195,13 @@ pub trait WrapPhase {
     fn wrap_phase( &mut self );
 }
 
+pub trait AddAwgn<T> {
+    /// Add White Gaussian Noise (WGN) at a Signal-to-Noise Ratio (SNR) of `snr_db`. Computed-in-place.
+    fn add_awgn( &mut self, snr_db: T );
+    /// Add White Gaussian Noise (WGN), drawn from `rng`, at a Signal-to-Noise Ratio (SNR) of `snr_db`. Computed-in-place.
+    fn add_awgn_with_rng( &mut self, snr_db: T, rng: &mut Prng );
+}
+
 pub trait Clip<T> {
     /// Clip all values to the `{lower_limit, uppeer_limit}` range.
     fn clip( &mut self, lower_limit:T, uppeer_limit:T );
@@ -159,6 +232,16 @@ pub trait Ifft {
     fn ifft( &mut self );
 }
 
+pub trait Rfft<T> {
+    /// Compute the FFT of the real vector `self`. Returns the `N/2+1` non-negative frequency bins.
+    fn rfft( &self ) -> Vec<Cartesian<T>>;
+}
+
+pub trait Irfft<T> {
+    /// Compute the real inverse FFT of length `len` from the `len/2+1` non-negative frequency bins in `self`.
+    fn irfft( &self, len: usize ) -> Vec<T>;
+}
+
 pub trait HilbertTransform<T>
 {
     /// Compute the Discrete Hilbert Transform (DHT).
@@ -244,6 +327,55 @@ pub trait Energy<T>{
     fn energy( &self ) -> T;
 }
 
+pub trait Variance<T>{
+    /// Estimate the variance of the vector, with the divisor `N-ddof`.
+    fn variance( &self, ddof: usize ) -> T;
+    /// Estimate the standard deviation of the vector, with the divisor `N-ddof`.
+    fn std( &self, ddof: usize ) -> T;
+}
+
+pub trait Rms<T>{
+    /// Root Mean Square (RMS) of vector.
+    fn rms( &self ) -> T;
+}
+
+pub trait ArgMax<T>{
+    /// Return the index of the highest item in the vector.
+    fn argmax( &self ) -> usize;
+}
+
+pub trait ArgMin<T>{
+    /// Return the index of the lowest item in the vector.
+    fn argmin( &self ) -> usize;
+}
+
+pub trait Median<T>{
+    /// Median of vector.
+    fn median( &self ) -> T;
+}
+
+pub trait Percentile<T>{
+    /// The `p`-th percentile of the vector, for `p` in `0..=100`.
+    fn percentile( &self, p: T ) -> T;
+    /// The `q`-th quantile of the vector, for `q` in `0..=1`.
+    fn quantile( &self, q: T ) -> T;
+}
+
+pub trait Skewness<T>{
+    /// Skewness of vector.
+    fn skewness( &self ) -> T;
+}
+
+pub trait Kurtosis<T>{
+    /// Excess kurtosis of vector.
+    fn kurtosis( &self ) -> T;
+}
+
+pub trait CrestFactor<T>{
+    /// The ratio of the peak magnitude to the RMS of the vector.
+    fn crest_factor( &self ) -> T;
+}
+
 pub trait Power<T>{
     /// Power of the vector
     fn power( &mut self );
@@ -254,6 +386,47 @@ pub trait Psd<T>{
     fn psd( &self ) -> Vec<T>;
 }
 
+pub trait Welch<T>{
+    /// Estimate the Power Spectral Density (PSD) with Welch's method. Returns the frequency axis and the PSD.
+    fn welch( &self, window: &Vec<T>, overlap: usize, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>);
+    /// Estimate the Power Spectral Density (PSD) with a windowed periodogram. Returns the frequency axis and the PSD.
+    fn periodogram( &self, window: &Vec<T>, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>);
+}
+
+pub trait Convolve<T> {
+    /// Convolve `self` with `kernel`.
+    fn convolve( &self, kernel: &Self, mode: ConvolutionMode ) -> Self;
+    /// Correlate `self` with `other`.
+    fn correlate( &self, other: &Self, mode: ConvolutionMode ) -> Self;
+}
+
+pub trait Xcorr<T> {
+    /// Estimate the cross-correlation of `self` with `other`. Returns the lags and the correlation.
+    fn xcorr( &self, other: &Self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self);
+    /// Estimate the auto-correlation of `self`. Returns the lags and the correlation.
+    fn autocorr( &self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self);
+}
+
+pub trait Multitaper<T>{
+    /// Estimate the Power Spectral Density (PSD) with Thomson's multitaper method. Returns the frequency axis, the PSD and the degrees of freedom of each bin.
+    fn multitaper( &self, nw: T, num_tapers: usize, sample_rate: T, sides: PsdSides ) -> (Vec<T>, Vec<T>, Vec<T>);
+}
+
+pub trait Stft<T>{
+    /// Compute the Short-Time Fourier Transform (STFT). Returns a frames×bins matrix.
+    fn stft( &self, window: &Vec<T>, hop: usize, fft_size: usize ) -> Vec<Vec<Cartesian<T>>>;
+}
+
+pub trait Istft<T>{
+    /// Compute the inverse Short-Time Fourier Transform (STFT) of a frames×bins matrix, with weighted overlap-add.
+    fn istft( &self, window: &Vec<T>, hop: usize ) -> Vec<Cartesian<T>>;
+}
+
+pub trait FilterSample<C>: Copy {
+    /// Multiply the sample `self` with the filter coefficient `coefficient`.
+    fn mul_coefficient( self, coefficient: C ) -> Self;
+}
+
 pub trait FromBinary {
     // Load signal of type T in a binary file into vector. 
     fn from_binary( item_type: ItemType, path: &str ) -> Self;
@@ -12,11 +12,41 @@ pub use ops::*;
 
 pub mod math_impl;
 
+pub mod statistics;
+
 pub mod window;
+pub use window::*;
 
 pub mod complex;
 pub use complex::*;
 
+pub mod spectral;
+pub use spectral::*;
+
+pub mod filter;
+pub use filter::*;
+
+pub mod convolution;
+pub use convolution::*;
+
+pub mod correlation;
+pub use correlation::*;
+
+pub mod histogram;
+pub use histogram::*;
+
+pub mod noise;
+pub use noise::*;
+
+pub mod nco;
+pub use nco::*;
+
+pub mod waveform;
+pub use waveform::*;
+
+pub mod sequence;
+pub use sequence::*;
+
 
 #[cfg(any(feature = "std", test))]
 pub mod plot;
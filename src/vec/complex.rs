@@ -207,7 +207,8 @@ impl <T: MixedAbs> crate::traits::Mag<T> for Vec<T> {
 impl<T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> Psd<T> for Vec<Cartesian<T>> {
     /// Calculate the Power Spectral Density (PSD) in linear scale of a signal.
     /// 
-    /// Expects the signal length to be a power of two. If not, the signal is zero padded.
+    /// The PSD has the same length as the signal. Any signal length is supported.
+    /// The estimate is unwindowed and scaled by `1/N²`. See [`Welch`] for windowed estimates with density or spectrum scaling.
     ///  
     /// ## Example
     /// 
@@ -244,15 +245,7 @@ impl<T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry +
     /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/osc_psd.png) 
     fn psd( &self ) -> Vec<T>
     {
-        let padded_len = round_to_power_of_two(self.len());
-        let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(padded_len);
-        for idx in 0..self.len() {
-            buffer.push_back(self[idx]);
-        }
-        for _idx in 0..padded_len-self.len() {
-            buffer.push_back(Cartesian::<T>::mixed_zero());
-        }
-
+        let mut buffer = self.clone();
         buffer.fft();
         buffer.mag();
         let mut real_buffer = buffer.re();
@@ -262,13 +255,49 @@ impl<T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry +
     }
 }
 
-impl <T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Fft for Vec<Cartesian<T>> {
-    /// Calculate the Raddix-2 FFT for self.
+impl<T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> Psd<T> for Vec<T> {
+    /// Calculate the Power Spectral Density (PSD) in linear scale of a real signal.
+    /// 
+    /// The spectrum of a real signal is symmetric, hence only the `N/2+1` non-negative frequency bins are returned.
+    /// The bins are equal to the first `N/2+1` bins of the PSD of the corresponding complex signal.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    /// 
+    /// let mut signal = Vec::lin_range(0f32, 2f32*f32::mixed_tau(), 9);
+    /// signal.cos();
+    /// 
+    /// let psd = signal.psd();
+    /// let reference = Vec::<Cartesian<f32>>::new_from_real(signal).psd();
+    /// 
+    /// assert_eq!( psd.len(), 5 );
+    /// for idx in 0..psd.len()
+    /// {
+    ///     assert!( (psd[idx]-reference[idx]).abs() < 1e-6 );
+    /// }
+    /// ```
+    fn psd( &self ) -> Vec<T>
+    {
+        let mut buffer = self.rfft();
+        buffer.mag();
+        let mut real_buffer = buffer.re();
+        real_buffer.powi(2);
+
+        return real_buffer;
+    }
+}
+
+impl <T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Fft for Vec<Cartesian<T>> {
+    /// Calculate the FFT for self.
     /// Scaled for each butterfly computation.
-    /// Requires input size to be a power of two.
+    /// Any input size, see [`FftPlan`] for the selected algorithm.
     /// 
     /// Computed-in-place.
-    /// Decimation-in-freqency.
+    /// 
+    /// Delegates to a [`FftPlan`] created for the call. Create the plan once and use `FftPlan::process_vec` for repeating computations.
     /// 
     /// ## Example
     /// 
@@ -289,17 +318,18 @@ impl <T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry
     ///
     /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/fft_demonstration.png) 
     fn fft(&mut self){
-        fft( &mut self.vec);
+        FftPlan::new(self.len(), FftDirection::Forward).process_vec(self);
     }
 }
 
-impl <T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Ifft for Vec<Cartesian<T>> {
-    /// Calculate the Raddix-2 IFFT for self.
-    /// Scaled for each butterfly computation.
-    /// Requires input size to be a power of two.
+impl <T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Ifft for Vec<Cartesian<T>> {
+    /// Calculate the IFFT for self.
+    /// Unscaled.
+    /// Any input size, see [`FftPlan`] for the selected algorithm.
     /// 
     /// Computed-in-place.
-    /// Decimation-in-freqency.
+    /// 
+    /// Delegates to a [`FftPlan`] created for the call. Create the plan once and use `FftPlan::process_vec` for repeating computations.
     /// 
     /// ## Example
     /// 
@@ -321,7 +351,60 @@ impl <T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry
     /// 
     /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/ifft_demonstration.png) 
     fn ifft(&mut self){
-        ifft( &mut self.vec);
+        FftPlan::new(self.len(), FftDirection::Backward).process_vec(self);
+    }
+}
+
+impl <T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Rfft<T> for Vec<T> {
+    /// Calculate the FFT of the real vector self.
+    /// Scaled by `1/N`, as [`traits::Fft`].
+    /// Any input size.
+    /// 
+    /// Returns the `N/2+1` non-negative frequency bins, see [`rfft`].
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    /// 
+    /// let mut signal = Vec::lin_range(0f32, 2f32*f32::mixed_tau(), 9);
+    /// signal.cos();
+    /// 
+    /// let mut spectrum = signal.rfft();
+    /// spectrum.mag();
+    /// 
+    /// assert_eq!( spectrum.len(), 5 );
+    /// assert!( (spectrum[2].re-0.5).abs() < 0.1 );
+    /// ```
+    fn rfft( &self ) -> Vec<Cartesian<T>> {
+        return rfft(&self.vec);
+    }
+}
+
+impl <T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi> traits::Irfft<T> for Vec<Cartesian<T>> {
+    /// Calculate the real inverse FFT of length `len`, from the `len/2+1` non-negative frequency bins in self.
+    /// Unscaled, as [`traits::Ifft`].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len` - The length of the real output.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let signal = Vec::lin_range(0f64, 5f64, 6);
+    /// let restored = signal.rfft().irfft(6);
+    /// 
+    /// for idx in 0..6
+    /// {
+    ///     assert!( (restored[idx]-signal[idx]).abs() < 1e-12 );
+    /// }
+    /// ```
+    fn irfft( &self, len: usize ) -> Vec<T> {
+        return irfft(&self.vec, len);
     }
 }
 
@@ -1,18 +1,71 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use alloc::boxed::Box;
 
 use mixed_num::traits::*;
 use mixed_num::complex::*;
 
 use crate::*;
 
-#[derive(PartialEq)]
-enum FftDirection {
+mod mixed_radix;
+
+mod bluestein;
+use bluestein::Bluestein;
+
+mod real;
+pub use real::*;
+
+/// The direction of a Fourier transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FftDirection {
+    /// The forward transform, FFT.
     Forward,
+    /// The inverse transform, IFFT.
     Backward,
 }
 
+/// The scaling policy of a Fourier transform.
+/// 
+/// For floating point types the choice mainly sets the normalization of the result.
+/// For fixed point types it also sets how the growth of the values through the transform is handled.
+/// The unscaled transform grows the values by up to a factor of `N`, and may overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// 
+/// const N:usize = 4;
+/// let forward  = FftPlan::<f32>::new_with_scaling( N, FftDirection::Forward,  FftScaling::OneOverSqrtN );
+/// let backward = FftPlan::<f32>::new_with_scaling( N, FftDirection::Backward, FftScaling::OneOverSqrtN );
+/// 
+/// let mut buffer = Vec::<Cartesian<f32>>::ones(N);
+/// forward.process_vec( &mut buffer );
+/// assert_eq!( buffer.to_string(), "[ 2+0i, 0+0i, 0+0i, 0+0i ]" );
+/// 
+/// // The unitary transforms round-trip.
+/// backward.process_vec( &mut buffer );
+/// assert_eq!( buffer.to_string(), "[ 1+0i, 1+0i, 1+0i, 1+0i ]" );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FftScaling {
+    /// No scaling, `X[k] = Σ x[n]W^(nk)`.
+    Unscaled,
+    /// Scaled by `1/N` after the transform.
+    OneOverN,
+    /// Scaled by `1/sqrt(N)` after the transform. Forward and inverse transforms are unitary.
+    OneOverSqrtN,
+    /// Each stage of radix `r` is scaled by `1/r`, for a total scale of `1/N`.
+    /// Avoids overflow in fixed point, at the cost of the resolution of small signals.
+    PerStage,
+    /// Block floating point. Each stage is shifted right only when the values of the block could overflow in the stage.
+    /// The plan returns the block exponent `e`, such that the unscaled transform is the result times `2^e`.
+    /// Preserves the dynamic range of small fixed point signals. Floating point types are never shifted.
+    BlockFloatingPoint,
+}
+
 /// Check if x is a power of two.
 /// 
 /// ## Argument
@@ -88,27 +141,20 @@ pub fn log2( x: usize ) -> usize
   return i - 1;
 }
 
-/// Bit-reverse the order of the input array. Computed in place.
+/// Calculate the bit-reversed index of each position in an array of length `n`.
 /// 
 /// ## Arguments
 /// 
-/// * `arr` - A mutable reference to the array to do the computation on, and store the result in.
+/// * `n` - The length of the array. Must be a power of two.
 /// 
-fn bitreverse_order<T>( arr: &mut [Cartesian<T>] )
-    where T: core::marker::Copy
+fn bitreverse_indices( n: usize ) -> Vec<usize>
 {
-    let n:usize = arr.len();
+    let mut indices = Vec::<usize>::with_capacity(n);
     let mut target_index:usize = 0;
 
-    for index in 0..n
+    for _index in 0..n
     {
-        if index<target_index
-        {
-            let temp = arr[target_index];
-
-            arr[target_index] = arr[index];
-            arr[index] = temp;
-        }
+        indices.push(target_index);
 
         let mut mask:usize = n;
         mask >>=1;
@@ -122,15 +168,373 @@ fn bitreverse_order<T>( arr: &mut [Cartesian<T>] )
         }
         target_index |= mask;
     }
+    return indices;
+}
+
+/// Scale each element of `array` by `factor`. Computed in place.
+fn scale_array<T>( array: &mut [Cartesian<T>], factor: T )
+    where T: MixedNum + MixedOps
+{
+    for idx in 0..array.len()
+    {
+        array[idx] = Cartesian::new(array[idx].re*factor, array[idx].im*factor);
+    }
+}
+
+/// Scale each element of `array` by `2^exponent`. Computed in place.
+/// 
+/// The scaling is done in steps of at most `2^8`, such that each factor is representable in fixed point types with few bits.
+fn scale_array_by_power_of_two<T>( array: &mut [Cartesian<T>], exponent: i32 )
+    where T: MixedNum + MixedOps
+{
+    let mut remaining = exponent;
+    while remaining != 0
+    {
+        let step = remaining.clamp(-8, 8);
+        scale_array(array, T::mixed_from_num(2f64.mixed_powi(step)));
+        remaining -= step;
+    }
+}
+
+/// Find the block floating point scale factor of a stage with radix `radix`.
+/// 
+/// The block is shifted right until the largest component is small enough that the stage can not overflow.
+/// A stage of radix `r` grows a component by less than a factor `2r`.
+/// 
+/// ## Arguments
+/// 
+/// * `array`    - The input of the stage.
+/// * `radix`    - The radix of the stage.
+/// * `exponent` - The block exponent, which is increased by the number of shifts.
+/// 
+fn block_floating_point_scale<T>( array: &[Cartesian<T>], radix: usize, exponent: &mut i32 ) -> T
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero
+{
+    let mut max = T::mixed_zero();
+    for idx in 0..array.len()
+    {
+        for value in [array[idx].re, array[idx].im]
+        {
+            let abs = if value < T::mixed_zero() { -value } else { value };
+            if max < abs
+            {
+                max = abs;
+            }
+        }
+    }
+
+    let limit = T::mixed_max_value()/T::mixed_from_num((2*radix) as u32);
+    let mut shift = 0;
+    let mut factor = 1f64;
+    // The shift is bounded, in case of non-finite floating point values.
+    while limit < max && shift < 64
+    {
+        max = max/T::mixed_from_num(2u32);
+        factor = factor*0.5;
+        shift += 1;
+    }
+
+    *exponent += shift;
+    return T::mixed_from_num(factor);
 }
 
-/// Calculate the Raddix-2 FFT for fixed point vectors.
+/// Bit-reverse the order of the input array using a precomputed index table. Computed in place.
+/// 
+/// ## Arguments
+/// 
+/// * `arr`     - A mutable reference to the array to do the computation on, and store the result in.
+/// * `indices` - The bit-reversed index of each position in `arr`.
+/// 
+fn bitreverse_order<T>( arr: &mut [Cartesian<T>], indices: &[usize] )
+    where T: core::marker::Copy
+{
+    for index in 0..arr.len()
+    {
+        let target_index = indices[index];
+        if index<target_index
+        {
+            arr.swap(index, target_index);
+        }
+    }
+}
+
+/// The FFT algorithm used by a plan, with its precomputed tables.
+#[derive(Clone, Debug, PartialEq)]
+enum FftAlgorithm<T> {
+    /// In-place Raddix-2 decimation-in-frequency, for power-of-two lengths.
+    Radix2 {
+        twiddle_factors: crate::Vec<Cartesian<T>>,
+        bitreverse_indices: Vec<usize>,
+    },
+    /// Stockham mixed-radix, for lengths that factor into 2, 3, 4 and 5.
+    MixedRadix {
+        factors: Vec<usize>,
+        twiddle_factors: crate::Vec<Cartesian<T>>,
+    },
+    /// Bluestein chirp-z transform, for all other lengths.
+    Bluestein( Box<Bluestein<T>> ),
+}
+
+/// Precomputed FFT plan for a specific length and direction.
+/// 
+/// Holds the twiddle factors, and the bit-reversal table or the factorization for the specific size and direction,
+/// such that repeated transforms do not have to recompute them.
+/// The plan can be applied to any number of buffers of the planned size.
+/// 
+/// The algorithm is selected from the length of the transform:
+/// - Power-of-two lengths use an in-place Raddix-2 decimation-in-frequency transform.
+/// - Lengths that factor into 2, 3, 4 and 5 use a mixed-radix transform.
+/// - All other lengths, e.g. primes, use the Bluestein chirp-z transform.
+/// 
+/// By default, the forward transform is scaled by `1/N`, distributed over the stages to avoid overflow, and the inverse transform is unscaled.
+/// Other scaling policies are selected with [`FftPlan::new_with_scaling`], see [`FftScaling`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// 
+/// const N:usize = 4;
+/// let plan = FftPlan::<f32>::new( N, FftDirection::Forward );
+/// 
+/// let mut arr  = vec![ Cartesian::new(0f32, 0f32 ); N  ];
+/// arr[0].re = 1f32;
+/// 
+/// plan.process( &mut arr );
+/// assert_eq!( arr, vec![ Cartesian::new(0.25, 0.0 ); N ] );
+/// 
+/// // The same plan is reused for the next buffer.
+/// let mut buffer = Vec::<Cartesian<f32>>::ones(N);
+/// plan.process_vec( &mut buffer );
+/// assert_eq!( buffer.to_string(), "[ 1+0i, 0+0i, 0+0i, 0+0i ]" );
+/// ```
+/// 
+/// Lengths which are not a power of two are supported.
+/// 
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// 
+/// // 1000 = 4*2*5*5*5 is computed with the mixed-radix algorithm.
+/// let plan = FftPlan::<f64>::new( 1000, FftDirection::Forward );
+/// let mut buffer = Vec::<Cartesian<f64>>::ones(1000);
+/// plan.process_vec( &mut buffer );
+/// 
+/// assert!( (buffer[0].re-1.0).abs() < 1e-12 );
+/// assert!( buffer[1].re.abs() < 1e-12 );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FftPlan<T> {
+    len: usize,
+    direction: FftDirection,
+    scaling: FftScaling,
+    algorithm: FftAlgorithm<T>,
+}
+
+// A plan is never empty, as zero lengths are rejected.
+#[allow(clippy::len_without_is_empty)]
+impl <T> FftPlan<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Create a plan for transforms of length `len` in the direction `direction`.
+    /// 
+    /// The forward transform uses [`FftScaling::PerStage`], and the inverse transform [`FftScaling::Unscaled`].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`       - The length of the buffers to transform.
+    /// * `direction` - The direction of the transform.
+    /// 
+    pub fn new( len: usize, direction: FftDirection ) -> FftPlan<T>
+    {
+        let scaling = match direction {
+            FftDirection::Forward  => FftScaling::PerStage,
+            FftDirection::Backward => FftScaling::Unscaled,
+        };
+        return FftPlan::new_with_scaling(len, direction, scaling);
+    }
+
+    /// Create a plan for transforms of length `len` in the direction `direction`, with the scaling policy `scaling`.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`       - The length of the buffers to transform.
+    /// * `direction` - The direction of the transform.
+    /// * `scaling`   - The scaling policy of the transform.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed::FixedI32 as F;
+    /// use fixed::types::extra::U24 as U;
+    /// use mixed_num::Cartesian;
+    /// use ndsp::*;
+    /// 
+    /// const N:usize = 256;
+    /// let plan = FftPlan::<F<U>>::new_with_scaling( N, FftDirection::Forward, FftScaling::BlockFloatingPoint );
+    /// 
+    /// let mut buffer = Vec::<Cartesian<F<U>>>::ones(N);
+    /// let exponent = plan.process_vec( &mut buffer );
+    /// 
+    /// // The unscaled DC bin, N, does not fit in the fixed point type.
+    /// assert_eq!( exponent, 3 );
+    /// assert_eq!( buffer[0].re.to_num::<f32>()*2f32.powi(exponent), 256f32 );
+    /// ```
+    pub fn new_with_scaling( len: usize, direction: FftDirection, scaling: FftScaling ) -> FftPlan<T>
+    {
+        if len == 0
+        {
+            panic!("The FFT length must be larger than zero.");
+        }
+
+        let algorithm;
+        if is_power_of_two(len)
+        {
+            let dir_val = match direction {
+                FftDirection::Forward  => T::mixed_from_num(1),
+                FftDirection::Backward => T::mixed_from_num(-1),
+            };
+
+            algorithm = FftAlgorithm::Radix2 {
+                twiddle_factors: calculate_twiddle_factors(len, dir_val),
+                bitreverse_indices: bitreverse_indices(len),
+            };
+        }
+        else if let Some(factors) = mixed_radix::factorize(len)
+        {
+            algorithm = FftAlgorithm::MixedRadix {
+                factors: factors,
+                twiddle_factors: mixed_radix::twiddle_table(len, direction),
+            };
+        }
+        else
+        {
+            algorithm = FftAlgorithm::Bluestein( Box::new(Bluestein::new(len, direction)) );
+        }
+
+        return FftPlan {
+            len: len,
+            direction: direction,
+            scaling: scaling,
+            algorithm: algorithm,
+        };
+    }
+
+    /// The transform length of the plan.
+    pub fn len( &self ) -> usize
+    {
+        return self.len;
+    }
+
+    /// The transform direction of the plan.
+    pub fn direction( &self ) -> FftDirection
+    {
+        return self.direction;
+    }
+
+    /// The scaling policy of the plan.
+    pub fn scaling( &self ) -> FftScaling
+    {
+        return self.scaling;
+    }
+
+    /// Apply the planned transform to `array`. Computed-in-place.
+    /// 
+    /// Returns the block exponent for [`FftScaling::BlockFloatingPoint`], and zero for the other scaling policies.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `array` - A mutable reference to the array to do the computation on, and store the result in. Must be of the planned length.
+    /// 
+    pub fn process( &self, array: &mut [Cartesian<T>] ) -> i32
+    {
+        if array.len() != self.len
+        {
+            panic!("Buffer length does not match the plan. Plan length = {}, buffer length = {}", self.len, array.len());
+        }
+
+        let len = self.len as f64;
+        let scaling = self.scaling;
+        let mut exponent = 0i32;
+
+        // The scale factor of the input of each stage.
+        let stage_scale = |stage: &[Cartesian<T>], radix: usize| -> T {
+            match scaling {
+                FftScaling::PerStage           => T::mixed_from_num(1f64/(radix as f64)),
+                FftScaling::BlockFloatingPoint => block_floating_point_scale(stage, radix, &mut exponent),
+                _                              => T::mixed_from_num(1),
+            }
+        };
+
+        // The scale factor applied after the transform.
+        let mut post_scale = match scaling {
+            FftScaling::OneOverN     => 1f64/len,
+            FftScaling::OneOverSqrtN => 1f64/len.mixed_sqrt(),
+            _                        => 1f64,
+        };
+
+        match &self.algorithm {
+            FftAlgorithm::Radix2 { twiddle_factors, bitreverse_indices } => {
+                fft_processor(array, twiddle_factors, stage_scale);
+
+                // Decimation-in-freqency.
+                bitreverse_order(array, bitreverse_indices);
+            }
+            FftAlgorithm::MixedRadix { factors, twiddle_factors } => {
+                mixed_radix::mixed_radix_processor(array, factors, twiddle_factors, stage_scale, self.direction);
+            }
+            FftAlgorithm::Bluestein( bluestein ) => {
+                // The unscaled transform is the result times 2^e.
+                let block_exponent = bluestein.process(array);
+                if scaling == FftScaling::PerStage
+                {
+                    post_scale = 1f64/len;
+                }
+                match scaling {
+                    FftScaling::BlockFloatingPoint if 0 < block_exponent => exponent = block_exponent,
+                    // Shifting down first preserves the range, and shifting up last the resolution.
+                    _ if block_exponent <= 0 => scale_array_by_power_of_two(array, block_exponent),
+                    _ => {
+                        if post_scale != 1f64
+                        {
+                            scale_array(array, T::mixed_from_num(post_scale));
+                            post_scale = 1f64;
+                        }
+                        scale_array_by_power_of_two(array, block_exponent);
+                    }
+                }
+            }
+        }
+
+        if post_scale != 1f64
+        {
+            scale_array(array, T::mixed_from_num(post_scale));
+        }
+        return exponent;
+    }
+
+    /// Apply the planned transform to `vec`. Computed-in-place.
+    /// 
+    /// Returns the block exponent for [`FftScaling::BlockFloatingPoint`], and zero for the other scaling policies.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `vec` - A mutable reference to the vector to do the computation on, and store the result in. Must be of the planned length.
+    /// 
+    pub fn process_vec( &self, vec: &mut crate::Vec<Cartesian<T>> ) -> i32
+    {
+        return self.process(&mut vec.vec);
+    }
+}
+
+/// Calculate the FFT for fixed point vectors.
 /// - Scaled for each butterfly computation.
-/// - Requires input size to be a power of two.
+/// - Any input size, see [`FftPlan`] for the selected algorithm.
 /// - Computed-in-place.
-/// - Decimation-in-freqency.
 /// 
-/// The function computes the twiddle factor each time it is called, which is suboptimal for repeating computations.
+/// The function creates a new [`FftPlan`] each time it is called, which is suboptimal for repeating computations.
+/// Create a plan once, and reuse it, for repeating computations.
 /// 
 /// ## Arguments
 /// 
@@ -158,22 +562,18 @@ fn bitreverse_order<T>( arr: &mut [Cartesian<T>] )
 ///                         Cartesian::new(0.0,  0.25 )] );
 /// ```
 pub fn fft<T>( array: &mut [Cartesian<T>] )
-    where T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
 {
-    // Process fft.
-    fft_processor(array, FftDirection::Forward);
-
-    // Decimation-in-freqency.
-    bitreverse_order(array); // Bitreverse order
+    FftPlan::new(array.len(), FftDirection::Forward).process(array);
 }
 
-/// Calculate the Raddix-2 Inverse FFT for fixed point vectors.
-/// - Scaled for each butterfly computation.
-/// - Requires input size to be a power of two.
+/// Calculate the Inverse FFT for fixed point vectors.
+/// - Unscaled.
+/// - Any input size, see [`FftPlan`] for the selected algorithm.
 /// - Computed-in-place.
-/// - Decimation-in-freqency.
 /// 
-/// The function computes the twiddle factor each time it is called, which is suboptimal for repeating computations.
+/// The function creates a new [`FftPlan`] each time it is called, which is suboptimal for repeating computations.
+/// Create a plan once, and reuse it, for repeating computations.
 /// 
 /// ## Arguments
 /// 
@@ -201,12 +601,74 @@ pub fn fft<T>( array: &mut [Cartesian<T>] )
 ///                         Cartesian::new(0.0, 1.0 )] );
 /// ```
 pub fn ifft<T>( vec: &mut Vec<Cartesian<T>> )
-    where T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedOps + MixedPowi
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedOps + MixedPowi
 {
-    // Process fft.
-    fft_processor(vec, FftDirection::Backward);
-    // Decimation-in-freqency.
-    bitreverse_order(vec); // Bitreverse order
+    FftPlan::new(vec.len(), FftDirection::Backward).process(vec);
+}
+
+/// Calculate the FFT with the scaling policy `scaling`.
+/// - Any input size, see [`FftPlan`] for the selected algorithm.
+/// - Computed-in-place.
+/// 
+/// Returns the block exponent for [`FftScaling::BlockFloatingPoint`], and zero for the other scaling policies.
+/// 
+/// The function creates a new [`FftPlan`] each time it is called, which is suboptimal for repeating computations.
+/// 
+/// ## Arguments
+/// 
+/// * `array`   - A mutable reference to the array to do the computation on, and store the result in.
+/// * `scaling` - The scaling policy of the transform.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// use ndsp::complex::fft_scaled;
+/// 
+/// const N:usize = 4;
+/// let mut arr  = vec![ Cartesian::new(1f32, 0f32 ); N  ];
+/// 
+/// let exponent = fft_scaled( &mut arr, FftScaling::Unscaled );
+/// assert_eq!( exponent, 0 );
+/// assert_eq!( arr[0], Cartesian::new(4.0, 0.0) );
+/// ```
+pub fn fft_scaled<T>( array: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    return FftPlan::new_with_scaling(array.len(), FftDirection::Forward, scaling).process(array);
+}
+
+/// Calculate the Inverse FFT with the scaling policy `scaling`.
+/// - Any input size, see [`FftPlan`] for the selected algorithm.
+/// - Computed-in-place.
+/// 
+/// Returns the block exponent for [`FftScaling::BlockFloatingPoint`], and zero for the other scaling policies.
+/// 
+/// The function creates a new [`FftPlan`] each time it is called, which is suboptimal for repeating computations.
+/// 
+/// ## Arguments
+/// 
+/// * `array`   - A mutable reference to the array to do the computation on, and store the result in.
+/// * `scaling` - The scaling policy of the transform.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// use ndsp::complex::ifft_scaled;
+/// 
+/// const N:usize = 4;
+/// let mut arr  = vec![ Cartesian::new(1f32, 0f32 ); N  ];
+/// 
+/// ifft_scaled( &mut arr, FftScaling::OneOverN );
+/// assert_eq!( arr[0], Cartesian::new(1.0, 0.0) );
+/// ```
+pub fn ifft_scaled<T>( array: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    return FftPlan::new_with_scaling(array.len(), FftDirection::Backward, scaling).process(array);
 }
 
 /// Butterfly computation for decimate-in-frequeny.
@@ -255,26 +717,19 @@ pub fn calculate_twiddle_factors<T>( n: usize, dir: T) -> crate::Vec<Cartesian<T
 
 /// Shared fft processor for fft and ifft.
 /// Requires bit-reversion afterwards.
-fn fft_processor<T>( array: &mut [Cartesian<T>], dir: FftDirection )
-    where T: MixedReal + MixedNumSigned + MixedNumConversion<T> + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+/// 
+/// ## Arguments
+/// 
+/// * `array` - The array to transform in place.
+/// * `w`     - The twiddle factors for the transform length and direction.
+/// * `scale` - Function returning the factor each butterfly input of a stage is scaled with, from the stage input and radix.
+/// 
+fn fft_processor<T, F>( array: &mut [Cartesian<T>], w: &crate::Vec<Cartesian<T>>, mut scale: F )
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi,
+          F: FnMut(&[Cartesian<T>], usize) -> T
 {
-    let scale_factor:T;
-    let dir_val:T;
-    if dir == FftDirection::Forward
-    {
-        dir_val = T::mixed_from_num(1);
-        scale_factor=T::mixed_from_num(0.5);
-    }
-    else
-    {
-        dir_val = T::mixed_from_num(-1);
-        scale_factor=T::mixed_from_num(1);
-    }
-
     let n = array.len();
 
-    let w = calculate_twiddle_factors(n, dir_val);
-
     // Number of butterfly computations per block.
     let mut num_butt:   usize = n/2;
     // Number of blocks.
@@ -286,6 +741,8 @@ fn fft_processor<T>( array: &mut [Cartesian<T>], dir: FftDirection )
     // Iterate over stages
     for _stage in 1..=stages
     {
+        let scale_factor = scale(array, 2);
+
         // Iterate over blocks.
         for block in 0..num_blocks
         {       
@@ -297,8 +754,8 @@ fn fft_processor<T>( array: &mut [Cartesian<T>], dir: FftDirection )
             for butt in 0..num_butt
             {
                 // Scale values to avoid overflow.
-                let mut a = array[pa+butt]* scale_factor;
-                let mut b = array[pb+butt]* scale_factor;
+                let mut a = Cartesian::new(array[pa+butt].re*scale_factor, array[pa+butt].im*scale_factor);
+                let mut b = Cartesian::new(array[pb+butt].re*scale_factor, array[pb+butt].im*scale_factor);
 
                 let w_idx:usize = w_idx_step_size*(butt);
                 let w_temp = w[ w_idx ];
@@ -313,4 +770,4 @@ fn fft_processor<T>( array: &mut [Cartesian<T>], dir: FftDirection )
         num_blocks *= 2;
         num_butt   /= 2;
     }
-}
\ No newline at end of file
+}
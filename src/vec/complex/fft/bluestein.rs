@@ -0,0 +1,166 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use mixed_num::traits::*;
+use mixed_num::complex::*;
+
+use crate::traits::*;
+
+use super::{FftDirection, FftPlan, FftScaling, round_to_power_of_two, log2, block_floating_point_scale};
+
+/// Precomputed data for a Bluestein (chirp-z) transform of arbitrary length.
+///
+/// The transform is computed as a circular convolution with a chirp, using Radix-2 transforms of length `M >= 2N-1`.
+/// The inner transforms use block floating point, such that small fixed point signals keep their resolution,
+/// and the block exponent of the result is returned by [`Bluestein::process`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Bluestein<T> {
+    /// The chirp `exp(∓iπn²/N)`, for `n ∈ {0,1,...,N-1}`.
+    chirp: crate::Vec<Cartesian<T>>,
+    /// The unscaled forward transform of the conjugate chirp, times `2^-chirp_exponent`, such that each magnitude is below one.
+    chirp_spectrum: crate::Vec<Cartesian<T>>,
+    chirp_exponent: i32,
+    /// Forward Radix-2 plan of length `M`, with block floating point.
+    forward: FftPlan<T>,
+    /// Inverse Radix-2 plan of length `M`, with block floating point.
+    backward: FftPlan<T>,
+}
+
+impl <T> Bluestein<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Precompute a Bluestein transform of length `len`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`       - The transform length, `N`.
+    /// * `direction` - The transform direction.
+    ///
+    pub(crate) fn new( len: usize, direction: FftDirection ) -> Bluestein<T>
+    {
+        let inner_len = round_to_power_of_two(2*len-1);
+
+        let mut chirp = crate::Vec::<Cartesian<T>>::new_with_capacity(len);
+        let mut conj_chirp = Vec::<Cartesian<f64>>::with_capacity(inner_len);
+        for _idx in 0..inner_len
+        {
+            conj_chirp.push(Cartesian::new(0f64, 0f64));
+        }
+        for n in 0..len
+        {
+            // n² mod 2N keeps the angle accurate for large n.
+            let n_sq = ((n as u64)*(n as u64))%(2*len as u64);
+            let mut angle = -f64::mixed_pi()*(n_sq as f64)/(len as f64);
+            if angle < -f64::mixed_pi()
+            {
+                angle += f64::mixed_tau();
+            }
+            if direction == FftDirection::Backward
+            {
+                angle = -angle;
+            }
+            let (imag, real) = T::mixed_from_num(angle).mixed_sincos();
+            chirp.push_back(Cartesian::new(real, imag));
+
+            let (imag, real) = angle.mixed_sincos();
+            conj_chirp[n] = Cartesian::new(real, -imag);
+            if 0<n
+            {
+                conj_chirp[inner_len-n] = Cartesian::new(real, -imag);
+            }
+        }
+
+        // The chirp spectrum is computed in f64, and normalized to magnitudes below one before conversion,
+        // such that the product with a block of the inner transform can not overflow.
+        FftPlan::<f64>::new_with_scaling(inner_len, FftDirection::Forward, FftScaling::Unscaled).process(&mut conj_chirp);
+        let mut max = 0f64;
+        for k in 0..inner_len
+        {
+            let magnitude = (conj_chirp[k].re*conj_chirp[k].re + conj_chirp[k].im*conj_chirp[k].im).mixed_sqrt();
+            if max < magnitude
+            {
+                max = magnitude;
+            }
+        }
+        let mut chirp_exponent = 0i32;
+        let mut factor = 1f64;
+        while max*factor < 0.5f64
+        {
+            factor = factor*2f64;
+            chirp_exponent -= 1;
+        }
+        while 1f64 <= max*factor
+        {
+            factor = factor*0.5f64;
+            chirp_exponent += 1;
+        }
+
+        let mut chirp_spectrum = crate::Vec::<Cartesian<T>>::new_with_capacity(inner_len);
+        for k in 0..inner_len
+        {
+            chirp_spectrum.push_back(Cartesian::new(T::mixed_from_num(conj_chirp[k].re*factor), T::mixed_from_num(conj_chirp[k].im*factor)));
+        }
+
+        return Bluestein {
+            chirp: chirp,
+            chirp_spectrum: chirp_spectrum,
+            chirp_exponent: chirp_exponent,
+            forward: FftPlan::new_with_scaling(inner_len, FftDirection::Forward, FftScaling::BlockFloatingPoint),
+            backward: FftPlan::new_with_scaling(inner_len, FftDirection::Backward, FftScaling::BlockFloatingPoint),
+        };
+    }
+
+    /// Compute the transform of `array`. Computed-in-place.
+    ///
+    /// Returns the block exponent `e`, such that the unscaled transform is the result times `2^e`.
+    /// The exponent may be negative, as the inner transforms are of length `M > N`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `array` - The array to transform in place.
+    ///
+    pub(crate) fn process( &self, array: &mut [Cartesian<T>] ) -> i32
+    {
+        let len = array.len();
+        let inner_len = self.forward.len();
+        let mut exponent = 0i32;
+
+        // Each multiplication by a magnitude of at most one grows a component by up to √2,
+        // hence the block is shifted as for a stage of radix one before each of them.
+        let scale = block_floating_point_scale(array, 1, &mut exponent);
+        let mut buffer = Vec::<Cartesian<T>>::with_capacity(inner_len);
+        for n in 0..len
+        {
+            buffer.push(Cartesian::new(array[n].re*scale, array[n].im*scale)*self.chirp[n]);
+        }
+        for _n in len..inner_len
+        {
+            buffer.push(Cartesian::new(T::mixed_zero(), T::mixed_zero()));
+        }
+
+        // Circular convolution with the conjugate chirp.
+        exponent += self.forward.process(&mut buffer);
+        let scale = block_floating_point_scale(&buffer, 1, &mut exponent);
+        for k in 0..inner_len
+        {
+            buffer[k] = Cartesian::new(buffer[k].re*scale, buffer[k].im*scale)*self.chirp_spectrum[k];
+        }
+        exponent += self.backward.process(&mut buffer);
+
+        let scale = block_floating_point_scale(&buffer[0..len], 1, &mut exponent);
+        for k in 0..len
+        {
+            array[k] = Cartesian::new(buffer[k].re*scale, buffer[k].im*scale)*self.chirp[k];
+        }
+
+        // The unscaled inverse transform of the product is M times the circular convolution.
+        return exponent + self.chirp_exponent - log2(inner_len) as i32;
+    }
+}
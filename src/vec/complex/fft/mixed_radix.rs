@@ -0,0 +1,224 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use mixed_num::traits::*;
+use mixed_num::complex::*;
+
+use crate::traits::*;
+
+use super::FftDirection;
+
+/// Factorize `n` into the radices supported by the mixed-radix processor.
+///
+/// Radix-4 stages are preferred over radix-2 stages.
+/// Returns `None` if `n` has prime factors other than 2, 3 and 5.
+///
+/// ## Argument
+///
+/// * `n` - The transform length.
+///
+pub(crate) fn factorize( n: usize ) -> Option<Vec<usize>>
+{
+    if n == 0
+    {
+        return None;
+    }
+
+    let mut factors = Vec::<usize>::new();
+    let mut rest = n;
+
+    for radix in [4usize, 2, 3, 5]
+    {
+        while rest.is_multiple_of(radix)
+        {
+            factors.push(radix);
+            rest /= radix;
+        }
+    }
+
+    if rest != 1
+    {
+        return None;
+    }
+    return Some(factors);
+}
+
+/// Calculate the full table of twiddle factors, `W_n^k` for `k ∈ {0,1,...,n-1}`.
+///
+/// The angles are calculated in double precision, and wrapped to the -π=<x<π range before conversion to `T`,
+/// such that the table is accurate for long transforms, also for fixed point types.
+///
+/// ## Arguments
+///
+/// * `n`         - The transform length.
+/// * `direction` - The transform direction.
+///
+pub(crate) fn twiddle_table<T>( n: usize, direction: FftDirection ) -> crate::Vec<Cartesian<T>>
+    where T: MixedReal + MixedTrigonometry + MixedWrapPhase
+{
+    let mut w = crate::Vec::<Cartesian<T>>::new_with_capacity(n);
+
+    for k in 0..n
+    {
+        let mut angle = -f64::mixed_tau()*(k as f64)/(n as f64);
+        if angle < -f64::mixed_pi()
+        {
+            angle += f64::mixed_tau();
+        }
+        if direction == FftDirection::Backward
+        {
+            angle = -angle;
+        }
+
+        let (imag, real) = T::mixed_from_num(angle).mixed_sincos();
+        w.push_back( Cartesian::new(real, imag) );
+    }
+    return w;
+}
+
+/// Multiply a complex number by ∓i, the radix-4 rotation of the given direction.
+#[inline(always)]
+fn rotate_quarter<T>( x: Cartesian<T>, direction: FftDirection ) -> Cartesian<T>
+    where T: MixedNum + MixedNumSigned
+{
+    match direction {
+        FftDirection::Forward  => Cartesian::new(  x.im, -x.re ),
+        FftDirection::Backward => Cartesian::new( -x.im,  x.re ),
+    }
+}
+
+/// Run a single decimation-in-frequency Stockham stage.
+///
+/// ## Arguments
+///
+/// * `src`       - The input of the stage.
+/// * `dst`       - The output of the stage.
+/// * `radix`     - The radix of the stage.
+/// * `n`         - The length of the sub-transforms at this stage.
+/// * `stride`    - The number of interleaved sub-transforms.
+/// * `w`         - Full twiddle table for the transform length.
+/// * `scale`     - The factor each butterfly input is scaled with.
+/// * `direction` - The transform direction.
+///
+#[allow(clippy::too_many_arguments)]
+fn stockham_stage<T>( src: &[Cartesian<T>], dst: &mut [Cartesian<T>], radix: usize, n: usize, stride: usize,
+                      w: &crate::Vec<Cartesian<T>>, scale: T, direction: FftDirection )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero
+{
+    let m = n/radix;
+    let len = src.len();
+    let w_step = len/n;
+    let radix_step = len/radix;
+
+    let mut a = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); 5];
+    let mut b = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); 5];
+
+    for p in 0..m
+    {
+        for q in 0..stride
+        {
+            for k in 0..radix
+            {
+                let x = src[q + stride*(p + k*m)];
+                a[k] = Cartesian::new(x.re*scale, x.im*scale);
+            }
+
+            match radix {
+                2 => {
+                    b[0] = a[0] + a[1];
+                    b[1] = a[0] - a[1];
+                }
+                4 => {
+                    let sum_02  = a[0] + a[2];
+                    let diff_02 = a[0] - a[2];
+                    let sum_13  = a[1] + a[3];
+                    let diff_13 = rotate_quarter(a[1] - a[3], direction);
+
+                    b[0] = sum_02 + sum_13;
+                    b[1] = diff_02 + diff_13;
+                    b[2] = sum_02 - sum_13;
+                    b[3] = diff_02 - diff_13;
+                }
+                _ => {
+                    // Direct DFT of size 3 or 5.
+                    for u in 0..radix
+                    {
+                        let mut acc = a[0];
+                        for k in 1..radix
+                        {
+                            acc = acc + a[k]*w[((u*k)%radix)*radix_step];
+                        }
+                        b[u] = acc;
+                    }
+                }
+            }
+
+            dst[q + stride*radix*p] = b[0];
+            for u in 1..radix
+            {
+                let idx = p*u*w_step;
+                if idx == 0
+                {
+                    dst[q + stride*(radix*p + u)] = b[u];
+                }
+                else
+                {
+                    dst[q + stride*(radix*p + u)] = b[u]*w[idx];
+                }
+            }
+        }
+    }
+}
+
+/// Mixed-radix Stockham auto-sort FFT processor. The result is in natural order.
+///
+/// ## Arguments
+///
+/// * `array`     - The array to transform in place.
+/// * `factors`   - The radix of each stage, see `factorize`.
+/// * `w`         - Full twiddle table for the transform length, see `twiddle_table`.
+/// * `scale`     - Function returning the factor each butterfly input of a stage is scaled with, from the stage input and radix.
+/// * `direction` - The transform direction.
+///
+pub(crate) fn mixed_radix_processor<T, F>( array: &mut [Cartesian<T>], factors: &[usize], w: &crate::Vec<Cartesian<T>>, mut scale: F, direction: FftDirection )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero,
+          F: FnMut(&[Cartesian<T>], usize) -> T
+{
+    let len = array.len();
+    let mut scratch = Vec::<Cartesian<T>>::with_capacity(len);
+    scratch.extend_from_slice(array);
+
+    let mut n = len;
+    let mut stride = 1;
+    // Tracks whether the latest result is in `scratch`.
+    let mut in_scratch = false;
+
+    for &radix in factors
+    {
+        if in_scratch
+        {
+            let scale_factor = scale(&scratch, radix);
+            stockham_stage(&scratch, array, radix, n, stride, w, scale_factor, direction);
+        }
+        else
+        {
+            let scale_factor = scale(array, radix);
+            stockham_stage(array, &mut scratch, radix, n, stride, w, scale_factor, direction);
+        }
+        in_scratch = !in_scratch;
+        n /= radix;
+        stride *= radix;
+    }
+
+    if in_scratch
+    {
+        array.copy_from_slice(&scratch);
+    }
+}
@@ -0,0 +1,364 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::redundant_field_names, clippy::len_without_is_empty)]
+
+extern crate alloc;
+
+use mixed_num::traits::*;
+use mixed_num::complex::*;
+
+use crate::traits::*;
+
+use super::{FftDirection, FftPlan};
+
+/// Calculate `W_N^k` for `k ∈ {0,1,...,N/2}`, the twiddle factors combining the packed half-length transform.
+///
+/// ## Arguments
+///
+/// * `n`         - The length of the real transform, `N`.
+/// * `direction` - The transform direction.
+///
+fn half_twiddles<T>( n: usize, direction: FftDirection ) -> crate::Vec<Cartesian<T>>
+    where T: MixedReal + MixedTrigonometry
+{
+    let mut w = crate::Vec::<Cartesian<T>>::new_with_capacity(n/2+1);
+    for k in 0..=n/2
+    {
+        let mut angle = -f64::mixed_tau()*(k as f64)/(n as f64);
+        if direction == FftDirection::Backward
+        {
+            angle = -angle;
+        }
+        let (imag, real) = T::mixed_from_num(angle).mixed_sincos();
+        w.push_back( Cartesian::new(real, imag) );
+    }
+    return w;
+}
+
+#[inline(always)]
+fn conj<T: MixedNum + MixedNumSigned>( x: Cartesian<T> ) -> Cartesian<T>
+{
+    return Cartesian::new(x.re, -x.im);
+}
+
+#[inline(always)]
+fn half<T: MixedReal + MixedOps>( x: Cartesian<T> ) -> Cartesian<T>
+{
+    let factor = T::mixed_from_num(0.5);
+    return Cartesian::new(x.re*factor, x.im*factor);
+}
+
+/// A reusable plan for FFTs of real signals of length `N`, see [`rfft`].
+/// - Scaled by `1/N`, as [`super::fft`].
+///
+/// Even lengths are computed with a single complex FFT of length `N/2`,
+/// where the even and odd samples are packed into the real and imaginary parts.
+/// The plan holds the half-length complex plan and the twiddle factors combining its output, such that they are only computed once.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let plan = RfftPlan::<f64>::new( 4 );
+///
+/// // The plan is reused for each block of samples.
+/// for block in [[1f64, 1f64, 1f64, 0f64], [0f64, 1f64, 0f64, 1f64]]
+/// {
+///     let spectrum = plan.process( &block );
+///     assert_eq!( spectrum.len(), 3 );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RfftPlan<T> {
+    len: usize,
+    /// The complex plan, of length `N/2` for even lengths, and `N` for odd lengths.
+    plan: FftPlan<T>,
+    /// `W_N^k` for `k ∈ {0,1,...,N/2}`. Empty for odd lengths.
+    twiddles: crate::Vec<Cartesian<T>>,
+}
+
+impl <T> RfftPlan<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Create a plan for FFTs of real signals of length `len`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len` - The length of the real signals to transform, `N`.
+    ///
+    pub fn new( len: usize ) -> RfftPlan<T>
+    {
+        if len == 0
+        {
+            panic!("The FFT length must be larger than zero.");
+        }
+        if len%2 == 1
+        {
+            // Odd lengths can not be packed.
+            return RfftPlan { len: len, plan: FftPlan::new(len, FftDirection::Forward), twiddles: crate::Vec::new_with_capacity(0) };
+        }
+        return RfftPlan { len: len, plan: FftPlan::new(len/2, FftDirection::Forward), twiddles: half_twiddles(len, FftDirection::Forward) };
+    }
+
+    /// The length of the real signals of the plan.
+    pub fn len( &self ) -> usize
+    {
+        return self.len;
+    }
+
+    /// Calculate the FFT of the real signal `input`, of the length of the plan.
+    ///
+    /// Returns the `N/2+1` non-negative frequency bins. The remaining bins are given by the Hermitian symmetry, `X[N-k] = X*[k]`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The real signal to transform.
+    ///
+    pub fn process( &self, input: &[T] ) -> crate::Vec<Cartesian<T>>
+    {
+        let n = self.len;
+        if input.len() != n
+        {
+            panic!("The signal must be of the length of the plan. Plan length = {}, signal length = {}", n, input.len());
+        }
+
+        if n%2 == 1
+        {
+            let mut buffer = alloc::vec::Vec::<Cartesian<T>>::with_capacity(n);
+            for idx in 0..n
+            {
+                buffer.push(Cartesian::new(input[idx], T::mixed_zero()));
+            }
+            self.plan.process(&mut buffer);
+            buffer.truncate(n/2+1);
+            return crate::Vec::new_from_vec(buffer);
+        }
+
+        let m = n/2;
+        let mut packed = alloc::vec::Vec::<Cartesian<T>>::with_capacity(m);
+        for idx in 0..m
+        {
+            packed.push(Cartesian::new(input[2*idx], input[2*idx+1]));
+        }
+        // Scaled by 1/M.
+        self.plan.process(&mut packed);
+
+        let w = &self.twiddles;
+        let mut spectrum = crate::Vec::<Cartesian<T>>::new_with_capacity(m+1);
+        for k in 0..=m
+        {
+            let a = packed[k%m];
+            let b = conj(packed[(m-k)%m]);
+
+            // The spectra of the even and odd samples, scaled by 1/2 to obtain the 1/N scale.
+            let even = half(half(a+b));
+            let odd  = half(half(a-b));
+            let odd  = Cartesian::new(odd.im, -odd.re);
+
+            spectrum.push_back(even + odd*w[k]);
+        }
+        return spectrum;
+    }
+}
+
+/// A reusable plan for inverse FFTs of real signals of length `N`, see [`irfft`].
+/// - Unscaled, as [`super::ifft`].
+///
+/// Even lengths are computed with a single complex inverse FFT of length `N/2`.
+/// The plan holds the half-length complex plan and the twiddle factors preparing its input, such that they are only computed once.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let forward  = RfftPlan::<f64>::new( 4 );
+/// let backward = IrfftPlan::<f64>::new( 4 );
+///
+/// let spectrum = forward.process( &[1f64, 1f64, 1f64, 0f64] );
+/// let signal = backward.process( spectrum.to_alloc_vec() );
+/// assert!( (signal[0]-1.0).abs() < 1e-12 );
+/// assert!(  signal[3].abs() < 1e-12 );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct IrfftPlan<T> {
+    len: usize,
+    /// The complex plan, of length `N/2` for even lengths, and `N` for odd lengths.
+    plan: FftPlan<T>,
+    /// `W_N^-k` for `k ∈ {0,1,...,N/2}`. Empty for odd lengths.
+    twiddles: crate::Vec<Cartesian<T>>,
+}
+
+impl <T> IrfftPlan<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Create a plan for inverse FFTs of real signals of length `len`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len` - The length of the real output, `N`.
+    ///
+    pub fn new( len: usize ) -> IrfftPlan<T>
+    {
+        if len == 0
+        {
+            panic!("The FFT length must be larger than zero.");
+        }
+        if len%2 == 1
+        {
+            // Odd lengths can not be packed.
+            return IrfftPlan { len: len, plan: FftPlan::new(len, FftDirection::Backward), twiddles: crate::Vec::new_with_capacity(0) };
+        }
+        return IrfftPlan { len: len, plan: FftPlan::new(len/2, FftDirection::Backward), twiddles: half_twiddles(len, FftDirection::Backward) };
+    }
+
+    /// The length of the real signals of the plan.
+    pub fn len( &self ) -> usize
+    {
+        return self.len;
+    }
+
+    /// Calculate the real inverse FFT of the non-negative frequency bins in `spectrum`.
+    ///
+    /// The bins `N/2+1..N` are given by the Hermitian symmetry, `X[N-k] = X*[k]`.
+    /// The imaginary parts of the DC bin, and of the Nyquist bin for even lengths, are ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// * `spectrum` - The `N/2+1` non-negative frequency bins.
+    ///
+    pub fn process( &self, spectrum: &[Cartesian<T>] ) -> crate::Vec<T>
+    {
+        let len = self.len;
+        if spectrum.len() != len/2+1
+        {
+            panic!("The spectrum must hold N/2+1 bins. N = {}, number of bins = {}", len, spectrum.len());
+        }
+
+        if len%2 == 1
+        {
+            // Restore the full spectrum.
+            let mut buffer = alloc::vec::Vec::<Cartesian<T>>::with_capacity(len);
+            buffer.push(Cartesian::new(spectrum[0].re, T::mixed_zero()));
+            for k in 1..len
+            {
+                if k < spectrum.len()
+                {
+                    buffer.push(spectrum[k]);
+                }
+                else
+                {
+                    buffer.push(conj(spectrum[len-k]));
+                }
+            }
+            self.plan.process(&mut buffer);
+
+            let mut signal = crate::Vec::<T>::new_with_capacity(len);
+            for idx in 0..len
+            {
+                signal.push_back(buffer[idx].re);
+            }
+            return signal;
+        }
+
+        let m = len/2;
+        let w = &self.twiddles;
+        let mut packed = alloc::vec::Vec::<Cartesian<T>>::with_capacity(m);
+        for k in 0..m
+        {
+            let mut a = spectrum[k];
+            let mut b = conj(spectrum[m-k]);
+            if k == 0
+            {
+                a = Cartesian::new(a.re, T::mixed_zero());
+                b = Cartesian::new(b.re, T::mixed_zero());
+            }
+
+            let even = a+b;
+            let odd  = (a-b)*w[k];
+            packed.push(even + Cartesian::new(-odd.im, odd.re));
+        }
+        self.plan.process(&mut packed);
+
+        let mut signal = crate::Vec::<T>::new_with_capacity(len);
+        for idx in 0..m
+        {
+            signal.push_back(packed[idx].re);
+            signal.push_back(packed[idx].im);
+        }
+        return signal;
+    }
+}
+
+/// Calculate the FFT of a real signal.
+/// - Scaled by `1/N`, as [`super::fft`].
+/// - Any input size.
+///
+/// Returns the `N/2+1` non-negative frequency bins. The remaining bins are given by the Hermitian symmetry, `X[N-k] = X*[k]`.
+///
+/// The function creates a new [`RfftPlan`] each time it is called.
+/// Create a plan once, and reuse it, for repeating computations.
+///
+/// ## Arguments
+///
+/// * `input` - The real signal to transform.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::Cartesian;
+/// use ndsp::*;
+/// use ndsp::complex::rfft;
+///
+/// let spectrum = rfft( &[1f64, 1f64, 1f64, 0f64] );
+/// let expected = [ Cartesian::new(0.75, 0.0), Cartesian::new(0.0, -0.25), Cartesian::new(0.25, 0.0) ];
+///
+/// assert_eq!( spectrum.len(), 3 );
+/// for k in 0..3
+/// {
+///     assert!( (spectrum[k].re-expected[k].re).abs() < 1e-12 );
+///     assert!( (spectrum[k].im-expected[k].im).abs() < 1e-12 );
+/// }
+/// ```
+pub fn rfft<T>( input: &[T] ) -> crate::Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    return RfftPlan::new(input.len()).process(input);
+}
+
+/// Calculate the inverse FFT of the non-negative frequency bins of a real signal.
+/// - Unscaled, as [`super::ifft`].
+/// - Any output size.
+///
+/// The bins `N/2+1..N` are given by the Hermitian symmetry, `X[N-k] = X*[k]`.
+/// The imaginary parts of the DC bin, and of the Nyquist bin for even lengths, are ignored.
+///
+/// The function creates a new [`IrfftPlan`] each time it is called.
+/// Create a plan once, and reuse it, for repeating computations.
+///
+/// ## Arguments
+///
+/// * `spectrum` - The `N/2+1` non-negative frequency bins.
+/// * `len`      - The length of the real output, `N`.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+/// use ndsp::complex::{rfft, irfft};
+///
+/// let spectrum = rfft( &[1f64, 1f64, 1f64, 0f64] );
+/// let signal = irfft( spectrum.to_alloc_vec(), 4 );
+///
+/// assert_eq!( signal.len(), 4 );
+/// assert!( (signal[0]-1.0).abs() < 1e-12 );
+/// assert!(  signal[3].abs() < 1e-12 );
+/// ```
+pub fn irfft<T>( spectrum: &[Cartesian<T>], len: usize ) -> crate::Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    return IrfftPlan::new(len).process(spectrum);
+}
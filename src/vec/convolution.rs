@@ -0,0 +1,479 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// Kernels up to this length are convolved by direct summation, and longer kernels by FFT.
+const DIRECT_MAX_LEN: usize = 64;
+
+/// The part of the full convolution returned by [`Convolve::convolve`](crate::traits::Convolve::convolve) and [`Convolve::correlate`](crate::traits::Convolve::correlate).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvolutionMode {
+    /// The full convolution, of length `N+M-1`.
+    Full,
+    /// The center of the full convolution, of the length of the longer input, `max(N, M)`.
+    Same,
+    /// The part of the convolution computed without zero padding, of length `max(N, M)-min(N, M)+1`.
+    Valid,
+}
+
+impl ConvolutionMode {
+    /// The first index and the length of the part of the full convolution of inputs of length `n` and `m`.
+    fn range( &self, n: usize, m: usize ) -> (usize, usize)
+    {
+        let longer = n.max(m);
+        let shorter = n.min(m);
+        match self {
+            ConvolutionMode::Full  => return (0, n+m-1),
+            ConvolutionMode::Same  => return ((shorter-1)/2, longer),
+            ConvolutionMode::Valid => return (shorter-1, longer-shorter+1),
+        }
+    }
+}
+
+/// Add two complex numbers.
+#[inline(always)]
+fn complex_add<T: MixedReal + MixedOps>( a: Cartesian<T>, b: Cartesian<T> ) -> Cartesian<T>
+{
+    return Cartesian::new(a.re+b.re, a.im+b.im);
+}
+
+/// Multiply two complex numbers.
+#[inline(always)]
+fn complex_mul<T: MixedReal + MixedOps>( a: Cartesian<T>, b: Cartesian<T> ) -> Cartesian<T>
+{
+    return Cartesian::new(a.re*b.re - a.im*b.im, a.re*b.im + a.im*b.re);
+}
+
+/// The full convolution of complex sequences, by direct summation.
+fn direct_complex<T: MixedReal + MixedOps + MixedZero>( x: &[Cartesian<T>], h: &[Cartesian<T>] ) -> alloc::vec::Vec<Cartesian<T>>
+{
+    let len = x.len()+h.len()-1;
+    let mut y = alloc::vec::Vec::<Cartesian<T>>::with_capacity(len);
+    for n in 0..len
+    {
+        let first = if n+1 < h.len() { 0 } else { n+1-h.len() };
+        let last = n.min(x.len()-1);
+        let mut acc = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+        for k in first..=last
+        {
+            acc = complex_add(acc, complex_mul(x[k], h[n-k]));
+        }
+        y.push(acc);
+    }
+    return y;
+}
+
+/// The full convolution of real sequences, by direct summation.
+fn direct_real<T: MixedReal + MixedOps + MixedZero>( x: &[T], h: &[T] ) -> alloc::vec::Vec<T>
+{
+    let len = x.len()+h.len()-1;
+    let mut y = alloc::vec::Vec::<T>::with_capacity(len);
+    for n in 0..len
+    {
+        let first = if n+1 < h.len() { 0 } else { n+1-h.len() };
+        let last = n.min(x.len()-1);
+        let mut acc = T::mixed_zero();
+        for k in first..=last
+        {
+            acc = acc + x[k]*h[n-k];
+        }
+        y.push(acc);
+    }
+    return y;
+}
+
+/// The FFT of `h`, zero padded to `fft_len`, unscaled.
+fn kernel_spectrum<T>( h: &[Cartesian<T>], fft_len: usize ) -> Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    let mut spectrum = Vec::<Cartesian<T>>::new_with_capacity(fft_len);
+    for idx in 0..fft_len
+    {
+        spectrum.push_back(if idx < h.len() { h[idx] } else { Cartesian::new(T::mixed_zero(), T::mixed_zero()) });
+    }
+    FftPlan::<T>::new_with_scaling(fft_len, FftDirection::Forward, FftScaling::Unscaled).process_vec(&mut spectrum);
+    return spectrum;
+}
+
+/// The full convolution of complex sequences, by FFT with overlap-add.
+///
+/// The blocks of the longer sequence are transformed with `1/N` scaling, and the kernel without scaling, such that the result needs no rescaling.
+/// A single block is used when the sequences are of similar length.
+fn overlap_add<T>( x: &[Cartesian<T>], h: &[Cartesian<T>] ) -> alloc::vec::Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    let (x, h) = if x.len() < h.len() { (h, x) } else { (x, h) };
+    let full_len = x.len()+h.len()-1;
+    let fft_len = full_len.min(4*h.len()).next_power_of_two();
+    let block_len = fft_len-h.len()+1;
+
+    let spectrum = kernel_spectrum(h, fft_len);
+    let forward = FftPlan::<T>::new(fft_len, FftDirection::Forward);
+    let backward = FftPlan::<T>::new(fft_len, FftDirection::Backward);
+
+    let zero = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+    let mut y = alloc::vec![zero; full_len];
+    let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(fft_len);
+    let mut start = 0;
+    while start < x.len()
+    {
+        buffer.vec.clear();
+        for idx in 0..fft_len
+        {
+            buffer.push_back(if idx < block_len && start+idx < x.len() { x[start+idx] } else { zero });
+        }
+        forward.process_vec(&mut buffer);
+        for k in 0..fft_len
+        {
+            buffer[k] = complex_mul(buffer[k], spectrum[k]);
+        }
+        backward.process_vec(&mut buffer);
+
+        for idx in 0..fft_len.min(full_len-start)
+        {
+            y[start+idx] = complex_add(y[start+idx], buffer[idx]);
+        }
+        start = start+block_len;
+    }
+    return y;
+}
+
+impl <T> Convolve<T> for Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Convolve self with `kernel`, `y[n] = Σx[k]h[n-k]`.
+    ///
+    /// Short kernels are convolved by direct summation, and long kernels by FFT with overlap-add.
+    /// The convolution is commutative, hence either input may be the longer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The sequence to convolve with.
+    /// * `mode`   - The part of the full convolution to return.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let x = Vec::new_from_vec( vec![1f32, 2f32, 3f32] );
+    /// let h = Vec::new_from_vec( vec![0f32, 1f32, 0.5f32] );
+    ///
+    /// assert_eq!( x.convolve( &h, ConvolutionMode::Full ).to_string(),  "[ 0, 1, 2.5, 4, 1.5 ]" );
+    /// assert_eq!( x.convolve( &h, ConvolutionMode::Same ).to_string(),  "[ 1, 2.5, 4 ]" );
+    /// assert_eq!( x.convolve( &h, ConvolutionMode::Valid ).to_string(), "[ 2.5 ]" );
+    /// ```
+    fn convolve( &self, kernel: &Self, mode: ConvolutionMode ) -> Self
+    {
+        if self.len() == 0 || kernel.len() == 0
+        {
+            core::panic!("The inputs must have at least one sample.");
+        }
+
+        let (first, len) = mode.range(self.len(), kernel.len());
+        let mut r_vec = Vec::<T>::new_with_capacity(len);
+        if self.len().min(kernel.len()) <= DIRECT_MAX_LEN
+        {
+            let full = direct_real(&self.vec, &kernel.vec);
+            for idx in first..first+len
+            {
+                r_vec.push_back(full[idx]);
+            }
+        }
+        else
+        {
+            let full = overlap_add(&Vec::new_from_real(self.clone()).vec, &Vec::new_from_real(kernel.clone()).vec);
+            for idx in first..first+len
+            {
+                r_vec.push_back(full[idx].re);
+            }
+        }
+        return r_vec;
+    }
+
+    /// Correlate self with `other`, `y[k] = Σx[n+k]v[n]`, i.e. the convolution with `other` reversed.
+    ///
+    /// The full correlation holds the lags from `-(M-1)` to `N-1`, where `M` is the length of `other`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The sequence to correlate with.
+    /// * `mode`  - The part of the full correlation to return.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let x = Vec::new_from_vec( vec![0f64, 0f64, 1f64, 2f64, 0f64] );
+    /// let v = Vec::new_from_vec( vec![1f64, 2f64] );
+    ///
+    /// // The template is found at a lag of 2.
+    /// assert_eq!( x.correlate( &v, ConvolutionMode::Valid ).to_string(), "[ 0, 2, 5, 2 ]" );
+    /// ```
+    fn correlate( &self, other: &Self, mode: ConvolutionMode ) -> Self
+    {
+        let mut reversed = Vec::<T>::new_with_capacity(other.len());
+        for idx in (0..other.len()).rev()
+        {
+            reversed.push_back(other[idx]);
+        }
+        return self.convolve(&reversed, mode);
+    }
+}
+
+impl <T> Convolve<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Convolve self with `kernel`, `y[n] = Σx[k]h[n-k]`.
+    ///
+    /// Short kernels are convolved by direct summation, and long kernels by FFT with overlap-add.
+    /// The convolution is commutative, hence either input may be the longer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The sequence to convolve with.
+    /// * `mode`   - The part of the full convolution to return.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let x = Vec::osc( f64::mixed_tau()/8f64, 0f64, 1000 );
+    /// let h = Vec::<Cartesian<f64>>::new_from_real( Vec::ones(100) );
+    ///
+    /// let y = x.convolve( &h, ConvolutionMode::Valid );
+    ///
+    /// // A tone of 1/8 of the sample rate sums to zero over a multiple of 8 samples.
+    /// assert_eq!( y.len(), 901 );
+    /// assert!( (y[0].re-1f64).abs() < 1e-9 );
+    /// ```
+    fn convolve( &self, kernel: &Self, mode: ConvolutionMode ) -> Self
+    {
+        if self.len() == 0 || kernel.len() == 0
+        {
+            core::panic!("The inputs must have at least one sample.");
+        }
+
+        let full = if self.len().min(kernel.len()) <= DIRECT_MAX_LEN {
+            direct_complex(&self.vec, &kernel.vec)
+        } else {
+            overlap_add(&self.vec, &kernel.vec)
+        };
+
+        let (first, len) = mode.range(self.len(), kernel.len());
+        let mut r_vec = Vec::<Cartesian<T>>::new_with_capacity(len);
+        for idx in first..first+len
+        {
+            r_vec.push_back(full[idx]);
+        }
+        return r_vec;
+    }
+
+    /// Correlate self with `other`, `y[k] = Σx[n+k]v*[n]`, i.e. the convolution with `other` reversed and conjugated.
+    ///
+    /// The full correlation holds the lags from `-(M-1)` to `N-1`, where `M` is the length of `other`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The sequence to correlate with.
+    /// * `mode`  - The part of the full correlation to return.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let v = Vec::osc( 0.3f64, 0f64, 16 );
+    ///
+    /// // The correlation at zero lag is the energy of the sequence.
+    /// let y = v.correlate( &v, ConvolutionMode::Valid );
+    /// assert!( (y[0].re-16f64).abs() < 1e-12 );
+    /// assert!( y[0].im.abs() < 1e-12 );
+    /// ```
+    fn correlate( &self, other: &Self, mode: ConvolutionMode ) -> Self
+    {
+        let mut reversed = Vec::<Cartesian<T>>::new_with_capacity(other.len());
+        for idx in (0..other.len()).rev()
+        {
+            reversed.push_back(Cartesian::new(other[idx].re, T::mixed_zero()-other[idx].im));
+        }
+        return self.convolve(&reversed, mode);
+    }
+}
+
+/// Streaming FFT convolution by overlap-save, for long filters applied to continuous input.
+///
+/// The input is collected into blocks of `block_len` samples. Each block is transformed together with the last `M-1` samples of the previous blocks,
+/// multiplied with the spectrum of the kernel and transformed back, and the `block_len` samples not corrupted by circular wrap-around are output.
+///
+/// Outputs are produced a whole block at a time, hence each call returns a multiple of `block_len` samples, and the outputs lag the inputs by less than a block.
+/// The concatenated outputs equal the first samples of the full convolution of the concatenated inputs, as with [`Fir`].
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+/// use mixed_num::*;
+///
+/// let kernel = Vec::fir_kaiser( FilterBand::Lowpass(0.1f64), 0.02f64, 60f64 );
+/// let mut filter = OverlapSave::<f64>::new( &kernel );
+///
+/// let signal = Vec::osc( 0.4f64, 0f64, 4096 );
+/// let mut output = Vec::<Cartesian<f64>>::new_with_capacity(4096);
+/// for start in (0..4096).step_by(100)
+/// {
+///     let mut block = Vec::<Cartesian<f64>>::new_with_capacity(100);
+///     for idx in start..(start+100).min(4096)
+///     {
+///         block.push_back(signal[idx]);
+///     }
+///     let filtered = filter.process(&block);
+///     for idx in 0..filtered.len()
+///     {
+///         output.push_back(filtered[idx]);
+///     }
+/// }
+///
+/// let reference = signal.convolve( &Vec::new_from_real(kernel), ConvolutionMode::Full );
+/// assert_eq!( output.len() % filter.block_len(), 0 );
+/// for idx in 0..output.len()
+/// {
+///     assert!( (output[idx].re-reference[idx].re).abs() < 1e-9 );
+///     assert!( (output[idx].im-reference[idx].im).abs() < 1e-9 );
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OverlapSave<T> {
+    kernel_len: usize,
+    spectrum: Vec<Cartesian<T>>,
+    forward: FftPlan<T>,
+    backward: FftPlan<T>,
+    /// The last `M-1` samples of the previous blocks, followed by the samples of the current block.
+    buffer: Vec<Cartesian<T>>,
+    /// The work area of the transforms.
+    work: Vec<Cartesian<T>>,
+}
+
+impl <T> OverlapSave<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Create a filter with a real kernel. The FFT size is a power of two of at least four times the kernel length.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The impulse response of the filter, `h[0]` first.
+    ///
+    pub fn new( kernel: &Vec<T> ) -> OverlapSave<T>
+    {
+        return OverlapSave::new_complex(&Vec::new_from_real(kernel.clone()));
+    }
+
+    /// Create a filter with a complex kernel. The FFT size is a power of two of at least four times the kernel length.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The impulse response of the filter, `h[0]` first.
+    ///
+    pub fn new_complex( kernel: &Vec<Cartesian<T>> ) -> OverlapSave<T>
+    {
+        if kernel.len() == 0
+        {
+            core::panic!("The kernel must have at least one tap.");
+        }
+
+        let fft_len = (4*kernel.len()).next_power_of_two();
+        let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(fft_len);
+        for _idx in 0..kernel.len()-1
+        {
+            buffer.push_back(Cartesian::new(T::mixed_zero(), T::mixed_zero()));
+        }
+
+        return OverlapSave {
+            kernel_len: kernel.len(),
+            spectrum: kernel_spectrum(&kernel.vec, fft_len),
+            forward: FftPlan::new(fft_len, FftDirection::Forward),
+            backward: FftPlan::new(fft_len, FftDirection::Backward),
+            buffer: buffer,
+            work: Vec::new_with_capacity(fft_len),
+        };
+    }
+
+    /// The number of output samples produced per block.
+    pub fn block_len( &self ) -> usize
+    {
+        return self.spectrum.len()-self.kernel_len+1;
+    }
+
+    /// Clear the history and any partial block.
+    pub fn reset( &mut self )
+    {
+        self.buffer.vec.clear();
+        for _idx in 0..self.kernel_len-1
+        {
+            self.buffer.push_back(Cartesian::new(T::mixed_zero(), T::mixed_zero()));
+        }
+    }
+
+    /// Filter the next samples. Returns the output of each block completed by the samples. The state is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The next block of input samples, of any length.
+    ///
+    pub fn process( &mut self, input: &Vec<Cartesian<T>> ) -> Vec<Cartesian<T>>
+    {
+        let fft_len = self.spectrum.len();
+        let history_len = self.kernel_len-1;
+
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(input.len()+self.block_len());
+        for idx in 0..input.len()
+        {
+            self.buffer.push_back(input[idx]);
+            if self.buffer.len() < fft_len
+            {
+                continue;
+            }
+
+            self.work.vec.clear();
+            self.work.vec.extend_from_slice(&self.buffer.vec);
+            self.forward.process_vec(&mut self.work);
+            for k in 0..fft_len
+            {
+                self.work[k] = complex_mul(self.work[k], self.spectrum[k]);
+            }
+            self.backward.process_vec(&mut self.work);
+
+            // The first M-1 samples are corrupted by the circular wrap-around.
+            for k in history_len..fft_len
+            {
+                output.push_back(self.work[k]);
+            }
+            self.buffer.vec.drain(0..fft_len-history_len);
+        }
+        return output;
+    }
+
+    /// Filter the next samples of a real signal, with a real kernel. See [`OverlapSave::process`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The next block of input samples, of any length.
+    ///
+    pub fn process_real( &mut self, input: &Vec<T> ) -> Vec<T>
+    {
+        return self.process(&Vec::new_from_real(input.clone())).re();
+    }
+}
@@ -0,0 +1,310 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The normalization of a correlation estimate, see [`Xcorr`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XcorrScaling {
+    /// The raw sums of the products.
+    None,
+    /// Divided by the length `N`. Biased towards zero at large lags, but with lower variance.
+    Biased,
+    /// Divided by the number of products at each lag, `N-|k|`.
+    Unbiased,
+    /// Divided by the square root of the product of the energies, such that the auto-correlation is one at zero lag.
+    Coefficient,
+}
+
+/// The lags of a correlation, from `-max_lag` to `max_lag`.
+///
+/// ## Arguments
+///
+/// * `len`     - The length of the signals, `N`.
+/// * `max_lag` - The largest lag, or `N-1` if none.
+///
+fn lags( len: usize, max_lag: Option<usize> ) -> Vec<isize>
+{
+    let max_lag = match max_lag {
+        Some(max_lag) => max_lag,
+        None          => len-1,
+    } as isize;
+
+    let mut lags = Vec::<isize>::new_with_capacity((2*max_lag+1) as usize);
+    for lag in -max_lag..=max_lag
+    {
+        lags.push_back(lag);
+    }
+    return lags;
+}
+
+/// The factor scaling the correlation at `lag`.
+///
+/// ## Arguments
+///
+/// * `lag`     - The lag.
+/// * `len`     - The length of the signals, `N`.
+/// * `energy`  - The square root of the product of the energies of the signals.
+/// * `scaling` - The normalization.
+///
+fn lag_factor( lag: isize, len: usize, energy: f64, scaling: XcorrScaling ) -> f64
+{
+    match scaling {
+        XcorrScaling::None        => return 1f64,
+        XcorrScaling::Biased      => return 1f64/(len as f64),
+        XcorrScaling::Unbiased    => return 1f64/((len-lag.unsigned_abs()) as f64),
+        XcorrScaling::Coefficient => return if energy == 0f64 { 0f64 } else { 1f64/energy },
+    }
+}
+
+/// Zero pad a signal to `len`.
+fn zero_pad<S: Copy>( signal: &Vec<S>, len: usize, zero: S ) -> Vec<S>
+{
+    let mut padded = Vec::<S>::new_with_capacity(len);
+    for idx in 0..len
+    {
+        padded.push_back(if idx < signal.len() { signal[idx] } else { zero });
+    }
+    return padded;
+}
+
+impl <T> Xcorr<T> for Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the cross-correlation of self with `other`, `R[k] = Σx[n+k]y[n]`.
+    ///
+    /// The shorter signal is zero padded to the length `N` of the longer.
+    /// Returns the lags from `-max_lag` to `max_lag`, and the correlation at each lag, which is zero for lags beyond `N-1`.
+    /// A peak at a positive lag means that self is delayed relative to `other`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other`   - The signal to correlate with.
+    /// * `max_lag` - The largest lag, or `N-1` if none.
+    /// * `scaling` - The normalization of the correlation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let x = Vec::new_from_vec( vec![0f64, 0f64, 1f64, 2f64, 1f64] );
+    /// let y = Vec::new_from_vec( vec![1f64, 2f64, 1f64] );
+    ///
+    /// let (lags, r) = x.xcorr( &y, Some(3), XcorrScaling::None );
+    ///
+    /// assert_eq!( lags.to_string(), "[ -3, -2, -1, 0, 1, 2, 3 ]" );
+    /// assert_eq!( r.to_string(),    "[ 0, 0, 0, 1, 4, 6, 4 ]" );
+    /// ```
+    fn xcorr( &self, other: &Self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self)
+    {
+        let len = self.len().max(other.len());
+        if len == 0
+        {
+            core::panic!("The signals must have at least one sample.");
+        }
+
+        let x = zero_pad(self, len, T::mixed_zero());
+        let y = zero_pad(other, len, T::mixed_zero());
+        let full = x.correlate(&y, ConvolutionMode::Full);
+
+        let mut energy = 0f64;
+        if scaling == XcorrScaling::Coefficient
+        {
+            let mut energy_x = 0f64;
+            let mut energy_y = 0f64;
+            for idx in 0..len
+            {
+                let a: f64 = x[idx].mixed_to_num();
+                let b: f64 = y[idx].mixed_to_num();
+                energy_x = energy_x + a*a;
+                energy_y = energy_y + b*b;
+            }
+            energy = (energy_x*energy_y).mixed_sqrt();
+        }
+
+        let lags = lags(len, max_lag);
+        let mut r_vec = Vec::<T>::new_with_capacity(lags.len());
+        for idx in 0..lags.len()
+        {
+            let lag = lags[idx];
+            if (len as isize) <= lag.abs()
+            {
+                r_vec.push_back(T::mixed_zero());
+                continue;
+            }
+            let r = full[(lag + len as isize - 1) as usize];
+            let factor = lag_factor(lag, len, energy, scaling);
+            let scaled: f64 = r.mixed_to_num();
+            r_vec.push_back(if factor == 1f64 { r } else { T::mixed_from_num(scaled*factor) });
+        }
+        return (lags, r_vec);
+    }
+
+    /// Estimate the auto-correlation of self, `R[k] = Σx[n+k]x[n]`. See [`Xcorr::xcorr`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_lag` - The largest lag, or `N-1` if none.
+    /// * `scaling` - The normalization of the correlation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let x = Vec::new_from_vec( vec![1f64, 2f64, 3f64] );
+    ///
+    /// let (_lags, r) = x.autocorr( None, XcorrScaling::Unbiased );
+    /// assert_eq!( r[1], 4f64 );
+    /// assert!( (r[2]-14f64/3f64).abs() < 1e-12 );
+    ///
+    /// let (_lags, r) = x.autocorr( None, XcorrScaling::Coefficient );
+    /// assert_eq!( r[2], 1f64 );
+    /// ```
+    fn autocorr( &self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self)
+    {
+        return self.xcorr(self, max_lag, scaling);
+    }
+}
+
+impl <T> Xcorr<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the cross-correlation of self with `other`, `R[k] = Σx[n+k]y*[n]`.
+    ///
+    /// The shorter signal is zero padded to the length `N` of the longer.
+    /// Returns the lags from `-max_lag` to `max_lag`, and the correlation at each lag, which is zero for lags beyond `N-1`.
+    /// A peak at a positive lag means that self is delayed relative to `other`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other`   - The signal to correlate with.
+    /// * `max_lag` - The largest lag, or `N-1` if none.
+    /// * `scaling` - The normalization of the correlation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A preamble received 5 samples late, with a phase rotation.
+    /// let preamble = Vec::osc( 1f64, 0f64, 32 );
+    /// let mut received = Vec::<Cartesian<f64>>::zeros(64);
+    /// for idx in 0..32
+    /// {
+    ///     let x = preamble[idx];
+    ///     received[idx+5] = Cartesian::new( -x.im, x.re );
+    /// }
+    ///
+    /// let (lags, r) = received.xcorr( &preamble, Some(10), XcorrScaling::Coefficient );
+    /// let mut magnitude = r.clone();
+    /// magnitude.mag();
+    ///
+    /// assert_eq!( lags[magnitude.re().argmax_interpolated().round() as usize], 5 );
+    /// assert!( (r[15].im-1f64).abs() < 1e-12 );
+    /// ```
+    fn xcorr( &self, other: &Self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self)
+    {
+        let len = self.len().max(other.len());
+        if len == 0
+        {
+            core::panic!("The signals must have at least one sample.");
+        }
+
+        let zero = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+        let x = zero_pad(self, len, zero);
+        let y = zero_pad(other, len, zero);
+        let full = x.correlate(&y, ConvolutionMode::Full);
+
+        let mut energy = 0f64;
+        if scaling == XcorrScaling::Coefficient
+        {
+            let mut energy_x = 0f64;
+            let mut energy_y = 0f64;
+            for idx in 0..len
+            {
+                let (a_re, a_im): (f64, f64) = (x[idx].re.mixed_to_num(), x[idx].im.mixed_to_num());
+                let (b_re, b_im): (f64, f64) = (y[idx].re.mixed_to_num(), y[idx].im.mixed_to_num());
+                energy_x = energy_x + a_re*a_re + a_im*a_im;
+                energy_y = energy_y + b_re*b_re + b_im*b_im;
+            }
+            energy = (energy_x*energy_y).mixed_sqrt();
+        }
+
+        let lags = lags(len, max_lag);
+        let mut r_vec = Vec::<Cartesian<T>>::new_with_capacity(lags.len());
+        for idx in 0..lags.len()
+        {
+            let lag = lags[idx];
+            if (len as isize) <= lag.abs()
+            {
+                r_vec.push_back(zero);
+                continue;
+            }
+            let r = full[(lag + len as isize - 1) as usize];
+            let factor = lag_factor(lag, len, energy, scaling);
+            let (re, im): (f64, f64) = (r.re.mixed_to_num(), r.im.mixed_to_num());
+            r_vec.push_back(if factor == 1f64 { r } else { Cartesian::new(T::mixed_from_num(re*factor), T::mixed_from_num(im*factor)) });
+        }
+        return (lags, r_vec);
+    }
+
+    /// Estimate the auto-correlation of self, `R[k] = Σx[n+k]x*[n]`. See [`Xcorr::xcorr`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_lag` - The largest lag, or `N-1` if none.
+    /// * `scaling` - The normalization of the correlation.
+    ///
+    fn autocorr( &self, max_lag: Option<usize>, scaling: XcorrScaling ) -> (Vec<isize>, Self)
+    {
+        return self.xcorr(self, max_lag, scaling);
+    }
+}
+
+impl <T: MixedReal> Vec<T>
+{
+    /// Find the index of the maximum, refined to a fraction of a sample by fitting a parabola through the maximum and its neighbours.
+    ///
+    /// Used to estimate a time delay from the peak of a correlation, see [`Xcorr`].
+    /// The delay is the lag at the integer part of the index, plus the fractional part.
+    /// A maximum at either end is not refined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // Samples of a parabola with its peak at 2.3.
+    /// let mut y = Vec::<f64>::new_with_capacity(5);
+    /// for idx in 0..5
+    /// {
+    ///     y.push_back( 1f64 - (idx as f64 - 2.3f64).powi(2) );
+    /// }
+    ///
+    /// assert!( (y.argmax_interpolated()-2.3f64).abs() < 1e-12 );
+    /// ```
+    pub fn argmax_interpolated( &self ) -> T
+    {
+        let best = self.argmax();
+        if best == 0 || best == self.len()-1
+        {
+            return T::mixed_from_num(best as f64);
+        }
+
+        let left: f64 = self[best-1].mixed_to_num();
+        let center: f64 = self[best].mixed_to_num();
+        let right: f64 = self[best+1].mixed_to_num();
+        let curvature = left - 2f64*center + right;
+        let offset = if curvature == 0f64 { 0f64 } else { 0.5f64*(left-right)/curvature };
+        return T::mixed_from_num(best as f64 + offset);
+    }
+}
@@ -0,0 +1,76 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+use mixed_num::*;
+
+use crate::traits::FilterSample;
+
+pub mod fir;
+pub use fir::*;
+pub mod iir;
+pub use iir::*;
+pub mod design;
+pub use design::*;
+pub mod response;
+pub use response::*;
+
+/// Implement real samples with real coefficients of the same type.
+macro_rules! impl_filter_sample_for_real{
+    ( $T:ty ) => {
+        impl FilterSample<$T> for $T {
+            #[inline(always)]
+            fn mul_coefficient( self, coefficient: $T ) -> Self {
+                return self*coefficient;
+            }
+        }
+    }
+}
+
+impl_filter_sample_for_real!(f32);
+impl_filter_sample_for_real!(f64);
+impl_filter_sample_for_real!(i8);
+impl_filter_sample_for_real!(i16);
+impl_filter_sample_for_real!(i32);
+impl_filter_sample_for_real!(i64);
+
+/// Implement real fixed point samples with real coefficients of the same type.
+macro_rules! impl_filter_sample_for_fixed{
+    ( $T:ident ) => {
+        impl <Frac> FilterSample<fixed::$T<Frac>> for fixed::$T<Frac>
+            where fixed::$T<Frac>: MixedNum + MixedOps
+        {
+            #[inline(always)]
+            fn mul_coefficient( self, coefficient: fixed::$T<Frac> ) -> Self {
+                return self*coefficient;
+            }
+        }
+    }
+}
+
+impl_filter_sample_for_fixed!(FixedI8);
+impl_filter_sample_for_fixed!(FixedI16);
+impl_filter_sample_for_fixed!(FixedI32);
+impl_filter_sample_for_fixed!(FixedI64);
+impl_filter_sample_for_fixed!(FixedU8);
+impl_filter_sample_for_fixed!(FixedU16);
+impl_filter_sample_for_fixed!(FixedU32);
+impl_filter_sample_for_fixed!(FixedU64);
+
+#[allow(clippy::needless_return)]
+impl <T: MixedNum + MixedOps> FilterSample<T> for Cartesian<T> {
+    /// Complex samples with real coefficients.
+    #[inline(always)]
+    fn mul_coefficient( self, coefficient: T ) -> Self {
+        return Cartesian::new(self.re*coefficient, self.im*coefficient);
+    }
+}
+
+#[allow(clippy::needless_return)]
+impl <T: MixedNum + MixedNumSigned + MixedOps> FilterSample<Cartesian<T>> for Cartesian<T> {
+    /// Complex samples with complex coefficients.
+    #[inline(always)]
+    fn mul_coefficient( self, coefficient: Cartesian<T> ) -> Self {
+        return Cartesian::new(self.re*coefficient.re - self.im*coefficient.im, self.re*coefficient.im + self.im*coefficient.re);
+    }
+}
@@ -0,0 +1,258 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::vec::*;
+
+pub mod remez;
+pub use remez::*;
+pub mod iir;
+pub use iir::*;
+
+/// The pass band(s) of a filter to be designed.
+///
+/// The cutoff frequencies are normalized to the sample rate, in cycles per sample, such that they lie in `(0, 0.5)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FilterBand<T> {
+    /// Pass frequencies below the cutoff.
+    Lowpass(T),
+    /// Pass frequencies above the cutoff.
+    Highpass(T),
+    /// Pass frequencies between the lower and upper cutoff.
+    Bandpass(T, T),
+    /// Stop frequencies between the lower and upper cutoff.
+    Bandstop(T, T),
+}
+
+#[allow(clippy::needless_return)]
+impl <T: MixedReal> FilterBand<T> {
+    /// The cutoff frequencies as `f64`, validated against the Nyquist frequency.
+    fn cutoffs( &self ) -> (f64, f64)
+    {
+        let (lower, upper): (f64, f64) = match *self {
+            FilterBand::Lowpass(cutoff)         => (0f64, cutoff.mixed_to_num()),
+            FilterBand::Highpass(cutoff)        => (cutoff.mixed_to_num(), 0.5f64),
+            FilterBand::Bandpass(lower, upper)  => (lower.mixed_to_num(), upper.mixed_to_num()),
+            FilterBand::Bandstop(lower, upper)  => (lower.mixed_to_num(), upper.mixed_to_num()),
+        };
+
+        let valid = match *self {
+            FilterBand::Lowpass(_)  => 0f64 < upper && upper < 0.5f64,
+            FilterBand::Highpass(_) => 0f64 < lower && lower < 0.5f64,
+            _                       => 0f64 < lower && lower < upper && upper < 0.5f64,
+        };
+        if !valid
+        {
+            core::panic!("The cutoff frequencies must be increasing, and lie between 0 and 0.5 cycles per sample.");
+        }
+        return (lower, upper);
+    }
+
+    /// True if the response is non-zero at the Nyquist frequency.
+    fn passes_nyquist( &self ) -> bool
+    {
+        return matches!(self, FilterBand::Highpass(_) | FilterBand::Bandstop(_, _));
+    }
+}
+
+/// sin(πx)/(πx).
+#[allow(clippy::needless_return)]
+fn sinc( x: f64 ) -> f64
+{
+    if x == 0f64
+    {
+        return 1f64;
+    }
+    let arg = core::f64::consts::PI*x;
+    return arg.mixed_sin()/arg;
+}
+
+/// The impulse response of an ideal lowpass filter, at a delay `m`, which need not be an integer.
+#[allow(clippy::needless_return)]
+fn ideal_lowpass( cutoff: f64, m: f64 ) -> f64
+{
+    return 2f64*cutoff*sinc(2f64*cutoff*m);
+}
+
+/// Estimate the shape parameter `β` of a Kaiser window, for a stop band attenuation in dB.
+///
+/// Uses the empirical formula of Kaiser.
+/// For a peak ripple `δ` in both the pass band and stop band, the attenuation is `-20log10(δ)` dB.
+///
+/// ## Arguments
+///
+/// * `attenuation_db` - The stop band attenuation in dB, positive.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// assert!( (kaiser_beta(60f64)-5.65326f64).abs() < 1e-12 );
+/// assert_eq!( kaiser_beta(20f64), 0f64 );
+/// ```
+#[allow(clippy::needless_return)]
+pub fn kaiser_beta<T: MixedReal>( attenuation_db: T ) -> T
+{
+    let a: f64 = attenuation_db.mixed_to_num();
+    let beta = if 50f64 < a {
+        0.1102f64*(a-8.7f64)
+    } else if 21f64 <= a {
+        0.5842f64*(a-21f64).mixed_pow(0.4f64) + 0.07886f64*(a-21f64)
+    } else {
+        0f64
+    };
+    return T::mixed_from_num(beta);
+}
+
+/// Estimate the length of a Kaiser windowed FIR filter, from its transition width and attenuation.
+///
+/// Uses the empirical formula of Kaiser, `N = (A-7.95)/(14.36Δf) + 1`.
+/// For a peak ripple `δ` in both the pass band and stop band, the attenuation is `A = -20log10(δ)` dB.
+///
+/// ## Arguments
+///
+/// * `transition_width` - The width of the transition band(s), normalized to the sample rate.
+/// * `attenuation_db`   - The stop band attenuation in dB, positive.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // 0.01 ripple, i.e. 40 dB attenuation, over a transition of 5% of the sample rate.
+/// let len = kaiser_length( 0.05f64, -20f64*0.01f64.log10() );
+/// assert_eq!( len, 46 );
+/// ```
+#[allow(clippy::needless_return)]
+pub fn kaiser_length<T: MixedReal>( transition_width: T, attenuation_db: T ) -> usize
+{
+    let width: f64 = transition_width.mixed_to_num();
+    let a: f64 = attenuation_db.mixed_to_num();
+    if width <= 0f64
+    {
+        core::panic!("The transition width must be positive.");
+    }
+
+    let two_pi_width = 2f64*core::f64::consts::PI*width;
+    let len = if 21f64 < a {
+        (a-7.95f64)/(2.285f64*two_pi_width) + 1f64
+    } else {
+        5.79f64/two_pi_width + 1f64
+    };
+    return len.mixed_ceil() as usize;
+}
+
+#[allow(clippy::assign_op_pattern, clippy::needless_range_loop, clippy::needless_return)]
+impl <T: MixedReal> Vec<T>
+{
+    /// Design a linear phase FIR filter by the window method.
+    ///
+    /// The ideal (sinc) impulse response of the band is truncated by the window, which sets the length of the filter.
+    /// The taps are normalized to unit gain at DC for lowpass and bandstop filters, at the Nyquist frequency for highpass filters,
+    /// and at the center of the pass band for bandpass filters.
+    ///
+    /// Any window may be used, e.g. [`Vec::hamming`], [`Vec::blackman`] or [`Vec::kaiser`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `band`   - The pass band(s), normalized to the sample rate.
+    /// * `window` - The window. Highpass and bandstop filters require an odd length.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let taps = Vec::fir_windowed_sinc( FilterBand::Lowpass(0.1f64), &Vec::<f64>::hamming(31) );
+    ///
+    /// assert_eq!( taps.len(), 31 );
+    /// assert!( (taps.sum()-1f64).abs() < 1e-12 );
+    /// assert_eq!( taps[0], taps[30] );
+    /// ```
+    pub fn fir_windowed_sinc( band: FilterBand<T>, window: &Vec<T> ) -> Vec<T>
+    {
+        let len = window.len();
+        if len == 0
+        {
+            core::panic!("The window must have at least one sample.");
+        }
+        if band.passes_nyquist() && len.is_multiple_of(2)
+        {
+            core::panic!("Highpass and bandstop filters require an odd length, as even length linear phase filters have a zero at the Nyquist frequency.");
+        }
+
+        let (lower, upper) = band.cutoffs();
+        let center = ((len-1) as f64)/2f64;
+
+        let mut taps = alloc::vec::Vec::<f64>::with_capacity(len);
+        for n in 0..len
+        {
+            let m = n as f64 - center;
+            let ideal = match band {
+                FilterBand::Lowpass(_)     => ideal_lowpass(upper, m),
+                FilterBand::Highpass(_)    => ideal_lowpass(0.5f64, m) - ideal_lowpass(lower, m),
+                FilterBand::Bandpass(_, _) => ideal_lowpass(upper, m) - ideal_lowpass(lower, m),
+                FilterBand::Bandstop(_, _) => ideal_lowpass(0.5f64, m) - ideal_lowpass(upper, m) + ideal_lowpass(lower, m),
+            };
+            let w: f64 = window[n].mixed_to_num();
+            taps.push(ideal*w);
+        }
+
+        // Normalize the gain at the reference frequency. The response of the symmetric taps is real, relative to the linear phase.
+        let reference = match band {
+            FilterBand::Lowpass(_)     => 0f64,
+            FilterBand::Highpass(_)    => 0.5f64,
+            FilterBand::Bandpass(_, _) => (lower+upper)/2f64,
+            FilterBand::Bandstop(_, _) => 0f64,
+        };
+        let mut gain = 0f64;
+        for n in 0..len
+        {
+            gain = gain + taps[n]*(2f64*core::f64::consts::PI*reference*(n as f64 - center)).mixed_cos();
+        }
+
+        let mut r_vec = Vec::<T>::new_with_capacity(len);
+        for n in 0..len
+        {
+            r_vec.push_back(T::mixed_from_num(taps[n]/gain));
+        }
+        return r_vec;
+    }
+
+    /// Design a linear phase FIR filter by the window method, with a Kaiser window sized for the specification.
+    ///
+    /// The length is estimated by [`kaiser_length`], and `β` by [`kaiser_beta`].
+    /// The length is rounded up to odd for highpass and bandstop filters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `band`             - The pass band(s), normalized to the sample rate. The cutoffs are the centers of the transition bands.
+    /// * `transition_width` - The width of the transition band(s), normalized to the sample rate.
+    /// * `attenuation_db`   - The stop band attenuation in dB, positive.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let taps = Vec::fir_kaiser( FilterBand::Highpass(0.25f64), 0.05f64, 60f64 );
+    ///
+    /// assert_eq!( taps.len(), 75 );
+    /// assert!( taps.sum().abs() < 1e-3 );
+    /// ```
+    pub fn fir_kaiser( band: FilterBand<T>, transition_width: T, attenuation_db: T ) -> Vec<T>
+    {
+        let mut len = kaiser_length(transition_width, attenuation_db);
+        if band.passes_nyquist() && len.is_multiple_of(2)
+        {
+            len = len+1;
+        }
+        let window = Vec::<T>::kaiser(len, kaiser_beta(attenuation_db));
+        return Vec::fir_windowed_sinc(band, &window);
+    }
+}
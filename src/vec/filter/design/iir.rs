@@ -0,0 +1,830 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::vec::*;
+use super::FilterBand;
+
+/// The analog prototype of an IIR filter design.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IirPrototype<T> {
+    /// Maximally flat pass band.
+    Butterworth,
+    /// Equiripple pass band, with positive peak-to-peak ripple in dB. The cutoff is the edge of the pass band ripple.
+    Chebyshev1 { ripple_db: T },
+    /// Equiripple stop band, with positive attenuation in dB. The cutoff is the edge of the stop band.
+    Chebyshev2 { attenuation_db: T },
+    /// Equiripple pass and stop bands (Cauer), with ripple and attenuation in dB, `0 < ripple_db < attenuation_db`. The cutoff is the edge of the pass band ripple.
+    Elliptic { ripple_db: T, attenuation_db: T },
+}
+
+/// A filter represented by its zeros, poles and gain, `H = k·Π(x-zᵢ)/Π(x-pᵢ)`.
+///
+/// `x` is the Laplace variable `s` for analog filters, and `z` for digital filters.
+/// The zeros and poles are in complex conjugate pairs.
+///
+/// The analog prototypes have a cutoff of 1 rad/s.
+/// They are transformed to the desired band by [`Zpk::lowpass`], [`Zpk::highpass`], [`Zpk::bandpass`] or [`Zpk::bandstop`],
+/// and to digital filters by [`Zpk::bilinear`]. [`Zpk::design`] performs all steps, with prewarping of the band edges.
+///
+/// The calculations are done in double precision, independent of `T`.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // Fourth order Butterworth bandpass, from 0.1 to 0.2 of the sample rate, as two second-order sections.
+/// let zpk = Zpk::<f32>::design( 2, IirPrototype::Butterworth, FilterBand::Bandpass(0.1f32, 0.2f32) );
+/// assert_eq!( zpk.poles.len(), 4 );
+///
+/// let sos = zpk.to_sos();
+/// assert_eq!( sos.len(), 2 );
+///
+/// let mut filter = BiquadCascade::<f32, f32>::new( &sos, BiquadForm::DirectForm2Transposed );
+///
+/// let mut signal = Vec::<f32>::ones(512);
+/// filter.process( &mut signal );
+///
+/// // No DC passes a bandpass filter.
+/// assert!( signal[511].abs() < 1e-4 );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Zpk<T> {
+    /// The zeros of the transfer function.
+    pub zeros: Vec<Cartesian<T>>,
+    /// The poles of the transfer function.
+    pub poles: Vec<Cartesian<T>>,
+    /// The gain `k` of the transfer function.
+    pub gain: T,
+}
+
+/// The zeros, poles and gain in double precision, for the calculations.
+struct ZpkF64 {
+    zeros: alloc::vec::Vec<Cartesian<f64>>,
+    poles: alloc::vec::Vec<Cartesian<f64>>,
+    gain: f64,
+}
+
+/// a/b. The complex division is implemented here, as division by a `Cartesian` is not correct in mixed_num 0.5.
+fn div( a: Cartesian<f64>, b: Cartesian<f64> ) -> Cartesian<f64>
+{
+    let denominator = b.re*b.re + b.im*b.im;
+    return Cartesian::new((a.re*b.re + a.im*b.im)/denominator, (a.im*b.re - a.re*b.im)/denominator);
+}
+
+/// The principal square root.
+fn sqrt( a: Cartesian<f64> ) -> Cartesian<f64>
+{
+    let r = abs(a);
+    let re = ((r+a.re)/2f64).mixed_sqrt();
+    let im = ((r-a.re)/2f64).mixed_sqrt();
+    return Cartesian::new(re, if a.im < 0f64 { -im } else { im });
+}
+
+fn abs( a: Cartesian<f64> ) -> f64
+{
+    return (a.re*a.re + a.im*a.im).mixed_sqrt();
+}
+
+fn real( re: f64 ) -> Cartesian<f64>
+{
+    return Cartesian::new(re, 0f64);
+}
+
+/// Π(-xᵢ).
+fn product_of_negated( values: &[Cartesian<f64>] ) -> Cartesian<f64>
+{
+    let mut product = real(1f64);
+    for value in values
+    {
+        product = product*Cartesian::new(-value.re, -value.im);
+    }
+    return product;
+}
+
+/// Carlson's elliptic integral of the first kind, `RF(x, y, z)`, by the duplication theorem.
+///
+/// Each step reduces the spread of the arguments by a factor of four, so convergence takes about ten steps for valid arguments.
+/// The steps are capped, such that invalid arguments, e.g. negative or NaN, terminate with an invalid result.
+fn carlson_rf( x: f64, y: f64, z: f64 ) -> f64
+{
+    const MAX_ITERATIONS: usize = 64;
+
+    let mut x = x;
+    let mut y = y;
+    let mut z = z;
+    let mut mean = (x+y+z)/3f64;
+    let mut dx = 0f64;
+    let mut dy = 0f64;
+    let mut dz = 0f64;
+    for _ in 0..MAX_ITERATIONS
+    {
+        let sqrt_x = x.mixed_sqrt();
+        let sqrt_y = y.mixed_sqrt();
+        let sqrt_z = z.mixed_sqrt();
+        let lambda = sqrt_x*(sqrt_y+sqrt_z) + sqrt_y*sqrt_z;
+        x = (x+lambda)/4f64;
+        y = (y+lambda)/4f64;
+        z = (z+lambda)/4f64;
+
+        mean = (x+y+z)/3f64;
+        dx = (mean-x)/mean;
+        dy = (mean-y)/mean;
+        dz = (mean-z)/mean;
+        if dx.mixed_abs() < 1e-3 && dy.mixed_abs() < 1e-3 && dz.mixed_abs() < 1e-3
+        {
+            break;
+        }
+    }
+    let e2 = dx*dy - dz*dz;
+    let e3 = dx*dy*dz;
+    return (1f64 + (e2/24f64 - 0.1f64 - 3f64*e3/44f64)*e2 + e3/14f64)/mean.mixed_sqrt();
+}
+
+/// The complete elliptic integral of the first kind, `K(m)`, for the complementary parameter `m1 = 1-m`.
+fn ellipk_complement( m1: f64 ) -> f64
+{
+    return carlson_rf(0f64, m1, 1f64);
+}
+
+/// The complete elliptic integral of the first kind, `K(m)`.
+fn ellipk( m: f64 ) -> f64
+{
+    return ellipk_complement(1f64-m);
+}
+
+/// The incomplete elliptic integral of the first kind, `F(φ|m)`, for the complementary parameter `m1 = 1-m`.
+fn ellipf_complement( phi: f64, m1: f64 ) -> f64
+{
+    let s = phi.mixed_sin();
+    let c = phi.mixed_cos();
+    return s*carlson_rf(c*c, c*c + m1*s*s, 1f64);
+}
+
+/// The Jacobi elliptic functions `sn`, `cn` and `dn` of `u` with parameter `m`, by the arithmetic-geometric mean.
+fn ellipj( u: f64, m: f64 ) -> (f64, f64, f64)
+{
+    if m < 1e-9
+    {
+        let t = u.mixed_sin();
+        let b = u.mixed_cos();
+        let ai = 0.25f64*m*(u - t*b);
+        return (t - ai*b, b + ai*t, 1f64 - 0.5f64*m*t*t);
+    }
+    if 0.9999999999f64 <= m
+    {
+        let sech = 1f64/u.mixed_cosh();
+        return (u.mixed_tanh(), sech, sech);
+    }
+
+    let mut a = [0f64; 9];
+    let mut c = [0f64; 9];
+    a[0] = 1f64;
+    c[0] = m.mixed_sqrt();
+    let mut b = (1f64-m).mixed_sqrt();
+    let mut two_n = 1f64;
+    let mut i = 0;
+    while 1e-16 < (c[i]/a[i]).mixed_abs() && i < 8
+    {
+        let ai = a[i];
+        i += 1;
+        c[i] = (ai-b)/2f64;
+        let t = (ai*b).mixed_sqrt();
+        a[i] = (ai+b)/2f64;
+        b = t;
+        two_n = two_n*2f64;
+    }
+
+    let mut phi = two_n*a[i]*u;
+    let mut previous;
+    loop
+    {
+        let t = c[i]*phi.mixed_sin()/a[i];
+        previous = phi;
+        phi = (t.mixed_asin()+phi)/2f64;
+        i -= 1;
+        if i == 0
+        {
+            break;
+        }
+    }
+    let cn = phi.mixed_cos();
+    return (phi.mixed_sin(), cn, cn/(phi-previous).mixed_cos());
+}
+
+/// Solve the degree equation of elliptic filters for the parameter `m`, from the order and `m1`, using nomes.
+fn ellipdeg( order: usize, m1: f64 ) -> f64
+{
+    let q1 = (-core::f64::consts::PI*ellipk_complement(m1)/ellipk(m1)).mixed_exp();
+    let q = q1.mixed_pow(1f64/(order as f64));
+
+    let mut numerator = 0f64;
+    for m in 0..8
+    {
+        numerator = numerator + q.mixed_pow((m*(m+1)) as f64);
+    }
+    let mut denominator = 1f64;
+    for m in 1..9
+    {
+        denominator = denominator + 2f64*q.mixed_pow((m*m) as f64);
+    }
+    let ratio = numerator/denominator;
+    return 16f64*q*ratio*ratio*ratio*ratio;
+}
+
+/// The odd integers `-N+1, -N+3, ..., N-1`, which index the poles of the prototypes.
+fn pole_indices( order: usize ) -> alloc::vec::Vec<f64>
+{
+    let mut indices = alloc::vec::Vec::<f64>::with_capacity(order);
+    for k in 0..order
+    {
+        indices.push((2*k) as f64 - (order as f64) + 1f64);
+    }
+    return indices;
+}
+
+impl ZpkF64 {
+    fn butterworth( order: usize ) -> ZpkF64
+    {
+        let mut poles = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        for m in pole_indices(order)
+        {
+            let theta = core::f64::consts::PI*m/(2f64*(order as f64));
+            poles.push(Cartesian::new(-theta.mixed_cos(), -theta.mixed_sin()));
+        }
+        return ZpkF64 { zeros: alloc::vec::Vec::new(), poles: poles, gain: 1f64 };
+    }
+
+    fn chebyshev1( order: usize, ripple_db: f64 ) -> ZpkF64
+    {
+        if !ripple_db.is_finite() || ripple_db <= 0f64
+        {
+            core::panic!("The ripple must be finite and positive, got {} dB.", ripple_db);
+        }
+
+        let eps_sq = (ripple_db/10f64).mixed_exp10() - 1f64;
+        let mu = (1f64/eps_sq.mixed_sqrt()).mixed_asinh()/(order as f64);
+
+        let mut poles = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        for m in pole_indices(order)
+        {
+            // -sinh(μ+jθ)
+            let theta = core::f64::consts::PI*m/(2f64*(order as f64));
+            poles.push(Cartesian::new(-mu.mixed_sinh()*theta.mixed_cos(), -mu.mixed_cosh()*theta.mixed_sin()));
+        }
+
+        let mut gain = product_of_negated(&poles).re;
+        if order.is_multiple_of(2)
+        {
+            gain = gain/(1f64+eps_sq).mixed_sqrt();
+        }
+        return ZpkF64 { zeros: alloc::vec::Vec::new(), poles: poles, gain: gain };
+    }
+
+    fn chebyshev2( order: usize, attenuation_db: f64 ) -> ZpkF64
+    {
+        if !attenuation_db.is_finite() || attenuation_db <= 0f64
+        {
+            core::panic!("The attenuation must be finite and positive, got {} dB.", attenuation_db);
+        }
+
+        let de = 1f64/((attenuation_db/10f64).mixed_exp10() - 1f64).mixed_sqrt();
+        let mu = (1f64/de).mixed_asinh()/(order as f64);
+
+        let mut zeros = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        let mut poles = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        for m in pole_indices(order)
+        {
+            let theta = core::f64::consts::PI*m/(2f64*(order as f64));
+            if m != 0f64
+            {
+                zeros.push(Cartesian::new(0f64, 1f64/theta.mixed_sin()));
+            }
+            let pole = Cartesian::new(-mu.mixed_sinh()*theta.mixed_cos(), -mu.mixed_cosh()*theta.mixed_sin());
+            poles.push(div(real(1f64), pole));
+        }
+
+        let gain = div(product_of_negated(&poles), product_of_negated(&zeros)).re;
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    fn elliptic( order: usize, ripple_db: f64, attenuation_db: f64 ) -> ZpkF64
+    {
+        if !ripple_db.is_finite() || !attenuation_db.is_finite() || ripple_db <= 0f64 || attenuation_db <= ripple_db
+        {
+            core::panic!("The ripple and attenuation must be finite, with 0 < ripple < attenuation, got ripple {} dB and attenuation {} dB.", ripple_db, attenuation_db);
+        }
+
+        let eps_sq = (ripple_db/10f64).mixed_exp10() - 1f64;
+        if order == 1
+        {
+            let pole = -(1f64/eps_sq).mixed_sqrt();
+            return ZpkF64 { zeros: alloc::vec::Vec::new(), poles: alloc::vec![real(pole)], gain: -pole };
+        }
+
+        let eps = eps_sq.mixed_sqrt();
+        let ck1_sq = eps_sq/((attenuation_db/10f64).mixed_exp10() - 1f64);
+        if ck1_sq == 0f64
+        {
+            core::panic!("The attenuation is too large for the ripple.");
+        }
+
+        let m = ellipdeg(order, ck1_sq);
+        let capk = ellipk(m);
+
+        // sc⁻¹(1/ε) with the complementary parameter, i.e. F(atan(1/ε)|1-ck1²).
+        let r = ellipf_complement((1f64/eps).mixed_atan(), ck1_sq);
+        let v0 = capk*r/((order as f64)*ellipk(ck1_sq));
+        let (sv, cv, dv) = ellipj(v0, 1f64-m);
+
+        let mut zeros = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        let mut poles = alloc::vec::Vec::<Cartesian<f64>>::with_capacity(order);
+        let mut j = if order.is_multiple_of(2) { 1 } else { 0 };
+        while j < order
+        {
+            let (s, c, d) = ellipj((j as f64)*capk/(order as f64), m);
+            if 1e-14 < s.mixed_abs()
+            {
+                let zero = 1f64/(m.mixed_sqrt()*s);
+                zeros.push(Cartesian::new(0f64, zero));
+                zeros.push(Cartesian::new(0f64, -zero));
+            }
+
+            let denominator = 1f64 - (d*sv)*(d*sv);
+            let pole = Cartesian::new(-c*d*sv*cv/denominator, -s*dv/denominator);
+            poles.push(pole);
+            if j != 0
+            {
+                poles.push(Cartesian::new(pole.re, -pole.im));
+            }
+            j += 2;
+        }
+
+        let mut gain = div(product_of_negated(&poles), product_of_negated(&zeros)).re;
+        if order.is_multiple_of(2)
+        {
+            gain = gain/(1f64+eps_sq).mixed_sqrt();
+        }
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    /// The number of zeros at infinity.
+    fn degree( &self ) -> usize
+    {
+        return self.poles.len() - self.zeros.len();
+    }
+
+    fn lowpass( &self, cutoff: f64 ) -> ZpkF64
+    {
+        let mut zeros = alloc::vec::Vec::with_capacity(self.zeros.len());
+        for zero in &self.zeros
+        {
+            zeros.push(Cartesian::new(zero.re*cutoff, zero.im*cutoff));
+        }
+        let mut poles = alloc::vec::Vec::with_capacity(self.poles.len());
+        for pole in &self.poles
+        {
+            poles.push(Cartesian::new(pole.re*cutoff, pole.im*cutoff));
+        }
+        let gain = self.gain*cutoff.mixed_powi(self.degree() as i32);
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    fn highpass( &self, cutoff: f64 ) -> ZpkF64
+    {
+        let mut zeros = alloc::vec::Vec::with_capacity(self.poles.len());
+        for zero in &self.zeros
+        {
+            zeros.push(div(real(cutoff), *zero));
+        }
+        // The zeros at infinity move to the origin.
+        for _idx in 0..self.degree()
+        {
+            zeros.push(real(0f64));
+        }
+        let mut poles = alloc::vec::Vec::with_capacity(self.poles.len());
+        for pole in &self.poles
+        {
+            poles.push(div(real(cutoff), *pole));
+        }
+        let gain = self.gain*div(product_of_negated(&self.zeros), product_of_negated(&self.poles)).re;
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    /// Replace each root `x` by the roots of `s² - xs + ω₀² = 0`, i.e. `x/2 ± √((x/2)² - ω₀²)`.
+    fn split_roots( roots: &[Cartesian<f64>], scale: f64, center: f64, invert: bool ) -> alloc::vec::Vec<Cartesian<f64>>
+    {
+        let mut split = alloc::vec::Vec::with_capacity(2*roots.len());
+        for root in roots
+        {
+            let x = if invert { div(real(scale), *root) } else { Cartesian::new(root.re*scale, root.im*scale) };
+            let offset = sqrt(x*x - real(center*center));
+            split.push(x + offset);
+            split.push(x - offset);
+        }
+        return split;
+    }
+
+    fn bandpass( &self, center: f64, bandwidth: f64 ) -> ZpkF64
+    {
+        let mut zeros = ZpkF64::split_roots(&self.zeros, bandwidth/2f64, center, false);
+        let poles = ZpkF64::split_roots(&self.poles, bandwidth/2f64, center, false);
+        for _idx in 0..self.degree()
+        {
+            zeros.push(real(0f64));
+        }
+        let gain = self.gain*bandwidth.mixed_powi(self.degree() as i32);
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    fn bandstop( &self, center: f64, bandwidth: f64 ) -> ZpkF64
+    {
+        let mut zeros = ZpkF64::split_roots(&self.zeros, bandwidth/2f64, center, true);
+        let poles = ZpkF64::split_roots(&self.poles, bandwidth/2f64, center, true);
+        // The zeros at infinity move to ±jω₀.
+        for _idx in 0..self.degree()
+        {
+            zeros.push(Cartesian::new(0f64, center));
+            zeros.push(Cartesian::new(0f64, -center));
+        }
+        let gain = self.gain*div(product_of_negated(&self.zeros), product_of_negated(&self.poles)).re;
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+
+    fn bilinear( &self, sample_rate: f64 ) -> ZpkF64
+    {
+        let fs2 = real(2f64*sample_rate);
+
+        let mut zeros = alloc::vec::Vec::with_capacity(self.poles.len());
+        let mut zeros_product = real(1f64);
+        for zero in &self.zeros
+        {
+            zeros.push(div(fs2 + *zero, fs2 - *zero));
+            zeros_product = zeros_product*(fs2 - *zero);
+        }
+        // The zeros at infinity move to the Nyquist frequency.
+        for _idx in 0..self.degree()
+        {
+            zeros.push(real(-1f64));
+        }
+
+        let mut poles = alloc::vec::Vec::with_capacity(self.poles.len());
+        let mut poles_product = real(1f64);
+        for pole in &self.poles
+        {
+            poles.push(div(fs2 + *pole, fs2 - *pole));
+            poles_product = poles_product*(fs2 - *pole);
+        }
+
+        let gain = self.gain*div(zeros_product, poles_product).re;
+        return ZpkF64 { zeros: zeros, poles: poles, gain: gain };
+    }
+}
+
+/// Group roots in complex conjugate pairs and pairs of real roots, each represented by its polynomial `[1, c1, c2]`.
+/// A single remaining real root is represented by `[1, c1, 0]`.
+fn group_roots( roots: &[Cartesian<f64>] ) -> alloc::vec::Vec<(Cartesian<f64>, [f64; 3])>
+{
+    let mut groups = alloc::vec::Vec::new();
+    let mut reals = alloc::vec::Vec::<f64>::new();
+    for root in roots
+    {
+        let tolerance = 1e-10*(1f64 + abs(*root));
+        if tolerance < root.im
+        {
+            // (x-r)(x-r*) = x² - 2Re(r)x + |r|²
+            groups.push((*root, [1f64, -2f64*root.re, root.re*root.re + root.im*root.im]));
+        }
+        else if root.im.mixed_abs() <= tolerance
+        {
+            reals.push(root.re);
+        }
+    }
+
+    // Pair real roots of similar magnitude.
+    reals.sort_by(|a, b| b.mixed_abs().partial_cmp(&a.mixed_abs()).unwrap_or(core::cmp::Ordering::Equal));
+    let mut idx = 0;
+    while idx < reals.len()
+    {
+        if idx+1 < reals.len()
+        {
+            let (a, b) = (reals[idx], reals[idx+1]);
+            let representative = if b.mixed_abs() < a.mixed_abs() { a } else { b };
+            groups.push((real(representative), [1f64, -(a+b), a*b]));
+        }
+        else
+        {
+            groups.push((real(reals[idx]), [1f64, -reals[idx], 0f64]));
+        }
+        idx += 2;
+    }
+    return groups;
+}
+
+impl <T: MixedReal> Zpk<T>
+{
+    fn from_f64( zpk: ZpkF64 ) -> Zpk<T>
+    {
+        let mut zeros = Vec::<Cartesian<T>>::new_with_capacity(zpk.zeros.len());
+        for zero in &zpk.zeros
+        {
+            zeros.push_back(Cartesian::new(T::mixed_from_num(zero.re), T::mixed_from_num(zero.im)));
+        }
+        let mut poles = Vec::<Cartesian<T>>::new_with_capacity(zpk.poles.len());
+        for pole in &zpk.poles
+        {
+            poles.push_back(Cartesian::new(T::mixed_from_num(pole.re), T::mixed_from_num(pole.im)));
+        }
+        return Zpk { zeros: zeros, poles: poles, gain: T::mixed_from_num(zpk.gain) };
+    }
+
+    fn to_f64( &self ) -> ZpkF64
+    {
+        let mut zeros = alloc::vec::Vec::with_capacity(self.zeros.len());
+        for idx in 0..self.zeros.len()
+        {
+            zeros.push(Cartesian::new(self.zeros[idx].re.mixed_to_num(), self.zeros[idx].im.mixed_to_num()));
+        }
+        let mut poles = alloc::vec::Vec::with_capacity(self.poles.len());
+        for idx in 0..self.poles.len()
+        {
+            poles.push(Cartesian::new(self.poles[idx].re.mixed_to_num(), self.poles[idx].im.mixed_to_num()));
+        }
+        return ZpkF64 { zeros: zeros, poles: poles, gain: self.gain.mixed_to_num() };
+    }
+
+    /// Analog Butterworth lowpass prototype.
+    ///
+    /// ## Arguments
+    ///
+    /// * `order` - The order of the filter.
+    ///
+    pub fn butterworth( order: usize ) -> Zpk<T>
+    {
+        if order == 0
+        {
+            core::panic!("The order must be at least one.");
+        }
+        return Zpk::from_f64(ZpkF64::butterworth(order));
+    }
+
+    /// Analog Chebyshev type I lowpass prototype, with the edge of the pass band ripple at 1 rad/s.
+    ///
+    /// ## Arguments
+    ///
+    /// * `order`     - The order of the filter.
+    /// * `ripple_db` - The peak-to-peak ripple in the pass band, in dB. Must be positive.
+    ///
+    pub fn chebyshev1( order: usize, ripple_db: T ) -> Zpk<T>
+    {
+        if order == 0
+        {
+            core::panic!("The order must be at least one.");
+        }
+        return Zpk::from_f64(ZpkF64::chebyshev1(order, ripple_db.mixed_to_num()));
+    }
+
+    /// Analog Chebyshev type II lowpass prototype, with the edge of the stop band at 1 rad/s.
+    ///
+    /// ## Arguments
+    ///
+    /// * `order`          - The order of the filter.
+    /// * `attenuation_db` - The minimum attenuation in the stop band, in dB. Must be positive.
+    ///
+    pub fn chebyshev2( order: usize, attenuation_db: T ) -> Zpk<T>
+    {
+        if order == 0
+        {
+            core::panic!("The order must be at least one.");
+        }
+        return Zpk::from_f64(ZpkF64::chebyshev2(order, attenuation_db.mixed_to_num()));
+    }
+
+    /// Analog elliptic (Cauer) lowpass prototype, with the edge of the pass band ripple at 1 rad/s.
+    ///
+    /// ## Arguments
+    ///
+    /// * `order`          - The order of the filter.
+    /// * `ripple_db`      - The peak-to-peak ripple in the pass band, in dB. Must be positive.
+    /// * `attenuation_db` - The minimum attenuation in the stop band, in dB. Must be larger than the ripple.
+    ///
+    pub fn elliptic( order: usize, ripple_db: T, attenuation_db: T ) -> Zpk<T>
+    {
+        if order == 0
+        {
+            core::panic!("The order must be at least one.");
+        }
+        return Zpk::from_f64(ZpkF64::elliptic(order, ripple_db.mixed_to_num(), attenuation_db.mixed_to_num()));
+    }
+
+    /// Transform an analog lowpass prototype to a lowpass filter with another cutoff, `s → s/ωc`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cutoff` - The cutoff in rad/s.
+    ///
+    pub fn lowpass( &self, cutoff: T ) -> Zpk<T>
+    {
+        return Zpk::from_f64(self.to_f64().lowpass(cutoff.mixed_to_num()));
+    }
+
+    /// Transform an analog lowpass prototype to a highpass filter, `s → ωc/s`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cutoff` - The cutoff in rad/s.
+    ///
+    pub fn highpass( &self, cutoff: T ) -> Zpk<T>
+    {
+        return Zpk::from_f64(self.to_f64().highpass(cutoff.mixed_to_num()));
+    }
+
+    /// Transform an analog lowpass prototype to a bandpass filter, `s → (s²+ω₀²)/(sB)`. The order is doubled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center`    - The center frequency ω₀ in rad/s, the geometric mean of the band edges.
+    /// * `bandwidth` - The bandwidth B in rad/s.
+    ///
+    pub fn bandpass( &self, center: T, bandwidth: T ) -> Zpk<T>
+    {
+        return Zpk::from_f64(self.to_f64().bandpass(center.mixed_to_num(), bandwidth.mixed_to_num()));
+    }
+
+    /// Transform an analog lowpass prototype to a bandstop filter, `s → sB/(s²+ω₀²)`. The order is doubled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center`    - The center frequency ω₀ in rad/s, the geometric mean of the band edges.
+    /// * `bandwidth` - The bandwidth B in rad/s.
+    ///
+    pub fn bandstop( &self, center: T, bandwidth: T ) -> Zpk<T>
+    {
+        return Zpk::from_f64(self.to_f64().bandstop(center.mixed_to_num(), bandwidth.mixed_to_num()));
+    }
+
+    /// Transform an analog filter to a digital filter by the bilinear transform, `s → 2fs(z-1)/(z+1)`.
+    ///
+    /// The frequency axis is warped, see [`prewarp`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `sample_rate` - The sample rate of the digital filter.
+    ///
+    pub fn bilinear( &self, sample_rate: T ) -> Zpk<T>
+    {
+        return Zpk::from_f64(self.to_f64().bilinear(sample_rate.mixed_to_num()));
+    }
+
+    /// Design a digital IIR filter from an analog prototype, by the bilinear transform with prewarped band edges.
+    ///
+    /// Bandpass and bandstop filters are of twice the prototype order.
+    ///
+    /// ## Arguments
+    ///
+    /// * `order`     - The order of the prototype.
+    /// * `prototype` - The analog prototype.
+    /// * `band`      - The band edges, normalized to the sample rate. See [`IirPrototype`] for their meaning.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let sos = Zpk::<f64>::design( 2, IirPrototype::Butterworth, FilterBand::Lowpass(0.25f64) ).to_sos();
+    ///
+    /// let expected = [0.29289321881345254, 0.585786437626905, 0.29289321881345254, 1f64, 0f64, 0.17157287525380988];
+    /// for idx in 0..6 {
+    ///     assert!( (sos[0][idx]-expected[idx]).abs() < 1e-12 );
+    /// }
+    /// ```
+    pub fn design( order: usize, prototype: IirPrototype<T>, band: FilterBand<T> ) -> Zpk<T>
+    {
+        if order == 0
+        {
+            core::panic!("The order must be at least one.");
+        }
+
+        let analog = match prototype {
+            IirPrototype::Butterworth => ZpkF64::butterworth(order),
+            IirPrototype::Chebyshev1 { ripple_db } => ZpkF64::chebyshev1(order, ripple_db.mixed_to_num()),
+            IirPrototype::Chebyshev2 { attenuation_db } => ZpkF64::chebyshev2(order, attenuation_db.mixed_to_num()),
+            IirPrototype::Elliptic { ripple_db, attenuation_db } => ZpkF64::elliptic(order, ripple_db.mixed_to_num(), attenuation_db.mixed_to_num()),
+        };
+
+        let (lower, upper) = band.cutoffs();
+        let warp = |f: f64| -> f64 { 2f64*(core::f64::consts::PI*f).mixed_tan() };
+        let analog = match band {
+            FilterBand::Lowpass(_)     => analog.lowpass(warp(upper)),
+            FilterBand::Highpass(_)    => analog.highpass(warp(lower)),
+            FilterBand::Bandpass(_, _) => analog.bandpass((warp(lower)*warp(upper)).mixed_sqrt(), warp(upper)-warp(lower)),
+            FilterBand::Bandstop(_, _) => analog.bandstop((warp(lower)*warp(upper)).mixed_sqrt(), warp(upper)-warp(lower)),
+        };
+        return Zpk::from_f64(analog.bilinear(1f64));
+    }
+
+    /// Convert a digital filter to second-order sections `[b0, b1, b2, a0, a1, a2]`, e.g. for [`BiquadCascade`](crate::BiquadCascade).
+    ///
+    /// Poles are grouped in conjugate pairs, and paired with the nearest zeros.
+    /// The sections are ordered with the poles closest to the unit circle last, and the gain is applied in the first section.
+    pub fn to_sos( &self ) -> alloc::vec::Vec<[T; 6]>
+    {
+        let zpk = self.to_f64();
+
+        let mut pole_groups = group_roots(&zpk.poles);
+        let mut zero_groups = group_roots(&zpk.zeros);
+
+        // Pair the poles closest to the unit circle first.
+        pole_groups.sort_by(|a, b| abs(b.0).partial_cmp(&abs(a.0)).unwrap_or(core::cmp::Ordering::Equal));
+
+        let mut sections = alloc::vec::Vec::<[f64; 6]>::with_capacity(pole_groups.len());
+        for (pole, a) in &pole_groups
+        {
+            let order = if a[2] == 0f64 { 1 } else { 2 };
+
+            // The nearest zeros, preferring groups of the same order.
+            let mut nearest: Option<usize> = None;
+            for idx in 0..zero_groups.len()
+            {
+                let zero_order = if zero_groups[idx].1[2] == 0f64 { 1 } else { 2 };
+                let better = match nearest {
+                    None => true,
+                    Some(best) => {
+                        let best_order = if zero_groups[best].1[2] == 0f64 { 1 } else { 2 };
+                        let same  = zero_order == order;
+                        let best_same = best_order == order;
+                        (same && !best_same) || (same == best_same && abs(zero_groups[idx].0 - *pole) < abs(zero_groups[best].0 - *pole))
+                    }
+                };
+                if better
+                {
+                    nearest = Some(idx);
+                }
+            }
+
+            let b = match nearest {
+                Some(idx) => zero_groups.remove(idx).1,
+                None      => [1f64, 0f64, 0f64],
+            };
+            sections.push([b[0], b[1], b[2], a[0], a[1], a[2]]);
+        }
+
+        // Remaining zeros, e.g. when there are more zeros than poles, in sections without poles.
+        for (_zero, b) in &zero_groups
+        {
+            sections.push([b[0], b[1], b[2], 1f64, 0f64, 0f64]);
+        }
+
+        sections.reverse();
+        if sections.is_empty()
+        {
+            sections.push([1f64, 0f64, 0f64, 1f64, 0f64, 0f64]);
+        }
+        for idx in 0..3
+        {
+            sections[0][idx] = sections[0][idx]*zpk.gain;
+        }
+
+        let mut sos = alloc::vec::Vec::<[T; 6]>::with_capacity(sections.len());
+        for section in &sections
+        {
+            let mut converted = [T::mixed_from_num(0f64); 6];
+            for idx in 0..6
+            {
+                converted[idx] = T::mixed_from_num(section[idx]);
+            }
+            sos.push(converted);
+        }
+        return sos;
+    }
+}
+
+/// Prewarp a frequency for the bilinear transform, `ωa = 2fs·tan(πf/fs)`.
+///
+/// The analog frequency `ωa` (rad/s) is mapped to the digital frequency `f` by the bilinear transform.
+///
+/// ## Arguments
+///
+/// * `frequency`   - The digital frequency, in the unit of the sample rate.
+/// * `sample_rate` - The sample rate.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// assert!( (prewarp(0.25f64, 1f64)-2f64).abs() < 1e-12 );
+/// ```
+pub fn prewarp<T: MixedReal>( frequency: T, sample_rate: T ) -> T
+{
+    let frequency: f64 = frequency.mixed_to_num();
+    let sample_rate: f64 = sample_rate.mixed_to_num();
+    return T::mixed_from_num(2f64*sample_rate*(core::f64::consts::PI*frequency/sample_rate).mixed_tan());
+}
@@ -0,0 +1,391 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::vec::*;
+
+/// The kind of linear phase filter designed by [`Vec::remez`].
+///
+/// Together with the parity of the length, the kind selects the type of the filter.
+///
+/// | Kind             | Odd length | Even length |
+/// |------------------|------------|-------------|
+/// | `Bandpass`       | Type I     | Type II     |
+/// | `Differentiator` | Type III   | Type IV     |
+/// | `Hilbert`        | Type III   | Type IV     |
+///
+/// Type II filters have a zero at the Nyquist frequency, type III filters at DC and the Nyquist frequency,
+/// and type IV filters at DC.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RemezKind {
+    /// Symmetric taps, with piecewise constant gain, e.g. lowpass, highpass or multiband filters.
+    Bandpass,
+    /// Antisymmetric taps, approximating `j2πf·gain`, i.e. the derivative with respect to the sample index.
+    /// The error is weighted relative to the desired response in bands of non-zero gain.
+    Differentiator,
+    /// Antisymmetric taps, approximating `-j·gain` for positive frequencies, i.e. a Hilbert transformer.
+    Hilbert,
+}
+
+/// The maximum number of exchange iterations.
+const MAX_ITERATIONS: usize = 40;
+/// The number of grid points per extremal frequency.
+const GRID_DENSITY: usize = 16;
+
+/// Interpolation of the weighted Chebyshev approximation through the current extremal frequencies.
+struct Interpolation {
+    /// `cos(2πf)` at the extremal frequencies.
+    x: alloc::vec::Vec<f64>,
+    /// The barycentric weights.
+    ad: alloc::vec::Vec<f64>,
+    /// The values of the approximation at the extremal frequencies.
+    y: alloc::vec::Vec<f64>,
+}
+
+impl Interpolation {
+    /// Compute the approximation which alternates around the desired response at the extremal frequencies.
+    fn new( extremals: &[usize], grid: &[f64], desired: &[f64], weight: &[f64] ) -> Interpolation
+    {
+        let len = extremals.len();
+
+        let mut x = alloc::vec::Vec::<f64>::with_capacity(len);
+        for i in 0..len
+        {
+            x.push((2f64*core::f64::consts::PI*grid[extremals[i]]).mixed_cos());
+        }
+
+        // The factor two keeps the products in range. It cancels in the ratios below.
+        let mut ad = alloc::vec::Vec::<f64>::with_capacity(len);
+        for i in 0..len
+        {
+            let mut denominator = 1f64;
+            for j in 0..len
+            {
+                if j != i
+                {
+                    denominator = denominator*2f64*(x[i]-x[j]);
+                }
+            }
+            ad.push(1f64/denominator);
+        }
+
+        let mut numerator   = 0f64;
+        let mut denominator = 0f64;
+        let mut sign = 1f64;
+        for i in 0..len
+        {
+            numerator   = numerator + ad[i]*desired[extremals[i]];
+            denominator = denominator + sign*ad[i]/weight[extremals[i]];
+            sign = -sign;
+        }
+        let delta = numerator/denominator;
+
+        let mut y = alloc::vec::Vec::<f64>::with_capacity(len);
+        let mut sign = 1f64;
+        for i in 0..len
+        {
+            y.push(desired[extremals[i]] - sign*delta/weight[extremals[i]]);
+            sign = -sign;
+        }
+
+        return Interpolation { x: x, ad: ad, y: y };
+    }
+
+    /// Evaluate the approximation at the frequency `f`, by the barycentric form of the Lagrange interpolation.
+    fn evaluate( &self, f: f64 ) -> f64
+    {
+        let xc = (2f64*core::f64::consts::PI*f).mixed_cos();
+
+        let mut numerator   = 0f64;
+        let mut denominator = 0f64;
+        for i in 0..self.x.len()
+        {
+            let difference = xc - self.x[i];
+            let abs = if difference < 0f64 { -difference } else { difference };
+            if abs < 1e-12
+            {
+                return self.y[i];
+            }
+            let c = self.ad[i]/difference;
+            numerator   = numerator + c*self.y[i];
+            denominator = denominator + c;
+        }
+        return numerator/denominator;
+    }
+}
+
+/// Find the `count` extremal frequencies of the error, alternating in sign.
+/// Returns `None` if too few extrema are found.
+fn search_extremals( error: &[f64], count: usize ) -> Option<alloc::vec::Vec<usize>>
+{
+    let len = error.len();
+    let mut found = alloc::vec::Vec::<usize>::new();
+
+    for i in 0..len
+    {
+        let e = error[i];
+        let above_previous = i == 0 || (0f64 < e && error[i-1] <= e) || (e < 0f64 && e <= error[i-1]);
+        let above_next = i == len-1 || (0f64 < e && error[i+1] < e) || (e < 0f64 && e < error[i+1]);
+        if e != 0f64 && above_previous && above_next
+        {
+            found.push(i);
+        }
+    }
+
+    let abs = |i: usize| -> f64 { if error[i] < 0f64 { -error[i] } else { error[i] } };
+
+    while count < found.len()
+    {
+        // Remove the smaller of two adjacent extrema of equal sign.
+        let mut remove = None;
+        for j in 1..found.len()
+        {
+            if 0f64 < error[found[j-1]]*error[found[j]]
+            {
+                remove = Some(if abs(found[j-1]) < abs(found[j]) { j-1 } else { j });
+                break;
+            }
+        }
+
+        // All extrema alternate. Remove the smaller of the first and last.
+        let remove = match remove {
+            Some(j) => j,
+            None    => if abs(found[found.len()-1]) < abs(found[0]) { found.len()-1 } else { 0 },
+        };
+        found.remove(remove);
+    }
+
+    if found.len() < count
+    {
+        return None;
+    }
+    return Some(found);
+}
+
+impl <T: MixedReal> Vec<T>
+{
+    /// Design an optimal equiripple linear phase FIR filter, by the Parks–McClellan (Remez exchange) algorithm.
+    ///
+    /// The maximum weighted deviation from the desired response over the bands is minimized.
+    /// The regions between the bands are transition bands, without constraints.
+    ///
+    /// The type of the filter (I–IV) is set by `kind` and the parity of `len`, see [`RemezKind`].
+    /// Bands which include a zero of the filter type, e.g. a highpass with an even length, are truncated before the zero.
+    ///
+    /// Panics if the exchange does not converge, which may happen for very narrow transition bands or many taps.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`     - The number of taps.
+    /// * `edges`   - The band edges, in pairs, increasing from 0 to 0.5, normalized to the sample rate.
+    /// * `gains`   - The desired gain of each band. For differentiators the desired response is `gain·2πf`.
+    /// * `weights` - The relative weight of the deviation in each band.
+    /// * `kind`    - The kind of filter.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // Lowpass with 0.1 pass band and 0.15 stop band edge, weighting the stop band ten times the pass band.
+    /// let taps = Vec::<f64>::remez( 45, &[0f64, 0.1, 0.15, 0.5], &[1f64, 0f64], &[1f64, 10f64], RemezKind::Bandpass );
+    ///
+    /// assert_eq!( taps.len(), 45 );
+    /// // The DC gain is within the pass band ripple.
+    /// assert!( (taps.sum()-1f64).abs() < 0.03 );
+    /// assert!( (taps[0]-taps[44]).abs() < 1e-12 );
+    /// ```
+    pub fn remez( len: usize, edges: &[T], gains: &[T], weights: &[T], kind: RemezKind ) -> Vec<T>
+    {
+        let num_bands = gains.len();
+        if edges.len() != 2*num_bands || weights.len() != num_bands || num_bands == 0
+        {
+            core::panic!("There must be two band edges, a gain and a weight for each band.");
+        }
+        if len < 3
+        {
+            core::panic!("The filter must have at least three taps.");
+        }
+
+        let mut bands = alloc::vec::Vec::<f64>::with_capacity(edges.len());
+        for i in 0..edges.len()
+        {
+            let edge: f64 = edges[i].mixed_to_num();
+            if !(0f64..=0.5f64).contains(&edge) || (0 < i && edge < bands[i-1])
+            {
+                core::panic!("The band edges must be increasing, and lie between 0 and 0.5 cycles per sample.");
+            }
+            bands.push(edge);
+        }
+
+        let symmetric = kind == RemezKind::Bandpass;
+        let odd = !len.is_multiple_of(2);
+
+        // The number of cosine basis functions.
+        let r = if symmetric && odd { len/2 + 1 } else { len/2 };
+
+        // Dense grid over the bands, avoiding the zeros of the filter type.
+        let grid_step = 0.5f64/((GRID_DENSITY*r) as f64);
+        let zero_at_dc      = !symmetric;
+        let zero_at_nyquist = (symmetric && !odd) || (!symmetric && odd);
+        if zero_at_dc && bands[0] < grid_step
+        {
+            bands[0] = grid_step;
+        }
+
+        let mut grid    = alloc::vec::Vec::<f64>::new();
+        let mut desired = alloc::vec::Vec::<f64>::new();
+        let mut weight  = alloc::vec::Vec::<f64>::new();
+        for band in 0..num_bands
+        {
+            let lower = bands[2*band];
+            let upper = bands[2*band+1];
+            let gain: f64 = gains[band].mixed_to_num();
+            let band_weight: f64 = weights[band].mixed_to_num();
+
+            let num_points = (((upper-lower)/grid_step + 0.5f64).mixed_floor() as usize).max(1);
+            for i in 0..num_points
+            {
+                let mut f = if i == num_points-1 { upper } else { lower + (i as f64)*grid_step };
+                if zero_at_nyquist && 0.5f64-grid_step < f
+                {
+                    f = 0.5f64-grid_step;
+                }
+
+                grid.push(f);
+                if kind == RemezKind::Differentiator
+                {
+                    desired.push(gain*2f64*core::f64::consts::PI*f);
+                    weight.push(if gain == 0f64 { band_weight } else { band_weight/f });
+                }
+                else
+                {
+                    desired.push(gain);
+                    weight.push(band_weight);
+                }
+            }
+        }
+
+        if grid.len() < r+1
+        {
+            core::panic!("The bands are too narrow for the number of taps.");
+        }
+
+        // H(f) = Q(f)P(f), where P(f) is a sum of cosines. Approximate D(f)/Q(f) with the weight W(f)Q(f).
+        let q = |f: f64| -> f64 {
+            if symmetric && odd {
+                1f64
+            } else if symmetric {
+                (core::f64::consts::PI*f).mixed_cos()
+            } else if odd {
+                (2f64*core::f64::consts::PI*f).mixed_sin()
+            } else {
+                (core::f64::consts::PI*f).mixed_sin()
+            }
+        };
+        for i in 0..grid.len()
+        {
+            let c = q(grid[i]);
+            desired[i] = desired[i]/c;
+            weight[i]  = weight[i]*c;
+        }
+
+        // Initial extremal frequencies, evenly spaced over the grid.
+        let mut extremals = alloc::vec::Vec::<usize>::with_capacity(r+1);
+        for i in 0..=r
+        {
+            extremals.push(i*(grid.len()-1)/r);
+        }
+
+        let mut error = alloc::vec::Vec::<f64>::with_capacity(grid.len());
+        let mut converged = false;
+        for _iteration in 0..MAX_ITERATIONS
+        {
+            let interpolation = Interpolation::new(&extremals, &grid, &desired, &weight);
+
+            error.clear();
+            for i in 0..grid.len()
+            {
+                error.push(weight[i]*(desired[i]-interpolation.evaluate(grid[i])));
+            }
+
+            extremals = match search_extremals(&error, r+1) {
+                Some(found) => found,
+                None        => core::panic!("The Remez exchange found too few extremal frequencies. Try other band edges or number of taps."),
+            };
+
+            // Converged when the error is of equal magnitude at all extremal frequencies.
+            let mut min = f64::MAX;
+            let mut max = 0f64;
+            for i in 0..extremals.len()
+            {
+                let e = error[extremals[i]];
+                let abs = if e < 0f64 { -e } else { e };
+                min = if abs < min { abs } else { min };
+                max = if max < abs { abs } else { max };
+            }
+            if (max-min) <= 1e-4*max
+            {
+                converged = true;
+                break;
+            }
+        }
+        if !converged
+        {
+            core::panic!("The Remez exchange did not converge in {} iterations. Try other band edges or number of taps.", MAX_ITERATIONS);
+        }
+
+        // Sample the amplitude response, and compute the taps by the inverse DFT.
+        let interpolation = Interpolation::new(&extremals, &grid, &desired, &weight);
+        let mut amplitude = alloc::vec::Vec::<f64>::with_capacity(len/2+1);
+        for k in 0..=len/2
+        {
+            let f = (k as f64)/(len as f64);
+            amplitude.push(interpolation.evaluate(f)*q(f));
+        }
+
+        let center = ((len-1) as f64)/2f64;
+        let mut taps = Vec::<T>::new_with_capacity(len);
+        for n in 0..len
+        {
+            let m = n as f64 - center;
+            let x = 2f64*core::f64::consts::PI*m/(len as f64);
+
+            let mut value = 0f64;
+            if symmetric
+            {
+                value = amplitude[0];
+                for k in 1..=(len-1)/2
+                {
+                    value = value + 2f64*amplitude[k]*(x*(k as f64)).mixed_cos();
+                }
+            }
+            else
+            {
+                if !odd
+                {
+                    value = amplitude[len/2]*(core::f64::consts::PI*m).mixed_sin();
+                }
+                for k in 1..=(len-1)/2
+                {
+                    value = value + 2f64*amplitude[k]*(x*(k as f64)).mixed_sin();
+                }
+            }
+            value = value/(len as f64);
+
+            // The antisymmetric taps approximate -j times the amplitude response.
+            if kind == RemezKind::Differentiator
+            {
+                value = -value;
+            }
+            taps.push_back(T::mixed_from_num(value));
+        }
+        return taps;
+    }
+}
@@ -0,0 +1,164 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::redundant_field_names)]
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// Stateful Finite Impulse Response (FIR) filter, for streaming processing.
+///
+/// The filter owns the taps of type `C`, and a delay line of samples of type `T`.
+/// The delay line is carried across calls, such that a signal can be processed in blocks of any size,
+/// with the same result as processing the whole signal at once.
+///
+/// Supported combinations of samples and taps are real samples with real taps, complex samples with real taps,
+/// and complex samples with complex taps, see [`FilterSample`].
+/// The output is accumulated in the sample type, i.e. without extra headroom for fixed point types.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // Three-tap moving sum.
+/// let mut filter = Fir::<f32, f32>::new( Vec::ones(3) );
+///
+/// let first  = filter.process( &Vec::lin_range(1f32, 4f32, 4) );
+/// let second = filter.process( &Vec::lin_range(5f32, 6f32, 2) );
+///
+/// assert_eq!( first.to_string(),  "[ 1, 3, 6, 9 ]" );
+/// // The state is carried across the block boundary.
+/// assert_eq!( second.to_string(), "[ 12, 15 ]" );
+/// ```
+///
+/// Real taps filtering complex samples, in fixed point.
+///
+/// ```
+/// use ndsp::*;
+/// use mixed_num::Cartesian;
+/// use fixed::FixedI32 as F;
+/// use fixed::types::extra::U16 as U;
+///
+/// let taps = Vec::new_from_vec( vec![ F::<U>::from_num(0.5), F::<U>::from_num(0.5) ] );
+/// let mut filter = Fir::<Cartesian<F<U>>, F<U>>::new( taps );
+///
+/// let mut input = Vec::<Cartesian<F<U>>>::new_with_capacity(2);
+/// input.push_back( Cartesian::new( F::<U>::from_num(1), F::<U>::from_num(-2) ) );
+/// input.push_back( Cartesian::new( F::<U>::from_num(3), F::<U>::from_num(2) ) );
+///
+/// let output = filter.process( &input );
+/// assert_eq!( output.to_string(), "[ 0.5-1i, 2+0i ]" );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fir<T, C> {
+    taps: Vec<C>,
+    /// Two copies of the delay line, such that the taps can be applied to a contiguous range.
+    delay_line: Vec<T>,
+    /// The position of the latest sample in the delay line.
+    position: usize,
+}
+
+impl <T, C> Fir<T, C>
+    where T: FilterSample<C> + MixedZero + core::ops::Add<Output = T>,
+          C: Copy
+{
+    /// Create a filter with the taps `taps`, and a zeroed delay line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `taps` - The impulse response of the filter, `h[0]` first.
+    ///
+    pub fn new( taps: Vec<C> ) -> Fir<T, C>
+    {
+        if taps.len() == 0
+        {
+            core::panic!("The filter must have at least one tap.");
+        }
+
+        let mut delay_line = Vec::<T>::new_with_capacity(2*taps.len());
+        for _idx in 0..2*taps.len()
+        {
+            delay_line.push_back(T::mixed_zero());
+        }
+
+        return Fir {
+            taps: taps,
+            delay_line: delay_line,
+            position: 0,
+        };
+    }
+
+    /// The taps of the filter.
+    pub fn taps( &self ) -> &Vec<C>
+    {
+        return &self.taps;
+    }
+
+    /// Clear the delay line.
+    pub fn reset( &mut self )
+    {
+        for idx in 0..self.delay_line.len()
+        {
+            self.delay_line[idx] = T::mixed_zero();
+        }
+        self.position = 0;
+    }
+
+    /// Filter a single sample.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sample` - The next input sample.
+    ///
+    pub fn process_sample( &mut self, sample: T ) -> T
+    {
+        let len = self.taps.len();
+
+        self.position = (self.position+1)%len;
+        self.delay_line[self.position]     = sample;
+        self.delay_line[self.position+len] = sample;
+
+        // y[n] = Σ h[k]x[n-k], where x[n-k] is found at position+len-k.
+        let latest = self.position+len;
+        let mut acc = T::mixed_zero();
+        for k in 0..len
+        {
+            acc = acc + self.delay_line[latest-k].mul_coefficient(self.taps[k]);
+        }
+        return acc;
+    }
+
+    /// Filter a block of samples. The state is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The next block of input samples.
+    ///
+    pub fn process( &mut self, input: &Vec<T> ) -> Vec<T>
+    {
+        let mut output = Vec::<T>::new_with_capacity(input.len());
+        for idx in 0..input.len()
+        {
+            output.push_back(self.process_sample(input[idx]));
+        }
+        return output;
+    }
+
+    /// Filter a block of samples in place. The state is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `buffer` - The next block of input samples, replaced by the output.
+    ///
+    pub fn process_in_place( &mut self, buffer: &mut Vec<T> )
+    {
+        for idx in 0..buffer.len()
+        {
+            buffer[idx] = self.process_sample(buffer[idx]);
+        }
+    }
+}
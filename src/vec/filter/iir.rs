@@ -0,0 +1,265 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The realisation of the second-order sections of a [`BiquadCascade`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BiquadForm {
+    /// Direct form I. Four states per section, the previous inputs and outputs.
+    DirectForm1,
+    /// Direct form II transposed. Two states per section.
+    DirectForm2Transposed,
+}
+
+/// A second-order section, with coefficients normalized by `a0`, and scaled by `2^-shift`.
+#[derive(Clone, Debug, PartialEq)]
+struct Section<C> {
+    b: [C; 3],
+    a: [C; 2],
+    shift: usize,
+}
+
+/// Cascade of second-order sections (biquads), for streaming processing.
+///
+/// Each section computes `H(z) = (b0 + b1z⁻¹ + b2z⁻²)/(a0 + a1z⁻¹ + a2z⁻²)`.
+/// The state of each section is carried across calls, such that a signal can be processed in blocks of any size.
+///
+/// The coefficients are normalized in double precision, and converted to the coefficient type `C`.
+/// For fixed point coefficients with few integer bits, the coefficients of a section are scaled by `2^-s`,
+/// with the smallest `s` which makes them fit `C`. The sum of the products is doubled `s` times to compensate.
+///
+/// Supported combinations of samples and coefficients are those of [`FilterSample`],
+/// e.g. real or complex samples with real coefficients.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // A single one-pole section, y[n] = x[n] + 0.5y[n-1].
+/// let sos = [ [1f64, 0f64, 0f64, 1f64, -0.5f64, 0f64] ];
+/// let mut filter = BiquadCascade::<f32, f32>::new( &sos, BiquadForm::DirectForm2Transposed );
+///
+/// let mut buffer = Vec::new_from_vec( vec![1f32, 0f32, 0f32] );
+/// filter.process( &mut buffer );
+/// assert_eq!( buffer.to_string(), "[ 1, 0.5, 0.25 ]" );
+///
+/// // The state is carried to the next block.
+/// let mut buffer = Vec::new_from_vec( vec![0f32, 0f32] );
+/// filter.process( &mut buffer );
+/// assert_eq!( buffer.to_string(), "[ 0.125, 0.0625 ]" );
+/// ```
+///
+/// Fixed point coefficients out of the range of the type are scaled.
+///
+/// ```
+/// use ndsp::*;
+/// use fixed::FixedI32 as F;
+/// use fixed::types::extra::U29 as U;
+///
+/// // A double zero at DC, where b1 = -4 does not fit a FixedI32 with 29 fractional bits.
+/// let sos = [ [2f64, -4f64, 2f64, 1f64, -1.8f64, 0.81f64] ];
+/// let mut filter    = BiquadCascade::<F<U>, F<U>>::new( &sos, BiquadForm::DirectForm1 );
+/// let mut reference = BiquadCascade::<f64, f64>::new( &sos, BiquadForm::DirectForm1 );
+///
+/// let mut impulse = Vec::new_from_vec( vec![ F::<U>::from_num(0); 32 ] );
+/// impulse[0] = F::<U>::from_num(1);
+/// filter.process( &mut impulse );
+///
+/// let mut expected = Vec::new_from_vec( vec![ 0f64; 32 ] );
+/// expected[0] = 1f64;
+/// reference.process( &mut expected );
+///
+/// for n in 0..32 {
+///     assert!( (impulse[n].to_num::<f64>()-expected[n]).abs() < 1e-6 );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BiquadCascade<T, C> {
+    form: BiquadForm,
+    sections: alloc::vec::Vec<Section<C>>,
+    /// The state of each section. `[x1, x2, y1, y2]` for direct form I, and `[s1, s2, 0, 0]` for direct form II transposed.
+    state: alloc::vec::Vec<[T; 4]>,
+}
+
+impl <T, C> BiquadCascade<T, C>
+    where T: FilterSample<C> + MixedZero + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+          C: MixedReal
+{
+    /// Create a cascade from second-order sections, with zeroed states.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sos`  - The coefficients of each section, `[b0, b1, b2, a0, a1, a2]`, e.g. from [`Zpk::to_sos`]. The sections are applied in order.
+    /// * `form` - The realisation of the sections.
+    ///
+    pub fn new<S: MixedReal>( sos: &[[S; 6]], form: BiquadForm ) -> BiquadCascade<T, C>
+    {
+        if sos.is_empty()
+        {
+            core::panic!("The cascade must have at least one section.");
+        }
+
+        let max_value: f64 = C::mixed_max_value().mixed_to_num();
+
+        let mut sections = alloc::vec::Vec::<Section<C>>::with_capacity(sos.len());
+        let mut state = alloc::vec::Vec::<[T; 4]>::with_capacity(sos.len());
+        for section in sos
+        {
+            let mut coefficients = [0f64; 6];
+            for idx in 0..6
+            {
+                coefficients[idx] = section[idx].mixed_to_num();
+            }
+
+            let a0 = coefficients[3];
+            if a0 == 0f64
+            {
+                core::panic!("The a0 coefficient of a section must be non-zero.");
+            }
+
+            let mut normalized = [0f64; 5];
+            normalized[0] = coefficients[0]/a0;
+            normalized[1] = coefficients[1]/a0;
+            normalized[2] = coefficients[2]/a0;
+            normalized[3] = coefficients[4]/a0;
+            normalized[4] = coefficients[5]/a0;
+
+            let mut max_abs = 0f64;
+            for value in normalized
+            {
+                if !value.is_finite()
+                {
+                    core::panic!("The coefficients of a section must be finite after normalization by a0, got {:?} and a0 = {}.", coefficients, a0);
+                }
+                let abs = if value < 0f64 { -value } else { value };
+                if max_abs < abs
+                {
+                    max_abs = abs;
+                }
+            }
+
+            // Scale by 2^-shift until the coefficients fit the type, by at most the bit width of the type.
+            let max_shift = 8*core::mem::size_of::<C>();
+            let mut shift = 0;
+            let mut scale = 1f64;
+            while max_value <= max_abs*scale && shift < max_shift
+            {
+                shift += 1;
+                scale = scale*0.5;
+            }
+            if max_value <= max_abs*scale
+            {
+                core::panic!("The coefficients of a section are too large for the coefficient type, got {:?}.", normalized);
+            }
+
+            sections.push(Section {
+                b: [C::mixed_from_num(normalized[0]*scale), C::mixed_from_num(normalized[1]*scale), C::mixed_from_num(normalized[2]*scale)],
+                a: [C::mixed_from_num(normalized[3]*scale), C::mixed_from_num(normalized[4]*scale)],
+                shift: shift,
+            });
+            state.push([T::mixed_zero(); 4]);
+        }
+
+        return BiquadCascade {
+            form: form,
+            sections: sections,
+            state: state,
+        };
+    }
+
+    /// The realisation of the sections.
+    pub fn form( &self ) -> BiquadForm
+    {
+        return self.form;
+    }
+
+    /// The number of second-order sections.
+    pub fn num_sections( &self ) -> usize
+    {
+        return self.sections.len();
+    }
+
+    /// Clear the state of all sections.
+    pub fn reset( &mut self )
+    {
+        for idx in 0..self.state.len()
+        {
+            self.state[idx] = [T::mixed_zero(); 4];
+        }
+    }
+
+    /// Filter a single sample through all sections.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sample` - The next input sample.
+    ///
+    pub fn process_sample( &mut self, sample: T ) -> T
+    {
+        let mut x = sample;
+        for idx in 0..self.sections.len()
+        {
+            let section = &self.sections[idx];
+            let state = &mut self.state[idx];
+
+            match self.form {
+                BiquadForm::DirectForm1 => {
+                    let acc = x.mul_coefficient(section.b[0]) + state[0].mul_coefficient(section.b[1]) + state[1].mul_coefficient(section.b[2])
+                            - state[2].mul_coefficient(section.a[0]) - state[3].mul_coefficient(section.a[1]);
+                    let y = undo_shift(acc, section.shift);
+
+                    state[1] = state[0];
+                    state[0] = x;
+                    state[3] = state[2];
+                    state[2] = y;
+                    x = y;
+                }
+                BiquadForm::DirectForm2Transposed => {
+                    // The states are scaled as the coefficients.
+                    let y = undo_shift(x.mul_coefficient(section.b[0]) + state[0], section.shift);
+
+                    state[0] = x.mul_coefficient(section.b[1]) - y.mul_coefficient(section.a[0]) + state[1];
+                    state[1] = x.mul_coefficient(section.b[2]) - y.mul_coefficient(section.a[1]);
+                    x = y;
+                }
+            }
+        }
+        return x;
+    }
+
+    /// Filter a block of samples in place. The state is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `buffer` - The next block of input samples, replaced by the output.
+    ///
+    pub fn process( &mut self, buffer: &mut Vec<T> )
+    {
+        for idx in 0..buffer.len()
+        {
+            buffer[idx] = self.process_sample(buffer[idx]);
+        }
+    }
+}
+
+/// Multiply `value` by `2^shift`, by repeated doubling.
+#[inline(always)]
+fn undo_shift<T: Copy + core::ops::Add<Output = T>>( value: T, shift: usize ) -> T
+{
+    let mut value = value;
+    for _idx in 0..shift
+    {
+        value = value + value;
+    }
+    return value;
+}
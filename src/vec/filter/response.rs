@@ -0,0 +1,241 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::vec::*;
+
+/// The frequency response of a filter, on a grid of frequencies. See [`freqz`] and [`freqz_sos`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrequencyResponse<T> {
+    /// The frequencies of the grid, in the unit of the sample rate.
+    pub frequencies: Vec<T>,
+    /// The complex response.
+    pub response: Vec<Cartesian<T>>,
+    /// The magnitude of the response in dB, floored at -400 dB.
+    pub magnitude_db: Vec<T>,
+    /// The phase of the response in radians, unwrapped along the grid.
+    pub phase: Vec<T>,
+    /// The group delay in samples, `-dφ/dω`. Zero where the response vanishes.
+    pub group_delay: Vec<T>,
+}
+
+/// The response of a polynomial in `z⁻¹`, and the derivative needed for its group delay, accumulated over factors.
+#[derive(Clone, Copy)]
+struct Accumulator {
+    re: f64,
+    im: f64,
+    delay: f64,
+    singular: bool,
+}
+
+impl Accumulator {
+    fn new() -> Accumulator
+    {
+        return Accumulator { re: 1f64, im: 0f64, delay: 0f64, singular: false };
+    }
+
+    /// Multiply by the response of `Σcₙz⁻ⁿ` at `ω` if `numerator`, or divide by it otherwise.
+    fn apply( &mut self, coefficients: &[f64], omega: f64, numerator: bool )
+    {
+        // C = Σcₙe^(-jωn), and D = Σn·cₙe^(-jωn). The group delay of C is Re(D/C).
+        let mut c_re = 0f64;
+        let mut c_im = 0f64;
+        let mut d_re = 0f64;
+        let mut d_im = 0f64;
+        let mut scale = 0f64;
+        for n in 0..coefficients.len()
+        {
+            let (sin, cos) = (omega*(n as f64)).mixed_sincos();
+            c_re = c_re + coefficients[n]*cos;
+            c_im = c_im - coefficients[n]*sin;
+            d_re = d_re + (n as f64)*coefficients[n]*cos;
+            d_im = d_im - (n as f64)*coefficients[n]*sin;
+            scale = scale + coefficients[n].mixed_abs();
+        }
+
+        let power = c_re*c_re + c_im*c_im;
+        if power <= 1e-24*scale*scale
+        {
+            self.singular = true;
+        }
+        else
+        {
+            let delay = (d_re*c_re + d_im*c_im)/power;
+            self.delay = if numerator { self.delay + delay } else { self.delay - delay };
+        }
+
+        let (re, im) = if numerator {
+            (self.re*c_re - self.im*c_im, self.re*c_im + self.im*c_re)
+        } else {
+            ((self.re*c_re + self.im*c_im)/power, (self.im*c_re - self.re*c_im)/power)
+        };
+        self.re = re;
+        self.im = im;
+    }
+}
+
+/// Collect the accumulated responses in a [`FrequencyResponse`].
+fn collect<T: MixedReal>( frequencies: &Vec<T>, accumulators: &[Accumulator] ) -> FrequencyResponse<T>
+{
+    let len = accumulators.len();
+    let mut response     = Vec::<Cartesian<T>>::new_with_capacity(len);
+    let mut magnitude_db = Vec::<T>::new_with_capacity(len);
+    let mut phase        = Vec::<T>::new_with_capacity(len);
+    let mut group_delay  = Vec::<T>::new_with_capacity(len);
+
+    let mut previous_phase = 0f64;
+    for idx in 0..len
+    {
+        let h = accumulators[idx];
+        response.push_back(Cartesian::new(T::mixed_from_num(h.re), T::mixed_from_num(h.im)));
+
+        let magnitude = (h.re*h.re + h.im*h.im).mixed_sqrt();
+        let magnitude = if magnitude < 1e-20 { 1e-20 } else { magnitude };
+        magnitude_db.push_back(T::mixed_from_num(20f64*magnitude.mixed_log10()));
+
+        // Unwrap relative to the previous frequency.
+        let mut angle = h.im.mixed_atan2(h.re);
+        if 0 < idx
+        {
+            while core::f64::consts::PI < angle-previous_phase
+            {
+                angle = angle - 2f64*core::f64::consts::PI;
+            }
+            while angle-previous_phase < -core::f64::consts::PI
+            {
+                angle = angle + 2f64*core::f64::consts::PI;
+            }
+        }
+        previous_phase = angle;
+        phase.push_back(T::mixed_from_num(angle));
+
+        group_delay.push_back(T::mixed_from_num(if h.singular { 0f64 } else { h.delay }));
+    }
+
+    return FrequencyResponse {
+        frequencies: frequencies.clone(),
+        response: response,
+        magnitude_db: magnitude_db,
+        phase: phase,
+        group_delay: group_delay,
+    };
+}
+
+/// The angular frequency in radians per sample.
+fn angular_frequency<T: MixedReal>( frequency: T, sample_rate: f64 ) -> f64
+{
+    let frequency: f64 = frequency.mixed_to_num();
+    return 2f64*core::f64::consts::PI*frequency/sample_rate;
+}
+
+/// Convert filter coefficients to `f64`.
+fn coefficients_to_f64<T: MixedReal>( coefficients: &Vec<T> ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = alloc::vec::Vec::<f64>::with_capacity(coefficients.len());
+    for idx in 0..coefficients.len()
+    {
+        converted.push(coefficients[idx].mixed_to_num());
+    }
+    return converted;
+}
+
+/// Evaluate the frequency response of a filter `B(z)/A(z)`, on a grid of frequencies.
+///
+/// Any grid may be used, e.g. from [`Vec::lin_range`], with no zero-padding or power of two length.
+/// FIR filters and windows have the denominator `[1]`.
+///
+/// ## Arguments
+///
+/// * `numerator`   - The coefficients `b` of `B(z) = Σbₙz⁻ⁿ`.
+/// * `denominator` - The coefficients `a` of `A(z) = Σaₙz⁻ⁿ`.
+/// * `frequencies` - The frequency grid, in the unit of `sample_rate`.
+/// * `sample_rate` - The sample rate. Use one for frequencies normalized to the sample rate.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // Two-tap moving average, with a zero at the Nyquist frequency and a delay of half a sample.
+/// let taps = Vec::new_from_vec( vec![0.5f64, 0.5f64] );
+/// let frequencies = Vec::lin_range( 0f64, 0.25f64, 2 );
+///
+/// let h = freqz( &taps, &Vec::new_from_vec( vec![1f64] ), &frequencies, 1f64 );
+///
+/// assert!( (h.magnitude_db[0]-0f64).abs() < 1e-12 );
+/// assert!( (h.magnitude_db[1]+3.0103f64).abs() < 1e-4 );
+/// assert!( (h.phase[1]+core::f64::consts::PI/4f64).abs() < 1e-12 );
+/// assert!( (h.group_delay[1]-0.5f64).abs() < 1e-12 );
+/// ```
+pub fn freqz<T: MixedReal>( numerator: &Vec<T>, denominator: &Vec<T>, frequencies: &Vec<T>, sample_rate: T ) -> FrequencyResponse<T>
+{
+    let sample_rate: f64 = sample_rate.mixed_to_num();
+    let b = coefficients_to_f64(numerator);
+    let a = coefficients_to_f64(denominator);
+
+    let mut accumulators = alloc::vec::Vec::<Accumulator>::with_capacity(frequencies.len());
+    for idx in 0..frequencies.len()
+    {
+        let omega = angular_frequency(frequencies[idx], sample_rate);
+        let mut h = Accumulator::new();
+        h.apply(&b, omega, true);
+        h.apply(&a, omega, false);
+        accumulators.push(h);
+    }
+    return collect(frequencies, &accumulators);
+}
+
+/// Evaluate the frequency response of second-order sections, e.g. from [`Zpk::to_sos`], on a grid of frequencies.
+///
+/// ## Arguments
+///
+/// * `sos`         - The coefficients of each section, `[b0, b1, b2, a0, a1, a2]`.
+/// * `frequencies` - The frequency grid, in the unit of `sample_rate`.
+/// * `sample_rate` - The sample rate. Use one for frequencies normalized to the sample rate.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let sos = Zpk::<f64>::design( 4, IirPrototype::Butterworth, FilterBand::Lowpass(100f64/1000f64) ).to_sos();
+///
+/// let h = freqz_sos( &sos, &Vec::new_from_vec( vec![0f64, 100f64] ), 1000f64 );
+/// assert!( h.magnitude_db[0].abs() < 1e-9 );
+/// assert!( (h.magnitude_db[1]+3.0103f64).abs() < 1e-4 );
+/// ```
+pub fn freqz_sos<T: MixedReal>( sos: &[[T; 6]], frequencies: &Vec<T>, sample_rate: T ) -> FrequencyResponse<T>
+{
+    let sample_rate: f64 = sample_rate.mixed_to_num();
+
+    let mut sections = alloc::vec::Vec::<[f64; 6]>::with_capacity(sos.len());
+    for section in sos
+    {
+        let mut converted = [0f64; 6];
+        for idx in 0..6
+        {
+            converted[idx] = section[idx].mixed_to_num();
+        }
+        sections.push(converted);
+    }
+
+    let mut accumulators = alloc::vec::Vec::<Accumulator>::with_capacity(frequencies.len());
+    for idx in 0..frequencies.len()
+    {
+        let omega = angular_frequency(frequencies[idx], sample_rate);
+        let mut h = Accumulator::new();
+        for section in &sections
+        {
+            h.apply(&section[0..3], omega, true);
+            h.apply(&section[3..6], omega, false);
+        }
+        accumulators.push(h);
+    }
+    return collect(frequencies, &accumulators);
+}
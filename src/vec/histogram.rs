@@ -0,0 +1,363 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The choice of bins of a histogram, see [`Vec::histogram`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistogramBins<T> {
+    /// A number of bins of equal width, spanning the range of the data.
+    Count(usize),
+    /// Explicit, increasing bin edges. `N` edges make `N-1` bins. Values outside the edges are not counted.
+    Edges(Vec<T>),
+    /// Freedman–Diaconis' rule, with the bin width `2·IQR·N^(-1/3)`. Robust to outliers.
+    FreedmanDiaconis,
+    /// Scott's rule, with the bin width `3.49·σ·N^(-1/3)`. Optimal for Gaussian data.
+    Scott,
+    /// Sturges' rule, with `⌈log₂N⌉+1` bins.
+    Sturges,
+}
+
+/// A histogram, see [`Vec::histogram`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram<T> {
+    /// The increasing bin edges. Bin `k` spans `edges[k]..edges[k+1]`, and the last bin includes its upper edge.
+    pub edges: Vec<T>,
+    /// The number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+/// A two-dimensional histogram of the real and imaginary parts of a complex vector, see [`Vec::histogram_2d`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram2d<T> {
+    /// The bin edges of the real part.
+    pub edges_re: Vec<T>,
+    /// The bin edges of the imaginary part.
+    pub edges_im: Vec<T>,
+    /// The number of values in each bin, indexed as `counts[re_bin][im_bin]`.
+    pub counts: Vec<Vec<usize>>,
+}
+
+/// Convert a real vector to `f64`.
+fn to_f64<T: MixedReal>( vec: &Vec<T> ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = alloc::vec::Vec::<f64>::with_capacity(vec.len());
+    for idx in 0..vec.len()
+    {
+        converted.push(vec[idx].mixed_to_num());
+    }
+    return converted;
+}
+
+/// Compute the bin edges of `values` from the choice of bins.
+fn bin_edges<T: MixedReal>( values: &Vec<T>, bins: &HistogramBins<T> ) -> alloc::vec::Vec<f64>
+{
+    if let HistogramBins::Edges(edges) = bins
+    {
+        if edges.len() < 2
+        {
+            core::panic!("At least two bin edges are required, got {}.", edges.len());
+        }
+        let edges = to_f64(edges);
+        for idx in 1..edges.len()
+        {
+            if edges[idx] <= edges[idx-1]
+            {
+                core::panic!("The bin edges must be increasing.");
+            }
+        }
+        return edges;
+    }
+
+    if values.len() == 0
+    {
+        core::panic!("The vector must have at least one item to choose the bins.");
+    }
+
+    let (min, max) = values.min_max();
+    let (mut min, mut max): (f64, f64) = (min.mixed_to_num(), max.mixed_to_num());
+    if min == max
+    {
+        min = min - 0.5f64;
+        max = max + 0.5f64;
+    }
+
+    let len = values.len() as f64;
+    let sturges = (len.mixed_log10()/2f64.mixed_log10()).mixed_ceil() as usize + 1;
+    let num_bins = match bins {
+        HistogramBins::Count(num_bins)     => *num_bins,
+        HistogramBins::Sturges             => sturges,
+        HistogramBins::Scott               => {
+            let std: f64 = values.std(0).mixed_to_num();
+            bins_of_width(min, max, 3.49f64*std/len.mixed_pow(1f64/3f64), sturges)
+        },
+        HistogramBins::FreedmanDiaconis    => {
+            let upper: f64 = values.quantile(T::mixed_from_num(0.75f64)).mixed_to_num();
+            let lower: f64 = values.quantile(T::mixed_from_num(0.25f64)).mixed_to_num();
+            bins_of_width(min, max, 2f64*(upper-lower)/len.mixed_pow(1f64/3f64), sturges)
+        },
+        HistogramBins::Edges(_)            => unreachable!(),
+    };
+    if num_bins == 0
+    {
+        core::panic!("The histogram must have at least one bin.");
+    }
+
+    let mut edges = alloc::vec::Vec::<f64>::with_capacity(num_bins+1);
+    for idx in 0..num_bins
+    {
+        edges.push(min + (max-min)*(idx as f64)/(num_bins as f64));
+    }
+    // Exactly the maximum, such that it is counted despite rounding.
+    edges.push(max);
+    return edges;
+}
+
+/// The number of bins of about `width` spanning `min..max`, or `fallback` if the width is zero.
+fn bins_of_width( min: f64, max: f64, width: f64, fallback: usize ) -> usize
+{
+    if width <= 0f64
+    {
+        return fallback;
+    }
+    return (((max-min)/width).mixed_ceil() as usize).max(1);
+}
+
+/// The bin of `value`, or `None` if outside the edges. The last bin includes its upper edge.
+fn bin_index( edges: &[f64], value: f64 ) -> Option<usize>
+{
+    if value < edges[0] || edges[edges.len()-1] < value || value.is_nan()
+    {
+        return None;
+    }
+    let upper = edges.partition_point(|edge| *edge <= value);
+    return Some(upper.clamp(1, edges.len()-1) - 1);
+}
+
+/// Convert `f64` values to a vector.
+fn from_f64<T: MixedReal>( values: &[f64] ) -> Vec<T>
+{
+    let mut converted = Vec::<T>::new_with_capacity(values.len());
+    for value in values
+    {
+        converted.push_back(T::mixed_from_num(*value));
+    }
+    return converted;
+}
+
+impl <T: MixedReal> Vec<T>
+{
+    /// Compute the histogram of the vector.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bins` - The choice of bins, a count, explicit edges or a rule.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![0f64, 0.5f64, 1f64, 1.5f64, 2f64, 4f64] );
+    ///
+    /// let histogram = test_vec.histogram( HistogramBins::Count(4) );
+    /// assert_eq!( histogram.edges.to_string(), "[ 0, 1, 2, 3, 4 ]" );
+    /// assert_eq!( histogram.counts.to_string(), "[ 2, 2, 1, 1 ]" );
+    ///
+    /// let edges = Vec::new_from_vec( vec![0.5f64, 1.5f64, 2.5f64] );
+    /// let histogram = test_vec.histogram( HistogramBins::Edges(edges) );
+    /// assert_eq!( histogram.counts.to_string(), "[ 2, 2 ]" );
+    /// ```
+    pub fn histogram( &self, bins: HistogramBins<T> ) -> Histogram<T>
+    {
+        let edges = bin_edges(self, &bins);
+
+        let mut counts = Vec::<usize>::new_with_capacity(edges.len()-1);
+        for _ in 1..edges.len()
+        {
+            counts.push_back(0);
+        }
+        for idx in 0..self.len()
+        {
+            if let Some(bin) = bin_index(&edges, self[idx].mixed_to_num())
+            {
+                counts[bin] = counts[bin] + 1;
+            }
+        }
+
+        return Histogram { edges: from_f64(&edges), counts: counts };
+    }
+
+    /// Compute the empirical Cumulative Distribution Function (CDF) of the vector.
+    ///
+    /// Returns the sorted values, and the fraction of the values less than or equal to each.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![3f64, 1f64, 2f64, 4f64] );
+    /// let (values, cdf) = test_vec.ecdf();
+    ///
+    /// assert_eq!( values.to_string(), "[ 1, 2, 3, 4 ]" );
+    /// assert_eq!( cdf.to_string(), "[ 0.25, 0.5, 0.75, 1 ]" );
+    /// ```
+    pub fn ecdf( &self ) -> (Vec<T>, Vec<T>)
+    {
+        let mut sorted = to_f64(self);
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        // Equal values share the fraction of the last of them.
+        let len = sorted.len();
+        let mut fractions = alloc::vec![0f64; len];
+        for idx in (0..len).rev()
+        {
+            fractions[idx] = if idx+1 < len && sorted[idx+1] == sorted[idx] { fractions[idx+1] } else { ((idx+1) as f64)/(len as f64) };
+        }
+        let cdf = from_f64(&fractions);
+        return (from_f64(&sorted), cdf);
+    }
+}
+
+impl <T: MixedReal> Vec<Cartesian<T>>
+{
+    /// Compute the two-dimensional histogram of the real and imaginary parts, the density of the I/Q samples.
+    ///
+    /// The bins of each axis are chosen independently, from the real and imaginary parts respectively.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bins_re` - The choice of bins of the real part.
+    /// * `bins_im` - The choice of bins of the imaginary part.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A QPSK constellation.
+    /// let mut symbols = Vec::<Cartesian<f64>>::new_with_capacity(8);
+    /// for idx in 0..8
+    /// {
+    ///     let re = if idx%2 == 0 { 1f64 } else { -1f64 };
+    ///     let im = if idx%4 < 2 { 1f64 } else { -1f64 };
+    ///     symbols.push_back( Cartesian::new(re, im) );
+    /// }
+    ///
+    /// let histogram = symbols.histogram_2d( HistogramBins::Count(2), HistogramBins::Count(2) );
+    /// assert_eq!( histogram.counts.to_string(), "[ [ 2, 2 ], [ 2, 2 ] ]" );
+    /// ```
+    pub fn histogram_2d( &self, bins_re: HistogramBins<T>, bins_im: HistogramBins<T> ) -> Histogram2d<T>
+    {
+        let edges_re = bin_edges(&self.re(), &bins_re);
+        let edges_im = bin_edges(&self.im(), &bins_im);
+
+        let mut counts = Vec::<Vec<usize>>::new_with_capacity(edges_re.len()-1);
+        for _ in 1..edges_re.len()
+        {
+            let mut row = Vec::<usize>::new_with_capacity(edges_im.len()-1);
+            for _ in 1..edges_im.len()
+            {
+                row.push_back(0);
+            }
+            counts.push_back(row);
+        }
+        for idx in 0..self.len()
+        {
+            let bin_re = bin_index(&edges_re, self[idx].re.mixed_to_num());
+            let bin_im = bin_index(&edges_im, self[idx].im.mixed_to_num());
+            if let (Some(bin_re), Some(bin_im)) = (bin_re, bin_im)
+            {
+                counts[bin_re][bin_im] = counts[bin_re][bin_im] + 1;
+            }
+        }
+
+        return Histogram2d { edges_re: from_f64(&edges_re), edges_im: from_f64(&edges_im), counts: counts };
+    }
+}
+
+impl <T: MixedReal> Histogram<T>
+{
+    /// The center of each bin.
+    pub fn centers( &self ) -> Vec<T>
+    {
+        let mut centers = Vec::<T>::new_with_capacity(self.counts.len());
+        for idx in 0..self.counts.len()
+        {
+            let lower: f64 = self.edges[idx].mixed_to_num();
+            let upper: f64 = self.edges[idx+1].mixed_to_num();
+            centers.push_back(T::mixed_from_num(0.5f64*(lower+upper)));
+        }
+        return centers;
+    }
+
+    /// The Probability Density Function (PDF) estimate of each bin, normalized to integrate to one over the edges.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let edges = Vec::new_from_vec( vec![0f64, 1f64, 3f64] );
+    /// let histogram = Vec::new_from_vec( vec![0.5f64, 1.5f64, 2.5f64, 2f64] ).histogram( HistogramBins::Edges(edges) );
+    ///
+    /// assert_eq!( histogram.density().to_string(), "[ 0.25, 0.375 ]" );
+    /// ```
+    pub fn density( &self ) -> Vec<T>
+    {
+        let total = self.total() as f64;
+        let mut density = Vec::<T>::new_with_capacity(self.counts.len());
+        for idx in 0..self.counts.len()
+        {
+            let lower: f64 = self.edges[idx].mixed_to_num();
+            let upper: f64 = self.edges[idx+1].mixed_to_num();
+            let value = if total == 0f64 { 0f64 } else { (self.counts[idx] as f64)/(total*(upper-lower)) };
+            density.push_back(T::mixed_from_num(value));
+        }
+        return density;
+    }
+
+    /// The cumulative distribution at the upper edge of each bin, the fraction of the counted values up to and including the bin.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let histogram = Vec::new_from_vec( vec![0f64, 1f64, 1f64, 3f64] ).histogram( HistogramBins::Count(3) );
+    /// assert_eq!( histogram.cdf().to_string(), "[ 0.25, 0.75, 1 ]" );
+    /// ```
+    pub fn cdf( &self ) -> Vec<T>
+    {
+        let total = self.total() as f64;
+        let mut cumulative = 0usize;
+        let mut cdf = Vec::<T>::new_with_capacity(self.counts.len());
+        for idx in 0..self.counts.len()
+        {
+            cumulative = cumulative + self.counts[idx];
+            cdf.push_back(T::mixed_from_num(if total == 0f64 { 0f64 } else { (cumulative as f64)/total }));
+        }
+        return cdf;
+    }
+
+    /// The number of counted values, excluding any outside the edges.
+    pub fn total( &self ) -> usize
+    {
+        let mut total = 0usize;
+        for idx in 0..self.counts.len()
+        {
+            total = total + self.counts[idx];
+        }
+        return total;
+    }
+}
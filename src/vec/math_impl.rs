@@ -720,11 +720,11 @@ impl <T1: MixedNum, T2: MixedNum + MixedNumConversion<T1> + core::cmp::PartialOr
 
 impl <T: MixedReal + MixedZero + MixedPowi + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase +
          MixedOps + MixedPi > HilbertTransform<T> for Vec<T>
-    where T: MixedNumConversion<T>
 {
     /// Compute the Discrete Hilbert Transform (DHT).
     /// 
     /// The transform computes the analytical signal from a real-only signal.
+    /// The spectrum of the real signal is computed with [`Rfft`], and `output_buffer` must be of the same length as `self`.
     /// 
     /// \[1\] [L. Marple, Computing the Discrete-Time “Analytic” Signal via FFT, IEEE, 1999](https://ieeexplore.ieee.org/document/782222)
     /// 
@@ -751,18 +751,33 @@ impl <T: MixedReal + MixedZero + MixedPowi + MixedNumSigned + MixedTrigonometry
     /// 
     /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/plot_hilbert.png) 
     fn hilbert(&self, output_buffer: &mut Vec<Cartesian<T>>)
-    {        
-        output_buffer.copy_from_buffer(self);
-
-        output_buffer.fft();
-        
-        for idx in 1..(output_buffer.len()/2)
+    {
+        let n = self.len();
+        if output_buffer.len() != n
         {
-            output_buffer[idx] = output_buffer[idx]*T::mixed_from_num(2);
+            core::panic!("The output buffer must be of the same length as the signal. Signal length = {}, buffer length = {}", n, output_buffer.len());
         }
-        for idx in output_buffer.len()/2+1..output_buffer.len()
+
+        let spectrum = self.rfft();
+        let two = T::mixed_from_num(2);
+
+        output_buffer[0] = spectrum[0];
+        // The positive frequencies are doubled, and the negative ones removed.
+        for idx in 1..n
         {
-            output_buffer[idx] = Cartesian::mixed_zero();
+            if 2*idx < n
+            {
+                output_buffer[idx] = Cartesian::new(spectrum[idx].re*two, spectrum[idx].im*two);
+            }
+            else if 2*idx == n
+            {
+                // The Nyquist bin of even lengths.
+                output_buffer[idx] = spectrum[idx];
+            }
+            else
+            {
+                output_buffer[idx] = Cartesian::mixed_zero();
+            }
         }
 
         output_buffer.ifft();
@@ -0,0 +1,435 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::redundant_field_names)]
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The number of phase accumulator steps in one turn, `2³²`.
+const TURN: f64 = 4294967296f64;
+
+/// Convert an angle in radians to the phase accumulator, wrapped to one turn.
+fn angle_to_phase( angle_rad: f64 ) -> u32
+{
+    let turns = angle_rad/(2f64*core::f64::consts::PI);
+    let fraction = turns - turns.mixed_floor();
+    // The fraction may round to a whole turn, which wraps to zero.
+    return ((fraction*TURN + 0.5f64).mixed_floor() as u64) as u32;
+}
+
+/// Convert the phase accumulator to an angle in radians, on `0..2π`.
+fn phase_to_angle( phase: u32 ) -> f64
+{
+    return (phase as f64)/TURN*2f64*core::f64::consts::PI;
+}
+
+/// Numerically Controlled Oscillator (NCO), for continuous carriers across buffers.
+///
+/// The phase is held in a 32-bit integer accumulator, where one turn is `2³²` steps, such that it wraps exactly and never loses precision.
+/// The frequency resolution is `2π/2³²` radians per sample.
+///
+/// The output is computed from the phase in `f64` by default, see [`Nco::new`].
+/// With [`Nco::new_with_lut`], the output is read from a cosine Look-Up Table (LUT) of type `T`, which suits fixed point types.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let omega = core::f64::consts::PI/8f64;
+/// let mut nco = Nco::<f64>::new( omega, 0f64 );
+///
+/// // Two buffers make one continuous tone.
+/// let first  = nco.generate(10);
+/// let second = nco.generate(10);
+///
+/// let reference = Vec::osc( omega, 0f64, 20 );
+/// for idx in 0..10
+/// {
+///     assert!( (second[idx].re-reference[idx+10].re).abs() < 1e-9 );
+///     assert!( (second[idx].im-reference[idx+10].im).abs() < 1e-9 );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nco<T> {
+    /// The current phase, in `2π/2³²` steps.
+    phase: u32,
+    /// The phase increment per sample, the tuning word.
+    increment: u32,
+    /// The phase set at creation, restored by [`Nco::reset`].
+    start_phase: u32,
+    /// The cosine of `2^lut_bits` uniformly spaced phases over one turn, if any.
+    lut: Option<Vec<T>>,
+    lut_bits: u32,
+}
+
+impl <T: MixedReal + MixedOps> Nco<T>
+{
+    /// Create an oscillator, with the output computed from the phase in `f64`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `angular_freq_rad` - The angular frequency in radians per sample (ω). Negative frequencies rotate clockwise.
+    /// * `phase_rad`        - The start phase in radians (θ).
+    ///
+    pub fn new( angular_freq_rad: T, phase_rad: T ) -> Nco<T>
+    {
+        let phase = angle_to_phase(phase_rad.mixed_to_num());
+        return Nco {
+            phase: phase,
+            increment: angle_to_phase(angular_freq_rad.mixed_to_num()),
+            start_phase: phase,
+            lut: None,
+            lut_bits: 0,
+        };
+    }
+
+    /// Create an oscillator, with the output read from a cosine Look-Up Table (LUT) of `2^lut_bits` entries.
+    ///
+    /// The phase is rounded to the `lut_bits` most significant bits, for a Spurious-Free Dynamic Range (SFDR) of about `6.02·lut_bits` dB.
+    /// No trigonometric function is evaluated per sample, as suited for fixed point types.
+    ///
+    /// ## Arguments
+    ///
+    /// * `angular_freq_rad` - The angular frequency in radians per sample (ω). Negative frequencies rotate clockwise.
+    /// * `phase_rad`        - The start phase in radians (θ).
+    /// * `lut_bits`         - The number of phase bits addressing the table, in `2..=20`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use fixed::FixedI32 as F;
+    /// use fixed::types::extra::U28 as U;
+    ///
+    /// let omega = F::<U>::from_num(core::f64::consts::PI/2f64);
+    /// let mut nco = Nco::<F<U>>::new_with_lut( omega, F::<U>::from_num(0), 10 );
+    ///
+    /// assert_eq!( nco.generate(4).to_string(), "[ 1+0i, 0+1i, -1+0i, 0-1i ]" );
+    /// ```
+    pub fn new_with_lut( angular_freq_rad: T, phase_rad: T, lut_bits: u32 ) -> Nco<T>
+    {
+        if !(2..=20).contains(&lut_bits)
+        {
+            core::panic!("The number of LUT bits must be in the range 2 to 20, got {}.", lut_bits);
+        }
+
+        let len = 1usize << lut_bits;
+        let mut lut = Vec::<T>::new_with_capacity(len);
+        for idx in 0..len
+        {
+            let angle = 2f64*core::f64::consts::PI*(idx as f64)/(len as f64);
+            lut.push_back(T::mixed_from_num(angle.mixed_cos()));
+        }
+
+        let mut nco = Nco::new(angular_freq_rad, phase_rad);
+        nco.lut = Some(lut);
+        nco.lut_bits = lut_bits;
+        return nco;
+    }
+
+    /// Convert an angle in radians to a phase word, in steps of `2π/2³²` and wrapped to one turn.
+    ///
+    /// Use it to convert offsets once, for [`Nco::generate_fm_words`] and [`Nco::generate_pm_words`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `angle_rad` - The angle or angular frequency in radians.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// assert_eq!( Nco::<f64>::phase_word( core::f64::consts::PI ), 1u32 << 31 );
+    /// assert_eq!( Nco::<f64>::phase_word( -core::f64::consts::PI/2f64 ), 3u32 << 30 );
+    /// ```
+    pub fn phase_word( angle_rad: T ) -> u32
+    {
+        return angle_to_phase(angle_rad.mixed_to_num());
+    }
+
+    /// The angular frequency in radians per sample, on `0..2π`.
+    pub fn frequency( &self ) -> T
+    {
+        return T::mixed_from_num(phase_to_angle(self.increment));
+    }
+
+    /// Retune the oscillator. The phase is continuous.
+    ///
+    /// ## Arguments
+    ///
+    /// * `angular_freq_rad` - The angular frequency in radians per sample (ω).
+    ///
+    pub fn set_frequency( &mut self, angular_freq_rad: T )
+    {
+        self.increment = angle_to_phase(angular_freq_rad.mixed_to_num());
+    }
+
+    /// The phase of the next sample in radians, on `0..2π`.
+    pub fn phase( &self ) -> T
+    {
+        return T::mixed_from_num(phase_to_angle(self.phase));
+    }
+
+    /// Set the phase of the next sample.
+    ///
+    /// ## Arguments
+    ///
+    /// * `phase_rad` - The phase in radians (θ).
+    ///
+    pub fn set_phase( &mut self, phase_rad: T )
+    {
+        self.phase = angle_to_phase(phase_rad.mixed_to_num());
+    }
+
+    /// Restore the start phase. The frequency is kept.
+    pub fn reset( &mut self )
+    {
+        self.phase = self.start_phase;
+    }
+
+    /// The phasor at `phase`.
+    fn phasor( &self, phase: u32 ) -> Cartesian<T>
+    {
+        match &self.lut {
+            Some(lut) => {
+                let shift = 32 - self.lut_bits;
+                let quarter = 1u32 << (self.lut_bits-2);
+                // Round to the nearest entry.
+                let index = (phase.wrapping_add(1u32 << (shift-1)) >> shift) & ((1u32 << self.lut_bits)-1);
+                // sin(φ) = cos(φ-π/2).
+                let sin_index = index.wrapping_sub(quarter) & ((1u32 << self.lut_bits)-1);
+                return Cartesian::new(lut[index as usize], lut[sin_index as usize]);
+            },
+            None => {
+                let (sin, cos) = phase_to_angle(phase).mixed_sincos();
+                return Cartesian::new(T::mixed_from_num(cos), T::mixed_from_num(sin));
+            },
+        }
+    }
+
+    /// Generate the next sample, `e^(jφ)`, and advance the phase.
+    pub fn next_sample( &mut self ) -> Cartesian<T>
+    {
+        let sample = self.phasor(self.phase);
+        self.phase = self.phase.wrapping_add(self.increment);
+        return sample;
+    }
+
+    /// Generate the next `len` samples. The phase is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len` - The number of samples.
+    ///
+    pub fn generate( &mut self, len: usize ) -> Vec<Cartesian<T>>
+    {
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(len);
+        for _ in 0..len
+        {
+            output.push_back(self.next_sample());
+        }
+        return output;
+    }
+
+    /// Overwrite a buffer with the next samples. The phase is carried over to the next call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `buffer` - The buffer to fill.
+    ///
+    pub fn fill( &mut self, buffer: &mut Vec<Cartesian<T>> )
+    {
+        for idx in 0..buffer.len()
+        {
+            buffer[idx] = self.next_sample();
+        }
+    }
+
+    /// Generate a frequency modulated signal, one sample per frequency offset. The phase is carried over to the next call.
+    ///
+    /// The instantaneous frequency of sample `n` is the frequency of the oscillator plus `frequency_offsets[n]`,
+    /// which is integrated into the phase of the following samples.
+    ///
+    /// Each offset is converted to a phase word in double precision.
+    /// On targets without a floating point unit, convert the offsets once with [`Nco::phase_word`], and use [`Nco::generate_fm_words`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency_offsets` - The offset from the frequency of the oscillator, in radians per sample, for each sample.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // A step in frequency, from π/4 to π/2 radians per sample.
+    /// let mut nco = Nco::<f64>::new( core::f64::consts::PI/4f64, 0f64 );
+    /// let mut offsets = Vec::<f64>::new_with_capacity(8);
+    /// for idx in 0..8
+    /// {
+    ///     offsets.push_back( if idx < 4 { 0f64 } else { core::f64::consts::PI/4f64 } );
+    /// }
+    /// let signal = nco.generate_fm( &offsets );
+    ///
+    /// // The phase is π after four samples, then advances by π/2.
+    /// assert!( (signal[4].re+1f64).abs() < 1e-9 );
+    /// assert!( (signal[5].im+1f64).abs() < 1e-9 );
+    /// ```
+    pub fn generate_fm( &mut self, frequency_offsets: &Vec<T> ) -> Vec<Cartesian<T>>
+    {
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(frequency_offsets.len());
+        for idx in 0..frequency_offsets.len()
+        {
+            output.push_back(self.phasor(self.phase));
+            let offset = angle_to_phase(frequency_offsets[idx].mixed_to_num());
+            self.phase = self.phase.wrapping_add(self.increment).wrapping_add(offset);
+        }
+        return output;
+    }
+
+    /// Generate a phase modulated signal, one sample per phase offset. The phase is carried over to the next call.
+    ///
+    /// The phase offsets are added to the phase of each sample, and are not accumulated.
+    ///
+    /// Each offset is converted to a phase word in double precision.
+    /// On targets without a floating point unit, convert the offsets once with [`Nco::phase_word`], and use [`Nco::generate_pm_words`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `phase_offsets` - The offset from the phase of the oscillator, in radians, for each sample.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // BPSK at DC.
+    /// let mut nco = Nco::<f64>::new( 0f64, 0f64 );
+    /// let symbols = Vec::new_from_vec( vec![0f64, core::f64::consts::PI, 0f64] );
+    ///
+    /// let signal = nco.generate_pm( &symbols );
+    /// assert_eq!( signal.re().to_string(), "[ 1, -1, 1 ]" );
+    /// ```
+    pub fn generate_pm( &mut self, phase_offsets: &Vec<T> ) -> Vec<Cartesian<T>>
+    {
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(phase_offsets.len());
+        for idx in 0..phase_offsets.len()
+        {
+            let offset = angle_to_phase(phase_offsets[idx].mixed_to_num());
+            output.push_back(self.phasor(self.phase.wrapping_add(offset)));
+            self.phase = self.phase.wrapping_add(self.increment);
+        }
+        return output;
+    }
+
+    /// Generate a frequency modulated signal from frequency offsets in phase words, see [`Nco::generate_fm`].
+    ///
+    /// The phase is updated in integer arithmetic only.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency_offsets` - The offset from the frequency of the oscillator, in steps of `2π/2³²` radians per sample, for each sample.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // Binary FSK at ±π/2 radians per sample, with the offsets converted once.
+    /// let mut nco = Nco::<f64>::new( 0f64, 0f64 );
+    /// let high = Nco::<f64>::phase_word( core::f64::consts::PI/2f64 );
+    /// let low  = Nco::<f64>::phase_word( -core::f64::consts::PI/2f64 );
+    ///
+    /// let signal = nco.generate_fm_words( &[high, high, low] );
+    /// assert!( (signal[1].im-1f64).abs() < 1e-9 );
+    /// assert!( (signal[2].re+1f64).abs() < 1e-9 );
+    /// ```
+    pub fn generate_fm_words( &mut self, frequency_offsets: &[u32] ) -> Vec<Cartesian<T>>
+    {
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(frequency_offsets.len());
+        for idx in 0..frequency_offsets.len()
+        {
+            output.push_back(self.phasor(self.phase));
+            self.phase = self.phase.wrapping_add(self.increment).wrapping_add(frequency_offsets[idx]);
+        }
+        return output;
+    }
+
+    /// Generate a phase modulated signal from phase offsets in phase words, see [`Nco::generate_pm`].
+    ///
+    /// The phase is updated in integer arithmetic only.
+    ///
+    /// ## Arguments
+    ///
+    /// * `phase_offsets` - The offset from the phase of the oscillator, in steps of `2π/2³²` radians, for each sample.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // QPSK at DC, with the constellation converted once.
+    /// let mut nco = Nco::<f64>::new( 0f64, 0f64 );
+    /// let mut constellation = [0u32; 4];
+    /// for idx in 0..4
+    /// {
+    ///     constellation[idx] = Nco::<f64>::phase_word( (idx as f64)*core::f64::consts::PI/2f64 );
+    /// }
+    ///
+    /// let signal = nco.generate_pm_words( &[constellation[0], constellation[2], constellation[1]] );
+    /// assert!( (signal[1].re+1f64).abs() < 1e-9 );
+    /// assert!( (signal[2].im-1f64).abs() < 1e-9 );
+    /// ```
+    pub fn generate_pm_words( &mut self, phase_offsets: &[u32] ) -> Vec<Cartesian<T>>
+    {
+        let mut output = Vec::<Cartesian<T>>::new_with_capacity(phase_offsets.len());
+        for idx in 0..phase_offsets.len()
+        {
+            output.push_back(self.phasor(self.phase.wrapping_add(phase_offsets[idx])));
+            self.phase = self.phase.wrapping_add(self.increment);
+        }
+        return output;
+    }
+
+    /// Mix a buffer with the oscillator, multiplying each sample by the next output sample. The phase is carried over to the next call.
+    ///
+    /// Use a positive frequency to shift the spectrum up, and a negative frequency to shift it down.
+    ///
+    /// Computed-in-place.
+    ///
+    /// ## Arguments
+    ///
+    /// * `buffer` - The signal to shift in frequency.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // Down-convert a tone at π/8 radians per sample to DC.
+    /// let mut signal = Vec::osc( core::f64::consts::PI/8f64, 0.5f64, 32 );
+    /// let mut nco = Nco::<f64>::new( -core::f64::consts::PI/8f64, 0f64 );
+    /// nco.mix( &mut signal );
+    ///
+    /// for idx in 0..32
+    /// {
+    ///     assert!( (signal[idx].re-0.5f64.cos()).abs() < 1e-6 );
+    ///     assert!( (signal[idx].im-0.5f64.sin()).abs() < 1e-6 );
+    /// }
+    /// ```
+    pub fn mix( &mut self, buffer: &mut Vec<Cartesian<T>> )
+    {
+        for idx in 0..buffer.len()
+        {
+            let x = buffer[idx];
+            let w = self.next_sample();
+            buffer[idx] = Cartesian::new(x.re*w.re - x.im*w.im, x.re*w.im + x.im*w.re);
+        }
+    }
+}
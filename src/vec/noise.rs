@@ -0,0 +1,579 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern, clippy::redundant_field_names)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The seed used when no generator is given, e.g. by [`Wgn::wgn`] and [`AddAwgn::add_awgn`].
+pub const DEFAULT_SEED: u64 = 0x4E44_5350;
+
+/// A seeded pseudo-random number generator, xoshiro256** by Blackman and Vigna, with Gaussian sampling by the Box–Muller transform.
+///
+/// The generator needs no operating system, and the same seed always gives the same sequence.
+/// It is fast and of high statistical quality, but is not cryptographically secure.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let mut rng = Prng::new(1234);
+/// let uniform = rng.uniform();
+/// assert!( (0f64..1f64).contains(&uniform) );
+///
+/// let mut same_rng = Prng::new(1234);
+/// assert_eq!( same_rng.uniform(), uniform );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Prng {
+    state: [u64; 4],
+    spare_gaussian: Option<f64>,
+}
+
+impl Prng {
+    /// Create a new generator. The state is expanded from `seed` with SplitMix64, such that any seed, including zero, is valid.
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - The seed of the sequence.
+    ///
+    pub fn new( seed: u64 ) -> Prng
+    {
+        let mut splitmix = seed;
+        let mut state = [0u64; 4];
+        for idx in 0..4
+        {
+            splitmix = splitmix.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = splitmix;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            state[idx] = z ^ (z >> 31);
+        }
+        return Prng { state: state, spare_gaussian: None };
+    }
+
+    /// The next 64 random bits.
+    pub fn next_u64( &mut self ) -> u64
+    {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] = self.state[2] ^ self.state[0];
+        self.state[3] = self.state[3] ^ self.state[1];
+        self.state[1] = self.state[1] ^ self.state[2];
+        self.state[0] = self.state[0] ^ self.state[3];
+        self.state[2] = self.state[2] ^ t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        return result;
+    }
+
+    /// A sample of the uniform distribution on `[0, 1)`, with 53 bits of resolution.
+    pub fn uniform( &mut self ) -> f64
+    {
+        return ((self.next_u64() >> 11) as f64)/((1u64 << 53) as f64);
+    }
+
+    /// A sample of the standard normal distribution, with zero mean and unit variance.
+    ///
+    /// The Box–Muller transform gives two samples from two uniform samples, and the second is kept for the next call.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let mut rng = Prng::new(1);
+    /// let mut samples = Vec::<f64>::new_with_capacity(10000);
+    /// for _ in 0..10000
+    /// {
+    ///     samples.push_back( rng.gaussian() );
+    /// }
+    ///
+    /// assert!( samples.mean().abs() < 0.05 );
+    /// assert!( (samples.variance(1)-1f64).abs() < 0.05 );
+    /// ```
+    pub fn gaussian( &mut self ) -> f64
+    {
+        if let Some(sample) = self.spare_gaussian.take()
+        {
+            return sample;
+        }
+
+        // 1-u is on (0, 1], avoiding the logarithm of zero.
+        let radius = (-2f64*(1f64-self.uniform()).mixed_log()).mixed_sqrt();
+        let (sin, cos) = (2f64*core::f64::consts::PI*self.uniform()).mixed_sincos();
+        self.spare_gaussian = Some(radius*sin);
+        return radius*cos;
+    }
+}
+
+/// The ratio of noise power to signal power for `snr_db`.
+fn noise_to_signal<T: MixedReal>( snr_db: T ) -> f64
+{
+    let snr_db: f64 = snr_db.mixed_to_num();
+    return 10f64.mixed_pow(-snr_db/10f64);
+}
+
+impl <T: MixedReal> Wgn<T> for Vec<T>
+{
+    /// Generate real White Gaussian Noise (WGN) with the default seed, [`DEFAULT_SEED`].
+    ///
+    /// Each call returns the same noise. Use [`Wgn::wgn_with_rng`] for independent realizations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise, its variance.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let noise = Vec::<f64>::wgn( 10000, 2f64 );
+    /// assert!( (noise.variance(0)-2f64).abs() < 0.1 );
+    /// ```
+    fn wgn( len: usize, power: T ) -> Self
+    {
+        return Self::wgn_with_rng(len, power, &mut Prng::new(DEFAULT_SEED));
+    }
+
+    /// Generate real White Gaussian Noise (WGN) from a generator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise, its variance.
+    /// * `rng`   - The generator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let mut rng = Prng::new(42);
+    /// let first  = Vec::<f64>::wgn_with_rng( 16, 1f64, &mut rng );
+    /// let second = Vec::<f64>::wgn_with_rng( 16, 1f64, &mut rng );
+    /// assert_ne!( first, second );
+    /// ```
+    fn wgn_with_rng( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        let power: f64 = power.mixed_to_num();
+        let std = power.mixed_sqrt();
+
+        let mut noise = Vec::<T>::new_with_capacity(len);
+        for _ in 0..len
+        {
+            noise.push_back(T::mixed_from_num(std*rng.gaussian()));
+        }
+        return noise;
+    }
+}
+
+impl <T: MixedReal> Wgn<T> for Vec<Cartesian<T>>
+{
+    /// Generate circularly-symmetric complex White Gaussian Noise (WGN) with the default seed, [`DEFAULT_SEED`].
+    ///
+    /// Each call returns the same noise. Use [`Wgn::wgn_with_rng`] for independent realizations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise, `E|x|²`, split equally between the real and imaginary parts.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let noise = Vec::<Cartesian<f64>>::wgn( 10000, 2f64 );
+    /// assert!( (noise.variance(0)-2f64).abs() < 0.1 );
+    /// assert!( (noise.re().variance(0)-1f64).abs() < 0.05 );
+    /// ```
+    fn wgn( len: usize, power: T ) -> Self
+    {
+        return Self::wgn_with_rng(len, power, &mut Prng::new(DEFAULT_SEED));
+    }
+
+    /// Generate circularly-symmetric complex White Gaussian Noise (WGN) from a generator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise, `E|x|²`, split equally between the real and imaginary parts.
+    /// * `rng`   - The generator.
+    ///
+    fn wgn_with_rng( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        let power: f64 = power.mixed_to_num();
+        let std = (power/2f64).mixed_sqrt();
+
+        let mut noise = Vec::<Cartesian<T>>::new_with_capacity(len);
+        for _ in 0..len
+        {
+            let re = std*rng.gaussian();
+            let im = std*rng.gaussian();
+            noise.push_back(Cartesian::new(T::mixed_from_num(re), T::mixed_from_num(im)));
+        }
+        return noise;
+    }
+}
+
+impl <T: MixedReal + MixedZero + MixedOps + MixedPowi> AddAwgn<T> for Vec<T>
+{
+    /// Add real White Gaussian Noise (WGN) with the default seed, [`DEFAULT_SEED`], at a Signal-to-Noise Ratio (SNR).
+    ///
+    /// The signal power is measured over the vector with [`Energy`].
+    ///
+    /// Computed-in-place.
+    ///
+    /// ## Arguments
+    ///
+    /// * `snr_db` - The SNR in dB.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let signal = Vec::<Cartesian<f64>>::osc( 0.1f64, 0f64, 10000 ).re();
+    /// let mut noisy = signal.clone();
+    /// noisy.add_awgn( 10f64 );
+    ///
+    /// let noise = &noisy - &signal;
+    /// assert!( (noise.variance(0)-0.05f64).abs() < 0.005 );
+    /// ```
+    fn add_awgn( &mut self, snr_db: T )
+    {
+        self.add_awgn_with_rng(snr_db, &mut Prng::new(DEFAULT_SEED));
+    }
+
+    /// Add real White Gaussian Noise (WGN) from a generator at a Signal-to-Noise Ratio (SNR).
+    ///
+    /// Computed-in-place.
+    ///
+    /// ## Arguments
+    ///
+    /// * `snr_db` - The SNR in dB.
+    /// * `rng`    - The generator.
+    ///
+    fn add_awgn_with_rng( &mut self, snr_db: T, rng: &mut Prng )
+    {
+        if self.len() == 0
+        {
+            return;
+        }
+
+        let energy: f64 = self.energy().mixed_to_num();
+        let noise_power = energy/(self.len() as f64)*noise_to_signal(snr_db);
+        let noise = Vec::<T>::wgn_with_rng(self.len(), T::mixed_from_num(noise_power), rng);
+        for idx in 0..self.len()
+        {
+            self[idx] = self[idx] + noise[idx];
+        }
+    }
+}
+
+impl <T: MixedReal + MixedZero + MixedOps + MixedPowi> AddAwgn<T> for Vec<Cartesian<T>>
+{
+    /// Add circularly-symmetric complex White Gaussian Noise (WGN) with the default seed, [`DEFAULT_SEED`], at a Signal-to-Noise Ratio (SNR).
+    ///
+    /// The signal power is measured over the vector with [`Energy`] of the real and imaginary parts.
+    ///
+    /// Computed-in-place.
+    ///
+    /// ## Arguments
+    ///
+    /// * `snr_db` - The SNR in dB.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let mut signal = Vec::<Cartesian<f64>>::osc( 0.1f64, 0f64, 10000 );
+    /// signal.add_awgn( 0f64 );
+    ///
+    /// // Unit signal power plus unit noise power.
+    /// assert!( (signal.rms().powi(2)-2f64).abs() < 0.05 );
+    /// ```
+    fn add_awgn( &mut self, snr_db: T )
+    {
+        self.add_awgn_with_rng(snr_db, &mut Prng::new(DEFAULT_SEED));
+    }
+
+    /// Add circularly-symmetric complex White Gaussian Noise (WGN) from a generator at a Signal-to-Noise Ratio (SNR).
+    ///
+    /// Computed-in-place.
+    ///
+    /// ## Arguments
+    ///
+    /// * `snr_db` - The SNR in dB.
+    /// * `rng`    - The generator.
+    ///
+    fn add_awgn_with_rng( &mut self, snr_db: T, rng: &mut Prng )
+    {
+        if self.len() == 0
+        {
+            return;
+        }
+
+        let energy_re: f64 = self.re().energy().mixed_to_num();
+        let energy_im: f64 = self.im().energy().mixed_to_num();
+        let noise_power = (energy_re+energy_im)/(self.len() as f64)*noise_to_signal(snr_db);
+        let noise = Vec::<Cartesian<T>>::wgn_with_rng(self.len(), T::mixed_from_num(noise_power), rng);
+        for idx in 0..self.len()
+        {
+            self[idx] = Cartesian::new(self[idx].re + noise[idx].re, self[idx].im + noise[idx].im);
+        }
+    }
+}
+
+/// Noise with the Power Spectral Density (PSD) `shape(f)`, for frequencies `f` in cycles per sample on `-0.5..0.5`, and a total power of `power`.
+///
+/// A circularly-symmetric complex Gaussian spectrum, with the variance of each bin from the shape, is transformed with [`Ifft`].
+/// As the inverse FFT is unscaled, the power of the noise is the sum of the variances of the bins.
+fn shaped_spectrum<T, F>( len: usize, shape: F, power: f64, rng: &mut Prng ) -> Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi,
+          F: Fn(f64) -> f64
+{
+    let mut variances = alloc::vec::Vec::<f64>::with_capacity(len);
+    let mut sum = 0f64;
+    for k in 0..len
+    {
+        let frequency = if 2*k <= len { k as f64 } else { k as f64 - len as f64 }/(len as f64);
+        let variance = shape(frequency);
+        if variance < 0f64 || variance.is_nan()
+        {
+            core::panic!("The power spectral density must be non-negative, got {} at {} cycles per sample.", variance, frequency);
+        }
+        variances.push(variance);
+        sum = sum + variance;
+    }
+
+    let mut spectrum = Vec::<Cartesian<T>>::new_with_capacity(len);
+    for k in 0..len
+    {
+        let std = if sum == 0f64 { 0f64 } else { (power*variances[k]/sum/2f64).mixed_sqrt() };
+        let re = std*rng.gaussian();
+        let im = std*rng.gaussian();
+        spectrum.push_back(Cartesian::new(T::mixed_from_num(re), T::mixed_from_num(im)));
+    }
+    spectrum.ifft();
+    return spectrum;
+}
+
+/// Linear interpolation of the uniformly spaced samples of `mask`, spanning `start..=stop`, at `frequency`.
+fn interpolate_mask( mask: &[f64], start: f64, stop: f64, frequency: f64 ) -> f64
+{
+    if mask.len() == 1
+    {
+        return mask[0];
+    }
+    let position = (frequency-start)/(stop-start)*((mask.len()-1) as f64);
+    let lower = (position.mixed_floor() as usize).min(mask.len()-2);
+    let fraction = position - lower as f64;
+    return mask[lower] + fraction*(mask[lower+1]-mask[lower]);
+}
+
+/// Convert a PSD mask to `f64`.
+fn mask_to_f64<T: MixedReal>( psd_mask: &Vec<T> ) -> alloc::vec::Vec<f64>
+{
+    if psd_mask.len() == 0
+    {
+        core::panic!("The power spectral density mask must have at least one sample.");
+    }
+    let mut mask = alloc::vec::Vec::<f64>::with_capacity(psd_mask.len());
+    for idx in 0..psd_mask.len()
+    {
+        mask.push(psd_mask[idx].mixed_to_num());
+    }
+    return mask;
+}
+
+/// The PSD of pink noise, `1/|f|`, with no power at DC.
+fn pink( frequency: f64 ) -> f64
+{
+    return if frequency == 0f64 { 0f64 } else { 1f64/frequency.mixed_abs() };
+}
+
+/// The PSD of brown noise, `1/f²`, with no power at DC.
+fn brown( frequency: f64 ) -> f64
+{
+    return if frequency == 0f64 { 0f64 } else { 1f64/(frequency*frequency) };
+}
+
+impl <T> ColoredNoise<T> for Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Generate real pink noise, with a Power Spectral Density (PSD) proportional to `1/f`, i.e. -3 dB per octave.
+    ///
+    /// The noise is shaped in the frequency domain, and has no DC component.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise.
+    /// * `rng`   - The generator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let noise = Vec::<f64>::pink_noise( 4096, 1f64, &mut Prng::new(1) );
+    /// assert_eq!( noise.len(), 4096 );
+    /// assert!( (noise.variance(0)-1f64).abs() < 0.2 );
+    /// ```
+    fn pink_noise( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        let power: f64 = power.mixed_to_num();
+        return shaped_spectrum::<T, _>(len, pink, 2f64*power, rng).re();
+    }
+
+    /// Generate real brown noise, with a Power Spectral Density (PSD) proportional to `1/f²`, i.e. -6 dB per octave.
+    ///
+    /// The noise is shaped in the frequency domain, and has no DC component.
+    /// Most of the power is in the lowest frequency bins, such that the power of a realization varies considerably around `power`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The expected power of the noise.
+    /// * `rng`   - The generator.
+    ///
+    fn brown_noise( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        let power: f64 = power.mixed_to_num();
+        return shaped_spectrum::<T, _>(len, brown, 2f64*power, rng).re();
+    }
+
+    /// Generate real noise with a Power Spectral Density (PSD) shaped by a mask.
+    ///
+    /// The mask is sampled uniformly from DC to the Nyquist frequency, both included, and interpolated linearly to the frequency bins.
+    /// Only its shape matters, as the noise is scaled to `power`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `psd_mask` - The non-negative, linear PSD from DC to the Nyquist frequency.
+    /// * `power`    - The power of the noise.
+    /// * `rng`      - The generator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // Lowpass noise, with all power below a quarter of the sample rate.
+    /// let mask = Vec::new_from_vec( vec![1f64, 1f64, 0f64, 0f64, 0f64] );
+    /// let noise = Vec::<f64>::shaped_noise( 1000, &mask, 1f64, &mut Prng::new(2) );
+    ///
+    /// let (_, psd) = noise.periodogram( &Vec::ones(1000), 1f64, PsdScaling::Spectrum, PsdSides::OneSided );
+    /// let mut high_band_power = 0f64;
+    /// for idx in 300..501
+    /// {
+    ///     high_band_power += psd[idx];
+    /// }
+    /// assert!( high_band_power < 1e-20 );
+    /// ```
+    fn shaped_noise( len: usize, psd_mask: &Vec<T>, power: T, rng: &mut Prng ) -> Self
+    {
+        let power: f64 = power.mixed_to_num();
+        let mask = mask_to_f64(psd_mask);
+        let shape = |frequency: f64| interpolate_mask(&mask, 0f64, 0.5f64, frequency.mixed_abs());
+        return shaped_spectrum::<T, _>(len, shape, 2f64*power, rng).re();
+    }
+}
+
+impl <T> ColoredNoise<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Generate complex pink noise, with a Power Spectral Density (PSD) proportional to `1/|f|`, i.e. -3 dB per octave.
+    ///
+    /// The noise is shaped in the frequency domain, and has no DC component.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The power of the noise, `E|x|²`.
+    /// * `rng`   - The generator.
+    ///
+    fn pink_noise( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        return shaped_spectrum(len, pink, power.mixed_to_num(), rng);
+    }
+
+    /// Generate complex brown noise, with a Power Spectral Density (PSD) proportional to `1/f²`, i.e. -6 dB per octave.
+    ///
+    /// The noise is shaped in the frequency domain, and has no DC component.
+    /// Most of the power is in the lowest frequency bins, such that the power of a realization varies considerably around `power`.
+    /// Used e.g. to model the random-walk phase noise of an oscillator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`   - The number of samples.
+    /// * `power` - The expected power of the noise, `E|x|²`.
+    /// * `rng`   - The generator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let noise = Vec::<Cartesian<f64>>::brown_noise( 4096, 1f64, &mut Prng::new(3) );
+    /// assert_eq!( noise.len(), 4096 );
+    /// assert!( noise.re().mean().abs() < 1e-12 );
+    /// ```
+    fn brown_noise( len: usize, power: T, rng: &mut Prng ) -> Self
+    {
+        return shaped_spectrum(len, brown, power.mixed_to_num(), rng);
+    }
+
+    /// Generate complex noise with a Power Spectral Density (PSD) shaped by a mask.
+    ///
+    /// The mask is sampled uniformly from minus to plus the Nyquist frequency, both included, and interpolated linearly to the frequency bins.
+    /// Only its shape matters, as the noise is scaled to `power`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `psd_mask` - The non-negative, linear PSD from minus to plus the Nyquist frequency.
+    /// * `power`    - The power of the noise, `E|x|²`.
+    /// * `rng`      - The generator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // Noise at positive frequencies only.
+    /// let mask = Vec::new_from_vec( vec![0f64, 0f64, 0f64, 1f64, 1f64] );
+    /// let mut spectrum = Vec::<Cartesian<f64>>::shaped_noise( 64, &mask, 1f64, &mut Prng::new(4) );
+    /// spectrum.fft();
+    ///
+    /// for k in 33..64
+    /// {
+    ///     assert!( spectrum[k].re.abs() < 1e-12 && spectrum[k].im.abs() < 1e-12 );
+    /// }
+    /// ```
+    fn shaped_noise( len: usize, psd_mask: &Vec<T>, power: T, rng: &mut Prng ) -> Self
+    {
+        let mask = mask_to_f64(psd_mask);
+        let shape = |frequency: f64| interpolate_mask(&mask, -0.5f64, 0.5f64, frequency);
+        return shaped_spectrum(len, shape, power.mixed_to_num(), rng);
+    }
+}
@@ -204,7 +204,7 @@ impl <T:MixedNum + MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt +
 {
     /// Calculate the Power Spectral Density (PSD) in linear scale of a signal.
     /// 
-    /// Expects the signal length to be a power of two. If not, the signal is zero padded.
+    /// The PSD has the same length as the signal. Any signal length is supported.
     /// 
     /// For signals in the magnitude<1 range, the PSD plot is in dBFS.
     /// 
@@ -250,3 +250,277 @@ impl <T:MixedNum + MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt +
     }
 }
 
+
+impl <T: MixedReal> Vec<Vec<Cartesian<T>>>
+{
+    /// Plot the spectrogram of a frames×bins Short-Time Fourier Transform (STFT) matrix, see [`Stft`], as a heat map.
+    /// 
+    /// The power of each bin is shown in dB, from `floor_db` to the maximum of the spectrogram.
+    /// The bins are rearranged from [0,fs] to [-fs/2,fs/2].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `hop`            - The number of samples between the start of consecutive frames.
+    /// * `sample_rate_hz` - The sample rate of the signal.
+    /// * `floor_db`       - The lowest power shown.
+    /// * `path`           - The path and name of the file ot be generated.
+    /// * `caption`        - The plot caption.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    /// 
+    /// // A linear chirp from -400 Hz to 400 Hz over one second.
+    /// let sample_rate_hz = 1e3f32;
+    /// let n = 1000;
+    /// let mut signal = Vec::<Cartesian<f32>>::new_with_capacity(n);
+    /// for idx in 0..n
+    /// {
+    ///     let t = (idx as f32)/sample_rate_hz;
+    ///     let phase = f32::mixed_tau()*(-400f32*t + 400f32*t*t);
+    ///     signal.push_back( Cartesian::new(phase.cos(), phase.sin()) );
+    /// }
+    /// 
+    /// let window = Vec::<f32>::hamming(64);
+    /// let spectrogram = signal.stft( &window, 16, 64 );
+    /// 
+    /// spectrogram.plot_spectrogram( 16, sample_rate_hz, -60f32, "./figures/plot_spectrogram.png", "Spectrogram" );
+    /// ```
+    /// 
+    /// The resulting plot is shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/plot_spectrogram.png) 
+    pub fn plot_spectrogram( &self, hop: usize, sample_rate_hz: T, floor_db: T, path: &str, caption: &str ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let sample_rate_hz: f32 = sample_rate_hz.mixed_to_num();
+        let floor_db: f32 = floor_db.mixed_to_num();
+
+        let num_frames = self.len();
+        let fft_size = self[0].len();
+
+        // Power in dB, with the bins rearranged to [-fs/2,fs/2].
+        let mut power_db = std::vec::Vec::<f32>::with_capacity(num_frames*fft_size);
+        let mut max_db = floor_db;
+        for frame in 0..num_frames
+        {
+            for k in 0..fft_size
+            {
+                let x = self[frame][(k + fft_size - fft_size/2)%fft_size];
+                let re: f32 = x.re.mixed_to_num();
+                let im: f32 = x.im.mixed_to_num();
+                let db = (10f32*(re*re + im*im).log10()).max(floor_db);
+                if max_db < db
+                {
+                    max_db = db;
+                }
+                power_db.push(db);
+            }
+        }
+        let range_db = (max_db-floor_db).max(f32::EPSILON);
+
+        let time_step_s  = (hop as f32)/sample_rate_hz;
+        let freq_step_hz = sample_rate_hz/(fft_size as f32);
+        let min_freq_hz  = -((fft_size/2) as f32)*freq_step_hz;
+
+        let root = BitMapBackend::new(path, (1000, 500)).into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 25).into_font())
+            .margin(10i32)
+            .x_label_area_size(40i32)
+            .y_label_area_size(50i32)
+            .build_cartesian_2d( 0f32..(num_frames as f32)*time_step_s, min_freq_hz..min_freq_hz+(fft_size as f32)*freq_step_hz )?;
+
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .y_desc("Frequency [Hz]")
+            .x_desc("Time [s]")
+            .axis_desc_style(("sans-serif", 15))
+            .draw()?;
+
+        // Blue for the floor, through green, to red for the maximum.
+        chart.draw_series( (0..num_frames*fft_size).map(|idx| {
+            let frame = idx/fft_size;
+            let bin = idx%fft_size;
+            let level = (power_db[idx]-floor_db)/range_db;
+
+            let t0 = (frame as f32)*time_step_s;
+            let f0 = min_freq_hz + (bin as f32)*freq_step_hz;
+            Rectangle::new( [(t0, f0), (t0+time_step_s, f0+freq_step_hz)], HSLColor(0.66*(1.0-level as f64), 1.0, 0.5).filled() )
+        }))?;
+
+        Ok(())
+    }
+}
+
+impl <T: MixedReal> FrequencyResponse<T>
+{
+    /// Bode plot of the frequency response, with the magnitude in dB above the unwrapped phase in degrees.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `floor_db` - The lowest magnitude shown.
+    /// * `path`     - The path and name of the file ot be generated.
+    /// * `caption`  - The plot caption.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let sample_rate_hz = 48e3f64;
+    /// let sos = Zpk::<f64>::design( 6, IirPrototype::Elliptic{ ripple_db: 0.5f64, attenuation_db: 60f64 }, FilterBand::Lowpass(4e3f64/sample_rate_hz) ).to_sos();
+    /// 
+    /// let frequencies = Vec::lin_range( 0f64, sample_rate_hz/2f64, 512 );
+    /// let h = freqz_sos( &sos, &frequencies, sample_rate_hz );
+    /// 
+    /// h.plot_bode( -100f64, "./figures/plot_bode.png", "Bode Plot" );
+    /// ```
+    /// 
+    /// The resulting plot is shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/plot_bode.png) 
+    pub fn plot_bode( &self, floor_db: T, path: &str, caption: &str ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let floor_db: f32 = floor_db.mixed_to_num();
+
+        let len = self.frequencies.len();
+        let mut frequencies  = Vec::<f32>::new_with_capacity(len);
+        let mut magnitude_db = Vec::<f32>::new_with_capacity(len);
+        let mut phase_deg    = Vec::<f32>::new_with_capacity(len);
+        for idx in 0..len
+        {
+            let frequency: f32 = self.frequencies[idx].mixed_to_num();
+            let magnitude: f32 = self.magnitude_db[idx].mixed_to_num();
+            let phase: f32 = self.phase[idx].mixed_to_num();
+            frequencies.push_back(frequency);
+            magnitude_db.push_back(magnitude.max(floor_db));
+            phase_deg.push_back(phase.to_degrees());
+        }
+
+        let root = BitMapBackend::new(path, (1000, 800)).into_drawing_area();
+        root.fill(&WHITE)?;
+        let (upper, lower) = root.split_vertically(400);
+
+        let mut chart = ChartBuilder::on(&upper)
+            .caption(caption, ("sans-serif", 25).into_font())
+            .margin(10i32)
+            .x_label_area_size(40i32)
+            .y_label_area_size(50i32)
+            .build_cartesian_2d( frequencies.to_range(), magnitude_db.to_range() )?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .bold_line_style(WHITE.mix(0.3))
+            .y_desc("Magnitude [dB]")
+            .x_desc("Frequency")
+            .axis_desc_style(("sans-serif", 15))
+            .draw()?;
+
+        chart
+            .draw_series( LineSeries::new(
+                frequencies.to_xy_touples(&magnitude_db),
+                &BLUE) )?;
+
+        let mut chart = ChartBuilder::on(&lower)
+            .margin(10i32)
+            .x_label_area_size(40i32)
+            .y_label_area_size(50i32)
+            .build_cartesian_2d( frequencies.to_range(), phase_deg.to_range() )?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .bold_line_style(WHITE.mix(0.3))
+            .y_desc("Phase [deg]")
+            .x_desc("Frequency")
+            .axis_desc_style(("sans-serif", 15))
+            .draw()?;
+
+        chart
+            .draw_series( LineSeries::new(
+                frequencies.to_xy_touples(&phase_deg),
+                &BLUE) )?;
+
+        Ok(())
+    }
+}
+
+impl <T: MixedReal> crate::vec::Histogram<T>
+{
+    /// Bar chart of the histogram, with one bar spanning each bin.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `path`    - The path and name of the file ot be generated.
+    /// * `caption` - The plot caption.
+    /// * `x_label` - The label of the values.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    /// 
+    /// // The amplitude distribution of a sinusoid, with peaks at the extremes.
+    /// let signal = Vec::<Cartesian<f64>>::osc( 0.01f64, 0f64, 10000 ).re();
+    /// let histogram = signal.histogram( HistogramBins::FreedmanDiaconis );
+    /// 
+    /// histogram.plot_histogram( "./figures/plot_histogram.png", "Histogram", "Amplitude" );
+    /// ```
+    /// 
+    /// The resulting plot is shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/plot_histogram.png) 
+    pub fn plot_histogram( &self, path: &str, caption: &str, x_label: &str ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let num_bins = self.counts.len();
+        let min_value: f32 = self.edges[0].mixed_to_num();
+        let max_value: f32 = self.edges[num_bins].mixed_to_num();
+
+        let mut max_count = 1usize;
+        for idx in 0..num_bins
+        {
+            if max_count < self.counts[idx]
+            {
+                max_count = self.counts[idx];
+            }
+        }
+
+        let root = BitMapBackend::new(path, (1000, 500)).into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 25).into_font())
+            .margin(10i32)
+            .x_label_area_size(40i32)
+            .y_label_area_size(50i32)
+            .build_cartesian_2d( min_value..max_value, 0f32..1.05f32*(max_count as f32) )?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .bold_line_style(WHITE.mix(0.3))
+            .y_desc("Count")
+            .x_desc(x_label)
+            .axis_desc_style(("sans-serif", 15))
+            .draw()?;
+
+        chart.draw_series( (0..num_bins).map(|idx| {
+            let lower: f32 = self.edges[idx].mixed_to_num();
+            let upper: f32 = self.edges[idx+1].mixed_to_num();
+            Rectangle::new( [(lower, 0f32), (upper, self.counts[idx] as f32)], BLUE.filled() )
+        }))?;
+
+        chart.draw_series( (0..num_bins).map(|idx| {
+            let lower: f32 = self.edges[idx].mixed_to_num();
+            let upper: f32 = self.edges[idx+1].mixed_to_num();
+            Rectangle::new( [(lower, 0f32), (upper, self.counts[idx] as f32)], WHITE.stroke_width(1) )
+        }))?;
+
+        Ok(())
+    }
+}
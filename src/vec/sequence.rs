@@ -0,0 +1,509 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// Primitive polynomials over GF(2) of degree 2 to 32, with the fewest non-zero coefficients.
+///
+/// Bit `k` is the coefficient of `xᵏ`, e.g. `0x25` is `x⁵+x²+1`.
+const PRIMITIVE_POLYNOMIALS: [u64; 31] = [
+    0x7,         0xb,         0x13,        0x25,        0x43,        0x83,        0x187,       0x211,
+    0x409,       0x805,       0x1107,      0x2027,      0x5007,      0x8003,      0x1100b,     0x20009,
+    0x40081,     0x80027,     0x100009,    0x200005,    0x400003,    0x800021,    0x1000087,   0x2000009,
+    0x4000047,   0x8000027,   0x10000009,  0x20000005,  0x40800007,  0x80000009,  0x100400007,
+];
+
+/// Look up a primitive polynomial of degree `degree`, for a maximal-length sequence of period `2^degree-1`.
+///
+/// Bit `k` of the polynomial is the coefficient of `xᵏ`.
+///
+/// ## Arguments
+///
+/// * `degree` - The degree of the polynomial, from 2 to 32.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // x⁵+x²+1
+/// assert_eq!( primitive_polynomial(5), 0b100101 );
+/// ```
+pub fn primitive_polynomial( degree: u32 ) -> u64
+{
+    if !(2..=32).contains(&degree)
+    {
+        core::panic!("Primitive polynomials are tabulated for degrees 2 to 32, got {}.", degree);
+    }
+    return PRIMITIVE_POLYNOMIALS[(degree-2) as usize];
+}
+
+/// The structure of a Linear-Feedback Shift Register (LFSR), see [`Lfsr`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LfsrKind {
+    /// The feedback is the parity of the tapped bits, shifted in at one end. The state holds the next `degree` output bits.
+    Fibonacci,
+    /// The output bit is added to the tapped bits as it is shifted out, i.e. the state is multiplied by `x` modulo the polynomial.
+    Galois,
+}
+
+/// Linear-Feedback Shift Register (LFSR), for pseudo-noise bit sequences over GF(2).
+///
+/// The polynomial `p(x) = xᵐ + cₘ₋₁xᵐ⁻¹ + ... + c₁x + 1` is given as an integer, where bit `k` is the coefficient of `xᵏ`.
+/// Both register structures generate sequences satisfying the recurrence of `p`, and a primitive `p` gives a maximal-length sequence (m-sequence) of period `2ᵐ-1`.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// // x³+x+1
+/// let mut lfsr = Lfsr::new( 0b1011, 0b001, LfsrKind::Fibonacci );
+/// assert_eq!( lfsr.generate(7).to_string(), "[ 1, 0, 0, 1, 0, 1, 1 ]" );
+///
+/// // The sequence repeats after 2³-1 bits.
+/// assert_eq!( lfsr.generate(7).to_string(), "[ 1, 0, 0, 1, 0, 1, 1 ]" );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lfsr {
+    polynomial: u64,
+    degree: u32,
+    kind: LfsrKind,
+    /// The register, in the `degree` lowest bits.
+    state: u64,
+    /// The register set at creation, restored by [`Lfsr::reset`].
+    seed: u64,
+}
+
+impl Lfsr
+{
+    /// Create a shift register.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polynomial` - The feedback polynomial of degree 1 to 63, where bit `k` is the coefficient of `xᵏ`. The constant term must be one.
+    /// * `seed`       - The initial non-zero register, in the `degree` lowest bits.
+    /// * `kind`       - The register structure.
+    ///
+    pub fn new( polynomial: u64, seed: u64, kind: LfsrKind ) -> Lfsr
+    {
+        if polynomial & 1 == 0 || polynomial < 2
+        {
+            core::panic!("The polynomial must be of degree 1 or more with a constant term of one, got {:#x}.", polynomial);
+        }
+        let degree = 63 - polynomial.leading_zeros();
+        let mask = (1u64 << degree) - 1;
+        if seed & mask == 0
+        {
+            core::panic!("The seed must be non-zero in the {} lowest bits, got {:#x}.", degree, seed);
+        }
+
+        return Lfsr {
+            polynomial,
+            degree,
+            kind,
+            state: seed & mask,
+            seed: seed & mask,
+        };
+    }
+
+    /// Create a shift register for the m-sequence of degree `degree`, with a polynomial from [`primitive_polynomial`] and a seed of one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the polynomial, from 2 to 32.
+    /// * `kind`   - The register structure.
+    ///
+    pub fn new_maximal( degree: u32, kind: LfsrKind ) -> Lfsr
+    {
+        return Lfsr::new(primitive_polynomial(degree), 1, kind);
+    }
+
+    /// The feedback polynomial, where bit `k` is the coefficient of `xᵏ`.
+    pub fn polynomial( &self ) -> u64
+    {
+        return self.polynomial;
+    }
+
+    /// The degree of the polynomial, i.e. the length of the register.
+    pub fn degree( &self ) -> u32
+    {
+        return self.degree;
+    }
+
+    /// The current register, in the `degree` lowest bits.
+    pub fn state( &self ) -> u64
+    {
+        return self.state;
+    }
+
+    /// Restore the register to the seed.
+    pub fn reset( &mut self )
+    {
+        self.state = self.seed;
+    }
+
+    /// Clock the register once, and return the output bit.
+    pub fn next_bit( &mut self ) -> u8
+    {
+        match self.kind {
+            LfsrKind::Fibonacci => {
+                let output = self.state & 1;
+                let feedback = (self.state & self.polynomial).count_ones() as u64 & 1;
+                self.state = (self.state >> 1) | (feedback << (self.degree-1));
+                return output as u8;
+            },
+            LfsrKind::Galois    => {
+                let output = (self.state >> (self.degree-1)) & 1;
+                self.state <<= 1;
+                if output == 1
+                {
+                    self.state ^= self.polynomial;
+                }
+                return output as u8;
+            },
+        }
+    }
+
+    /// Clock the register `len` times, and return the output bits.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len` - The number of bits.
+    ///
+    pub fn generate( &mut self, len: usize ) -> Vec<u8>
+    {
+        let mut bits = Vec::<u8>::new_with_capacity(len);
+        for _ in 0..len
+        {
+            bits.push_back(self.next_bit());
+        }
+        return bits;
+    }
+}
+
+/// One period of the m-sequence of degree `degree`.
+fn m_sequence_bits( degree: u32 ) -> alloc::vec::Vec<u8>
+{
+    let mut lfsr = Lfsr::new_maximal(degree, LfsrKind::Fibonacci);
+    return lfsr.generate((1usize << degree) - 1).vec;
+}
+
+/// Decimate a periodic sequence by `factor`, `v[n] = u[factor·n mod N]`.
+fn decimate( sequence: &[u8], factor: usize, len: usize ) -> alloc::vec::Vec<u8>
+{
+    let mut decimated = alloc::vec::Vec::<u8>::with_capacity(len);
+    for idx in 0..len
+    {
+        decimated.push(sequence[(factor*idx)%sequence.len()]);
+    }
+    return decimated;
+}
+
+/// The bits of `u ⊕ Tᵏv`, with `v` shifted cyclically by `k`.
+fn add_shifted( u: &[u8], v: &[u8], shift: usize ) -> alloc::vec::Vec<u8>
+{
+    let mut sum = alloc::vec::Vec::<u8>::with_capacity(u.len());
+    for idx in 0..u.len()
+    {
+        sum.push(u[idx] ^ v[(idx+shift)%v.len()]);
+    }
+    return sum;
+}
+
+/// The bits of code `index` of the Gold family of degree `degree`.
+fn gold_bits( degree: u32, index: usize ) -> alloc::vec::Vec<u8>
+{
+    if degree < 3 || degree.is_multiple_of(4)
+    {
+        core::panic!("Gold codes require a degree of 3 or more, not divisible by 4, got {}.", degree);
+    }
+    let len = (1usize << degree) - 1;
+    if len+2 <= index
+    {
+        core::panic!("The Gold family of degree {} has {} codes, got index {}.", degree, len+2, index);
+    }
+
+    // The preferred pair of m-sequences is u and its decimation by 2ᵏ+1, with k = 1 for odd and k = 2 for even degrees.
+    let u = m_sequence_bits(degree);
+    let factor = if degree%2 == 1 { 3 } else { 5 };
+    let v = decimate(&u, factor, len);
+
+    match index {
+        0 => return u,
+        1 => return v,
+        _ => return add_shifted(&u, &v, index-2),
+    }
+}
+
+/// The bits of code `index` of the small Kasami family of degree `degree`.
+fn kasami_bits( degree: u32, index: usize ) -> alloc::vec::Vec<u8>
+{
+    if degree < 2 || degree%2 == 1
+    {
+        core::panic!("Kasami codes require an even degree of 2 or more, got {}.", degree);
+    }
+    let size = 1usize << (degree/2);
+    if size <= index
+    {
+        core::panic!("The small Kasami family of degree {} has {} codes, got index {}.", degree, size, index);
+    }
+
+    // The decimation by 2^(m/2)+1 is an m-sequence of period 2^(m/2)-1.
+    let u = m_sequence_bits(degree);
+    let w = decimate(&u, size+1, size-1);
+
+    match index {
+        0 => return u,
+        _ => return add_shifted(&u, &w, index-1),
+    }
+}
+
+/// Map bits to the bipolar values, `0 → 1` and `1 → -1`.
+fn bipolar( bit: u8 ) -> f64
+{
+    return if bit == 0 { 1f64 } else { -1f64 };
+}
+
+impl <T: MixedReal> PnSequence<T> for Vec<T>
+{
+    /// Map bits to bipolar values, `0 → 1` and `1 → -1`, such that the modulo-2 sum of bits is the product of values.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bits` - The bits, which must be zero or one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let mut lfsr = Lfsr::new( 0b111, 0b01, LfsrKind::Galois );
+    /// let chips = Vec::<f32>::from_bits( &lfsr.generate(3) );
+    /// assert_eq!( chips.to_string(), "[ 1, -1, -1 ]" );
+    /// ```
+    fn from_bits( bits: &Vec<u8> ) -> Self
+    {
+        let mut vec = Vec::<T>::new_with_capacity(bits.len());
+        for idx in 0..bits.len()
+        {
+            vec.push_back(T::mixed_from_num(bipolar(bits[idx])));
+        }
+        return vec;
+    }
+
+    /// Create one period of the bipolar m-sequence of degree `degree`, of length `2^degree-1`, from the polynomial of [`primitive_polynomial`].
+    ///
+    /// The periodic autocorrelation is `2^degree-1` at lag zero, and `-1` at all other lags.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the polynomial, from 2 to 32.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let m_sequence = Vec::<f64>::m_sequence(5);
+    /// assert_eq!( m_sequence.len(), 31 );
+    /// // One more -1 than 1.
+    /// assert_eq!( m_sequence.sum(), -1f64 );
+    /// ```
+    fn m_sequence( degree: u32 ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(m_sequence_bits(degree)));
+    }
+
+    /// Create one period of the bipolar Gold code `index` of degree `degree`, of length `2^degree-1`.
+    ///
+    /// The family holds `2^degree+1` codes: the preferred pair of m-sequences `u` and `v` at index 0 and 1, and `u ⊕ Tᵏv` at index `k+2`, with `v` shifted cyclically by `k`.
+    /// The periodic cross-correlation of any two codes, and the autocorrelation at non-zero lags, takes the three values `-1`, `-t` and `t-2`, with `t = 2^⌊(degree+2)/2⌋+1`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the m-sequences, 3 or more and not divisible by 4.
+    /// * `index`  - The code of the family, on `0..2^degree+1`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let first  = Vec::<f64>::gold(5, 2);
+    /// let second = Vec::<f64>::gold(5, 3);
+    ///
+    /// // The zero-lag cross-correlation is one of -1, -9 and 7.
+    /// let r = (&first*&second).sum();
+    /// assert!( r == -1f64 || r == -9f64 || r == 7f64 );
+    /// ```
+    fn gold( degree: u32, index: usize ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(gold_bits(degree, index)));
+    }
+
+    /// Create one period of the bipolar code `index` of the small Kasami family of degree `degree`, of length `2^degree-1`.
+    ///
+    /// The family holds `2^(degree/2)` codes: the m-sequence `u` at index 0, and `u ⊕ Tᵏw` at index `k+1`, with `w` the decimation of `u` by `2^(degree/2)+1` shifted cyclically by `k`.
+    /// The periodic cross-correlation of any two codes, and the autocorrelation at non-zero lags, takes the three values `-1`, `-s` and `s-2`, with `s = 2^(degree/2)+1`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the m-sequence, even and 2 or more.
+    /// * `index`  - The code of the family, on `0..2^(degree/2)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let first  = Vec::<f64>::kasami(6, 1);
+    /// let second = Vec::<f64>::kasami(6, 2);
+    ///
+    /// // The zero-lag cross-correlation is one of -1, -9 and 7.
+    /// let r = (&first*&second).sum();
+    /// assert!( r == -1f64 || r == -9f64 || r == 7f64 );
+    /// ```
+    fn kasami( degree: u32, index: usize ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(kasami_bits(degree, index)));
+    }
+}
+
+impl <T: MixedReal> PnSequence<T> for Vec<Cartesian<T>>
+{
+    /// Map bits to bipolar values with zero imaginary part, `0 → 1` and `1 → -1`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bits` - The bits, which must be zero or one.
+    fn from_bits( bits: &Vec<u8> ) -> Self
+    {
+        let mut vec = Vec::<Cartesian<T>>::new_with_capacity(bits.len());
+        for idx in 0..bits.len()
+        {
+            vec.push_back(Cartesian::new(T::mixed_from_num(bipolar(bits[idx])), T::mixed_from_num(0f64)));
+        }
+        return vec;
+    }
+
+    /// Create one period of the bipolar m-sequence of degree `degree` with zero imaginary part, see [`PnSequence::m_sequence`] for `Vec<T>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the polynomial, from 2 to 32.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // The flat spectrum of the m-sequence, with |X(k)|² = (N+1)/N² off DC.
+    /// let mut spectrum = Vec::<Cartesian<f64>>::m_sequence(4);
+    /// spectrum.fft();
+    /// for k in 1..15
+    /// {
+    ///     let power = spectrum[k].re*spectrum[k].re + spectrum[k].im*spectrum[k].im;
+    ///     assert!( (power-16f64/225f64).abs() < 1e-12 );
+    /// }
+    /// ```
+    fn m_sequence( degree: u32 ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(m_sequence_bits(degree)));
+    }
+
+    /// Create one period of the bipolar Gold code `index` of degree `degree` with zero imaginary part, see [`PnSequence::gold`] for `Vec<T>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the m-sequences, 3 or more and not divisible by 4.
+    /// * `index`  - The code of the family, on `0..2^degree+1`.
+    fn gold( degree: u32, index: usize ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(gold_bits(degree, index)));
+    }
+
+    /// Create one period of the bipolar small Kasami code `index` of degree `degree` with zero imaginary part, see [`PnSequence::kasami`] for `Vec<T>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `degree` - The degree of the m-sequence, even and 2 or more.
+    /// * `index`  - The code of the family, on `0..2^(degree/2)`.
+    fn kasami( degree: u32, index: usize ) -> Self
+    {
+        return Self::from_bits(&Vec::new_from_vec(kasami_bits(degree, index)));
+    }
+}
+
+/// The greatest common divisor of `a` and `b`.
+fn gcd( a: usize, b: usize ) -> usize
+{
+    let (mut a, mut b) = (a, b);
+    while b != 0
+    {
+        (a, b) = (b, a%b);
+    }
+    return a;
+}
+
+impl <T: MixedReal> ZadoffChu<T> for Vec<Cartesian<T>>
+{
+    /// Create the Zadoff–Chu sequence of length `len` and root `root`, `x(n) = e^(-jπu·n(n+c)/N)` with `c = N mod 2`.
+    ///
+    /// The sequence is a Constant Amplitude Zero AutoCorrelation (CAZAC) sequence.
+    /// The periodic autocorrelation is zero at all non-zero lags, and its DFT is of constant magnitude.
+    /// For a prime length, the periodic cross-correlation of sequences with different roots is of constant magnitude `√N`.
+    ///
+    /// The phase is computed modulo `2N` in integers, such that long sequences do not lose precision.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`  - The length of the sequence (N).
+    /// * `root` - The root index (u), on `1..len` and relatively prime to `len`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let zc = Vec::<Cartesian<f64>>::zadoff_chu( 63, 25 );
+    ///
+    /// // The FFT is of constant magnitude, and so is the sequence.
+    /// let mut spectrum = zc.clone();
+    /// spectrum.fft();
+    /// for k in 0..63
+    /// {
+    ///     let power = spectrum[k].re*spectrum[k].re + spectrum[k].im*spectrum[k].im;
+    ///     assert!( (power-1f64/63f64).abs() < 1e-12 );
+    /// }
+    /// ```
+    fn zadoff_chu( len: usize, root: usize ) -> Self
+    {
+        if root == 0 || len <= root || gcd(root, len) != 1
+        {
+            core::panic!("The root must be on 1..{} and relatively prime to the length, got {}.", len, root);
+        }
+
+        let modulus = 2*(len as u128);
+        let odd = (len%2) as u128;
+        let mut vec = Vec::<Cartesian<T>>::new_with_capacity(len);
+        for idx in 0..len
+        {
+            let n = idx as u128;
+            let steps = ((root as u128)*n%modulus)*(n+odd)%modulus;
+            let (sin, cos) = (-core::f64::consts::PI*(steps as f64)/(len as f64)).mixed_sincos();
+            vec.push_back(Cartesian::new(T::mixed_from_num(cos), T::mixed_from_num(sin)));
+        }
+        return vec;
+    }
+}
@@ -0,0 +1,386 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+pub mod stft;
+pub mod multitaper;
+
+/// The scaling of a power spectral density estimate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PsdScaling {
+    /// Power spectral density, in V²/Hz for a signal in V.
+    /// The sum of the bins times the bin width is the power of the signal.
+    Density,
+    /// Power spectrum, in V² for a signal in V.
+    /// The bin of a sinusoid reads its power, for a sinusoid centered in the bin.
+    Spectrum,
+}
+
+/// The frequency range of a power spectral density estimate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PsdSides {
+    /// The `N/2+1` non-negative frequency bins, where the power of the negative frequencies is folded onto the positive ones.
+    /// Only for real signals.
+    OneSided,
+    /// All `N` bins, in FFT order. The frequencies of the second half are negative.
+    TwoSided,
+}
+
+/// The first sample of each segment of a Welch estimate.
+///
+/// ## Arguments
+///
+/// * `signal_len`  - The length of the signal.
+/// * `segment_len` - The length of each segment.
+/// * `overlap`     - The number of samples shared by consecutive segments.
+///
+#[allow(clippy::needless_return)]
+fn segment_starts( signal_len: usize, segment_len: usize, overlap: usize ) -> alloc::vec::Vec<usize>
+{
+    if segment_len == 0
+    {
+        core::panic!("The segment length must be larger than zero.");
+    }
+    if segment_len <= overlap
+    {
+        core::panic!("The overlap must be shorter than the segment. Segment length = {}, overlap = {}", segment_len, overlap);
+    }
+    if signal_len < segment_len
+    {
+        core::panic!("The signal is shorter than a segment. Signal length = {}, segment length = {}", signal_len, segment_len);
+    }
+
+    let step = segment_len-overlap;
+    let mut starts = alloc::vec::Vec::<usize>::new();
+    let mut start = 0;
+    while start+segment_len <= signal_len
+    {
+        starts.push(start);
+        start += step;
+    }
+    return starts;
+}
+
+/// The factor scaling the squared magnitude of the `1/N` scaled FFT of a windowed segment into a PSD bin.
+///
+/// ## Arguments
+///
+/// * `window`      - The window function.
+/// * `sample_rate` - The sample rate of the signal.
+/// * `scaling`     - The scaling of the estimate.
+///
+#[allow(clippy::needless_return)]
+fn psd_scale_factor<T: MixedReal>( window: &Vec<T>, sample_rate: T, scaling: PsdScaling ) -> f64
+{
+    let n = window.len() as f64;
+    let mut sum = 0f64;
+    let mut sum_sq = 0f64;
+    for idx in 0..window.len()
+    {
+        let w: f64 = window[idx].mixed_to_num();
+        sum    += w;
+        sum_sq += w*w;
+    }
+
+    match scaling {
+        PsdScaling::Density  => {
+            let sample_rate: f64 = sample_rate.mixed_to_num();
+            return n*n/(sample_rate*sum_sq);
+        }
+        PsdScaling::Spectrum => return (n/sum)*(n/sum),
+    }
+}
+
+/// The frequency of each bin of a PSD estimate with segments of length `segment_len`.
+///
+/// ## Arguments
+///
+/// * `segment_len` - The length of each segment, `N`.
+/// * `sample_rate` - The sample rate of the signal.
+/// * `sides`       - The frequency range of the estimate.
+///
+/// ## Example
+///
+/// ```
+/// use ndsp::*;
+///
+/// let frequencies = psd_frequencies( 4, 8f32, PsdSides::TwoSided );
+/// assert_eq!( frequencies.to_string(), "[ 0, 2, -4, -2 ]" );
+///
+/// let frequencies = psd_frequencies( 4, 8f32, PsdSides::OneSided );
+/// assert_eq!( frequencies.to_string(), "[ 0, 2, 4 ]" );
+/// ```
+#[allow(clippy::needless_return)]
+pub fn psd_frequencies<T: MixedReal>( segment_len: usize, sample_rate: T, sides: PsdSides ) -> Vec<T>
+{
+    let sample_rate: f64 = sample_rate.mixed_to_num();
+    let bins = match sides {
+        PsdSides::OneSided => segment_len/2+1,
+        PsdSides::TwoSided => segment_len,
+    };
+
+    let mut frequencies = Vec::<T>::new_with_capacity(bins);
+    for k in 0..bins
+    {
+        // The second half of a two-sided estimate holds the negative frequencies.
+        let bin = if sides == PsdSides::TwoSided && segment_len <= 2*k { k as f64 - segment_len as f64 } else { k as f64 };
+        frequencies.push_back(T::mixed_from_num(bin*sample_rate/(segment_len as f64)));
+    }
+    return frequencies;
+}
+
+/// Add the scaled squared magnitude of the spectrum of a windowed segment to the running sum of the estimate.
+///
+/// ## Arguments
+///
+/// * `psd`      - The running sum, of the same length as the spectrum.
+/// * `spectrum` - The spectrum of the windowed segment.
+/// * `scale`    - The scale factor of the segment.
+///
+#[allow(clippy::assign_op_pattern)]
+fn accumulate_power<T>( psd: &mut Vec<T>, spectrum: &Vec<Cartesian<T>>, scale: T )
+    where T: MixedReal + MixedOps
+{
+    for k in 0..psd.len()
+    {
+        let x = spectrum[k];
+        psd[k] = psd[k] + (x.re*x.re + x.im*x.im)*scale;
+    }
+}
+
+/// Arrange the `N/2+1` non-negative frequency bins of a real signal as a one-sided or two-sided estimate.
+///
+/// ## Arguments
+///
+/// * `half`        - The non-negative frequency bins.
+/// * `segment_len` - The length of each segment, `N`.
+/// * `sides`       - The frequency range of the estimate.
+/// * `fold`        - Fold the power of the negative frequencies onto the positive ones in one-sided estimates.
+///
+#[allow(clippy::needless_return)]
+fn arrange_sides<T: MixedReal + MixedOps>( half: &Vec<T>, segment_len: usize, sides: PsdSides, fold: bool ) -> Vec<T>
+{
+    let mut arranged = Vec::<T>::new_with_capacity(segment_len);
+    match sides {
+        PsdSides::OneSided => {
+            let two = T::mixed_from_num(2);
+            for k in 0..half.len()
+            {
+                // DC, and the Nyquist bin of even lengths, have no negative counterpart.
+                if !fold || k == 0 || 2*k == segment_len
+                {
+                    arranged.push_back(half[k]);
+                }
+                else
+                {
+                    arranged.push_back(half[k]*two);
+                }
+            }
+        }
+        PsdSides::TwoSided => {
+            for k in 0..segment_len
+            {
+                if k < half.len()
+                {
+                    arranged.push_back(half[k]);
+                }
+                else
+                {
+                    arranged.push_back(half[segment_len-k]);
+                }
+            }
+        }
+    }
+    return arranged;
+}
+
+#[allow(clippy::needless_return)]
+impl <T> Welch<T> for Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the Power Spectral Density (PSD) of a real signal with Welch's method.
+    ///
+    /// The signal is split into overlapping segments of the length of `window`.
+    /// The PSD is the average of the periodograms of the windowed segments.
+    /// Samples after the last whole segment are not used.
+    ///
+    /// Returns the frequency axis and the PSD, see [`psd_frequencies`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `window`      - The window function, e.g. `Vec::hamming(256)`. The segment length is the length of the window.
+    /// * `overlap`     - The number of samples shared by consecutive segments.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `scaling`     - Density (V²/Hz) or spectrum (V²) scaling.
+    /// * `sides`       - One-sided or two-sided estimate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A tone of amplitude 2 at 125 Hz, sampled at 1 kHz.
+    /// let mut signal = Vec::lin_range(0f64, 1023f64*f64::mixed_tau()/8f64, 1024);
+    /// signal.cos();
+    /// signal *= 2f64;
+    ///
+    /// let window = Vec::<f64>::hamming(64);
+    /// let (frequencies, psd) = signal.welch( &window, 32, 1e3, PsdScaling::Spectrum, PsdSides::OneSided );
+    ///
+    /// // The power of the tone is A²/2.
+    /// assert_eq!( frequencies[8], 125f64 );
+    /// assert!( (psd[8]-2f64).abs() < 1e-2 );
+    /// ```
+    fn welch( &self, window: &Vec<T>, overlap: usize, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>)
+    {
+        let segment_len = window.len();
+        let starts = segment_starts(self.len(), segment_len, overlap);
+        let factor = psd_scale_factor(window, sample_rate, scaling);
+
+        // The periodograms are averaged as they are computed, such that only one segment is held at a time.
+        let scale = T::mixed_from_num(factor/(starts.len() as f64));
+        let plan = RfftPlan::<T>::new(segment_len);
+        let mut half_psd = Vec::<T>::zeros(segment_len/2+1);
+        let mut buffer = Vec::<T>::new_with_capacity(segment_len);
+        for start in starts
+        {
+            buffer.vec.clear();
+            for idx in 0..segment_len
+            {
+                buffer.push_back(self[start+idx]*window[idx]);
+            }
+            accumulate_power(&mut half_psd, &plan.process(&buffer.vec), scale);
+        }
+
+        let psd = arrange_sides(&half_psd, segment_len, sides, true);
+        return (psd_frequencies(segment_len, sample_rate, sides), psd);
+    }
+
+    /// Estimate the Power Spectral Density (PSD) of a real signal with a windowed periodogram.
+    ///
+    /// Equal to [`Welch::welch`] with a single segment, i.e. `window` must be of the same length as the signal.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window`      - The window function, of the same length as the signal.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `scaling`     - Density (V²/Hz) or spectrum (V²) scaling.
+    /// * `sides`       - One-sided or two-sided estimate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let signal = Vec::<f64>::ones(16);
+    /// let window = Vec::<f64>::ones(16);
+    /// let (_frequencies, psd) = signal.periodogram( &window, 1f64, PsdScaling::Density, PsdSides::OneSided );
+    ///
+    /// // The density times the bin width of 1/16 Hz is the power of the signal.
+    /// assert_eq!( psd[0], 16f64 );
+    /// ```
+    fn periodogram( &self, window: &Vec<T>, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>)
+    {
+        if window.len() != self.len()
+        {
+            core::panic!("The window must be of the same length as the signal. Signal length = {}, window length = {}", self.len(), window.len());
+        }
+        return self.welch(window, 0, sample_rate, scaling, sides);
+    }
+}
+
+#[allow(clippy::needless_return)]
+impl <T> Welch<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the Power Spectral Density (PSD) of a complex signal with Welch's method.
+    ///
+    /// The signal is split into overlapping segments of the length of `window`.
+    /// The PSD is the average of the periodograms of the windowed segments.
+    /// Samples after the last whole segment are not used.
+    ///
+    /// Returns the frequency axis and the PSD, see [`psd_frequencies`].
+    /// The spectrum of a complex signal is not symmetric, hence only two-sided estimates are supported.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window`      - The window function, e.g. `Vec::hamming(256)`. The segment length is the length of the window.
+    /// * `overlap`     - The number of samples shared by consecutive segments.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `scaling`     - Density (V²/Hz) or spectrum (V²) scaling.
+    /// * `sides`       - Must be [`PsdSides::TwoSided`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A complex tone of amplitude 1 at -250 Hz, sampled at 1 kHz.
+    /// let signal = Vec::osc(-f64::mixed_tau()/4f64, 0f64, 1024);
+    ///
+    /// let window = Vec::<f64>::blackman(64);
+    /// let (frequencies, psd) = signal.welch( &window, 32, 1e3, PsdScaling::Spectrum, PsdSides::TwoSided );
+    ///
+    /// assert_eq!( frequencies[48], -250f64 );
+    /// assert!( (psd[48]-1f64).abs() < 1e-6 );
+    /// ```
+    fn welch( &self, window: &Vec<T>, overlap: usize, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>)
+    {
+        if sides == PsdSides::OneSided
+        {
+            core::panic!("One-sided estimates require a real signal.");
+        }
+
+        let segment_len = window.len();
+        let starts = segment_starts(self.len(), segment_len, overlap);
+        let factor = psd_scale_factor(window, sample_rate, scaling);
+        let plan = FftPlan::<T>::new(segment_len, FftDirection::Forward);
+
+        // The periodograms are averaged as they are computed, such that only one segment is held at a time.
+        let scale = T::mixed_from_num(factor/(starts.len() as f64));
+        let mut psd = Vec::<T>::zeros(segment_len);
+        let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(segment_len);
+        for start in starts
+        {
+            buffer.vec.clear();
+            for idx in 0..segment_len
+            {
+                let x = self[start+idx];
+                buffer.push_back(Cartesian::new(x.re*window[idx], x.im*window[idx]));
+            }
+            plan.process_vec(&mut buffer);
+            accumulate_power(&mut psd, &buffer, scale);
+        }
+
+        return (psd_frequencies(segment_len, sample_rate, sides), psd);
+    }
+
+    /// Estimate the Power Spectral Density (PSD) of a complex signal with a windowed periodogram.
+    ///
+    /// Equal to [`Welch::welch`] with a single segment, i.e. `window` must be of the same length as the signal.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window`      - The window function, of the same length as the signal.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `scaling`     - Density (V²/Hz) or spectrum (V²) scaling.
+    /// * `sides`       - Must be [`PsdSides::TwoSided`].
+    ///
+    fn periodogram( &self, window: &Vec<T>, sample_rate: T, scaling: PsdScaling, sides: PsdSides ) -> (Vec<T>, Vec<T>)
+    {
+        if window.len() != self.len()
+        {
+            core::panic!("The window must be of the same length as the signal. Signal length = {}, window length = {}", self.len(), window.len());
+        }
+        return self.welch(window, 0, sample_rate, scaling, sides);
+    }
+}
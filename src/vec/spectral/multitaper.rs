@@ -0,0 +1,295 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+use crate::vec::window::{dpss, dpss_concentration};
+
+use super::{arrange_sides, psd_frequencies};
+
+/// The DPSS tapers of a multitaper estimate, and their concentrations.
+///
+/// ## Arguments
+///
+/// * `len`        - The length of the signal.
+/// * `nw`         - The time half bandwidth product.
+/// * `num_tapers` - The number of tapers.
+///
+fn tapers<T: MixedReal>( len: usize, nw: T, num_tapers: usize ) -> (alloc::vec::Vec<alloc::vec::Vec<f64>>, alloc::vec::Vec<f64>)
+{
+    let nw: f64 = nw.mixed_to_num();
+    if num_tapers == 0 || len < num_tapers
+    {
+        core::panic!("The number of tapers must be between one and the length of the signal. Signal length = {}, number of tapers = {}", len, num_tapers);
+    }
+    if nw <= 0f64 || (len as f64)/2f64 <= nw
+    {
+        core::panic!("The time half bandwidth product must lie between 0 and half the length of the signal.");
+    }
+
+    let half_bandwidth = nw/(len as f64);
+    let tapers = dpss(len, half_bandwidth, num_tapers);
+    let mut concentrations = alloc::vec::Vec::<f64>::with_capacity(num_tapers);
+    for taper in tapers.iter()
+    {
+        concentrations.push(dpss_concentration(taper, half_bandwidth));
+    }
+    return (tapers, concentrations);
+}
+
+/// Combine the eigenspectra of each taper with Thomson's adaptive weights, `dₖ = √λₖS/(λₖS+(1-λₖ)σ²)`,
+/// where the broadband bias of each eigenspectrum is estimated from the variance `σ²` of the signal.
+/// Returns the PSD and the degrees of freedom `2(Σdₖ²)²/Σdₖ⁴` of each bin.
+///
+/// ## Arguments
+///
+/// * `eigenspectra`   - The density scaled power of each bin, for each taper.
+/// * `concentrations` - The concentration `λₖ` of each taper.
+/// * `variance`       - The variance of the signal, in the unit of the eigenspectra.
+///
+fn adaptive_weighting<T: MixedReal>( eigenspectra: &[alloc::vec::Vec<f64>], concentrations: &[f64], variance: f64 ) -> (Vec<T>, Vec<T>)
+{
+    let num_tapers = eigenspectra.len();
+    let bins = eigenspectra[0].len();
+
+    let mut psd = Vec::<T>::new_with_capacity(bins);
+    let mut degrees_of_freedom = Vec::<T>::new_with_capacity(bins);
+    let mut weights = alloc::vec![1f64; num_tapers];
+    for k in 0..bins
+    {
+        // Start from the average of the two best concentrated eigenspectra.
+        let mut estimate = if num_tapers == 1 {
+            eigenspectra[0][k]
+        } else {
+            (eigenspectra[0][k]+eigenspectra[1][k])/2f64
+        };
+
+        for _ in 0..100
+        {
+            let mut numerator = 0f64;
+            let mut denominator = 0f64;
+            for j in 0..num_tapers
+            {
+                let lambda = concentrations[j];
+                let bias = (1f64-lambda)*variance;
+                weights[j] = if estimate+bias == 0f64 {
+                    1f64
+                } else {
+                    lambda.mixed_sqrt()*estimate/(lambda*estimate + bias)
+                };
+                numerator = numerator + weights[j]*weights[j]*eigenspectra[j][k];
+                denominator = denominator + weights[j]*weights[j];
+            }
+            let updated = if denominator == 0f64 { 0f64 } else { numerator/denominator };
+            let converged = (updated-estimate).mixed_abs() <= 1e-10*updated.mixed_abs();
+            estimate = updated;
+            if converged
+            {
+                break;
+            }
+        }
+
+        let mut sum_squares = 0f64;
+        let mut sum_fourth_powers = 0f64;
+        for weight in weights.iter()
+        {
+            sum_squares = sum_squares + weight*weight;
+            sum_fourth_powers = sum_fourth_powers + weight*weight*weight*weight;
+        }
+        let nu = if sum_fourth_powers == 0f64 { 2f64*(num_tapers as f64) } else { 2f64*sum_squares*sum_squares/sum_fourth_powers };
+
+        psd.push_back(T::mixed_from_num(estimate));
+        degrees_of_freedom.push_back(T::mixed_from_num(nu));
+    }
+    return (psd, degrees_of_freedom);
+}
+
+impl <T> Multitaper<T> for Vec<T>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the Power Spectral Density (PSD) of a real signal with Thomson's multitaper method.
+    ///
+    /// The signal is multiplied by `num_tapers` orthonormal DPSS tapers, see [`Vec::dpss`], and the periodograms of the tapered signals (eigenspectra)
+    /// are combined with adaptive weights, which suppress the broadband leakage of the less concentrated tapers.
+    /// The whole signal is used for each eigenspectrum, such that the resolution is `2NW` bins, and the variance is reduced without segmenting the signal.
+    /// Use `num_tapers` of at most `2NW-1`.
+    ///
+    /// Returns the frequency axis, see [`psd_frequencies`], the PSD with density scaling (V²/Hz),
+    /// and the degrees of freedom of each bin, which is up to twice the number of tapers.
+    ///
+    /// ## Arguments
+    ///
+    /// * `nw`          - The time half bandwidth product `NW`, e.g. 4.
+    /// * `num_tapers`  - The number of tapers `K`.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `sides`       - One-sided or two-sided estimate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A tone of amplitude 2 at 125 Hz, sampled at 1 kHz.
+    /// let mut signal = Vec::lin_range(0f64, 255f64*f64::mixed_tau()/8f64, 256);
+    /// signal.cos();
+    /// signal *= 2f64;
+    ///
+    /// let (frequencies, psd, degrees_of_freedom) = signal.multitaper( 4f64, 7, 1e3, PsdSides::OneSided );
+    ///
+    /// // The density times the bin width sums to the power of the tone, A²/2.
+    /// assert_eq!( frequencies[32], 125f64 );
+    /// assert!( (psd.sum()*1e3/256f64/2f64-1f64).abs() < 1e-2 );
+    /// assert!( 13f64 < degrees_of_freedom[32] );
+    /// ```
+    fn multitaper( &self, nw: T, num_tapers: usize, sample_rate: T, sides: PsdSides ) -> (Vec<T>, Vec<T>, Vec<T>)
+    {
+        let len = self.len();
+        let (tapers, concentrations) = tapers(len, nw, num_tapers);
+        let sample_rate_f64: f64 = sample_rate.mixed_to_num();
+
+        let mut mean = 0f64;
+        for idx in 0..len
+        {
+            let x: f64 = self[idx].mixed_to_num();
+            mean = mean + x;
+        }
+        mean = mean/(len as f64);
+        let mut variance = 0f64;
+        for idx in 0..len
+        {
+            let x: f64 = self[idx].mixed_to_num();
+            variance = variance + (x-mean)*(x-mean);
+        }
+        variance = variance/(len as f64)/sample_rate_f64;
+
+        // The tapers have unit energy, and the FFT is scaled by 1/N.
+        let factor = (len as f64)*(len as f64)/sample_rate_f64;
+
+        let plan = RfftPlan::<T>::new(len);
+        let mut eigenspectra = alloc::vec::Vec::<alloc::vec::Vec<f64>>::with_capacity(num_tapers);
+        let mut buffer = Vec::<T>::new_with_capacity(len);
+        for taper in tapers.iter()
+        {
+            buffer.vec.clear();
+            for idx in 0..len
+            {
+                buffer.push_back(self[idx]*T::mixed_from_num(taper[idx]));
+            }
+            let spectrum = plan.process(&buffer.vec);
+
+            let mut power = alloc::vec::Vec::<f64>::with_capacity(spectrum.len());
+            for k in 0..spectrum.len()
+            {
+                let re: f64 = spectrum[k].re.mixed_to_num();
+                let im: f64 = spectrum[k].im.mixed_to_num();
+                power.push((re*re + im*im)*factor);
+            }
+            eigenspectra.push(power);
+        }
+
+        let (half_psd, half_degrees_of_freedom) = adaptive_weighting::<T>(&eigenspectra, &concentrations, variance);
+        let psd = arrange_sides(&half_psd, len, sides, true);
+        let degrees_of_freedom = arrange_sides(&half_degrees_of_freedom, len, sides, false);
+        return (psd_frequencies(len, sample_rate, sides), psd, degrees_of_freedom);
+    }
+}
+
+impl <T> Multitaper<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Estimate the Power Spectral Density (PSD) of a complex signal with Thomson's multitaper method.
+    ///
+    /// See the implementation for real signals.
+    /// The spectrum of a complex signal is not symmetric, hence only two-sided estimates are supported.
+    ///
+    /// ## Arguments
+    ///
+    /// * `nw`          - The time half bandwidth product `NW`, e.g. 4.
+    /// * `num_tapers`  - The number of tapers `K`.
+    /// * `sample_rate` - The sample rate of the signal.
+    /// * `sides`       - Must be [`PsdSides::TwoSided`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A complex tone of amplitude 1 at -250 Hz, sampled at 1 kHz.
+    /// let signal = Vec::osc(-f64::mixed_tau()/4f64, 0f64, 256);
+    ///
+    /// let (frequencies, psd, _degrees_of_freedom) = signal.multitaper( 3f64, 5, 1e3, PsdSides::TwoSided );
+    ///
+    /// assert_eq!( frequencies[192], -250f64 );
+    /// assert!( (psd.sum()*1e3/256f64-1f64).abs() < 1e-2 );
+    /// // The power is spread over the 2NW bins around the tone.
+    /// assert!( psd[192+6] < 1e-6*psd[192] );
+    /// ```
+    fn multitaper( &self, nw: T, num_tapers: usize, sample_rate: T, sides: PsdSides ) -> (Vec<T>, Vec<T>, Vec<T>)
+    {
+        if sides == PsdSides::OneSided
+        {
+            core::panic!("One-sided estimates require a real signal.");
+        }
+
+        let len = self.len();
+        let (tapers, concentrations) = tapers(len, nw, num_tapers);
+        let sample_rate_f64: f64 = sample_rate.mixed_to_num();
+
+        let mut mean_re = 0f64;
+        let mut mean_im = 0f64;
+        for idx in 0..len
+        {
+            let re: f64 = self[idx].re.mixed_to_num();
+            let im: f64 = self[idx].im.mixed_to_num();
+            mean_re = mean_re + re;
+            mean_im = mean_im + im;
+        }
+        mean_re = mean_re/(len as f64);
+        mean_im = mean_im/(len as f64);
+        let mut variance = 0f64;
+        for idx in 0..len
+        {
+            let re: f64 = self[idx].re.mixed_to_num();
+            let im: f64 = self[idx].im.mixed_to_num();
+            variance = variance + (re-mean_re)*(re-mean_re) + (im-mean_im)*(im-mean_im);
+        }
+        variance = variance/(len as f64)/sample_rate_f64;
+
+        // The tapers have unit energy, and the FFT is scaled by 1/N.
+        let factor = (len as f64)*(len as f64)/sample_rate_f64;
+        let plan = FftPlan::<T>::new(len, FftDirection::Forward);
+
+        let mut eigenspectra = alloc::vec::Vec::<alloc::vec::Vec<f64>>::with_capacity(num_tapers);
+        for taper in tapers.iter()
+        {
+            let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(len);
+            for idx in 0..len
+            {
+                let h = T::mixed_from_num(taper[idx]);
+                buffer.push_back(Cartesian::new(self[idx].re*h, self[idx].im*h));
+            }
+            plan.process_vec(&mut buffer);
+
+            let mut power = alloc::vec::Vec::<f64>::with_capacity(len);
+            for k in 0..len
+            {
+                let re: f64 = buffer[k].re.mixed_to_num();
+                let im: f64 = buffer[k].im.mixed_to_num();
+                power.push((re*re + im*im)*factor);
+            }
+            eigenspectra.push(power);
+        }
+
+        let (psd, degrees_of_freedom) = adaptive_weighting::<T>(&eigenspectra, &concentrations, variance);
+        return (psd_frequencies(len, sample_rate, sides), psd, degrees_of_freedom);
+    }
+}
@@ -0,0 +1,186 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+impl <T> Stft<T> for Vec<Cartesian<T>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Calculate the Short-Time Fourier Transform (STFT) of self.
+    ///
+    /// The signal is split into frames of the length of `window`, starting every `hop` samples.
+    /// Each frame is windowed, zero padded to `fft_size` and transformed. Samples after the last whole frame are not used.
+    /// The transforms are scaled as [`traits::Fft`].
+    ///
+    /// Returns a frames×bins matrix, with one vector of `fft_size` bins in FFT order per frame.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window`   - The window function, e.g. `Vec::hamming(256)`. The frame length is the length of the window.
+    /// * `hop`      - The number of samples between the start of consecutive frames.
+    /// * `fft_size` - The size of the transform of each frame. Must be at least the frame length.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let signal = Vec::osc(f32::mixed_tau()/8f32, 0f32, 1024);
+    /// let window = Vec::<f32>::hamming(64);
+    ///
+    /// let spectrogram = signal.stft( &window, 16, 128 );
+    ///
+    /// // (1024-64)/16+1 frames of 128 bins.
+    /// assert_eq!( spectrogram.len(), 61 );
+    /// assert_eq!( spectrogram[0].len(), 128 );
+    /// ```
+    fn stft( &self, window: &Vec<T>, hop: usize, fft_size: usize ) -> Vec<Vec<Cartesian<T>>>
+    {
+        let frame_len = window.len();
+        if frame_len == 0 || hop == 0
+        {
+            core::panic!("The frame length and hop must be larger than zero. Frame length = {}, hop = {}", frame_len, hop);
+        }
+        if fft_size < frame_len
+        {
+            core::panic!("The FFT size must be at least the frame length. FFT size = {}, frame length = {}", fft_size, frame_len);
+        }
+        if self.len() < frame_len
+        {
+            core::panic!("The signal is shorter than a frame. Signal length = {}, frame length = {}", self.len(), frame_len);
+        }
+
+        let num_frames = (self.len()-frame_len)/hop+1;
+        let plan = FftPlan::<T>::new(fft_size, FftDirection::Forward);
+
+        let mut frames = Vec::<Vec<Cartesian<T>>>::new_with_capacity(num_frames);
+        for frame in 0..num_frames
+        {
+            let start = frame*hop;
+            let mut buffer = Vec::<Cartesian<T>>::new_with_capacity(fft_size);
+            for idx in 0..frame_len
+            {
+                let x = self[start+idx];
+                buffer.push_back(Cartesian::new(x.re*window[idx], x.im*window[idx]));
+            }
+            for _idx in frame_len..fft_size
+            {
+                buffer.push_back(Cartesian::new(T::mixed_zero(), T::mixed_zero()));
+            }
+            plan.process_vec(&mut buffer);
+            frames.push_back(buffer);
+        }
+        return frames;
+    }
+}
+
+impl <T> Istft<T> for Vec<Vec<Cartesian<T>>>
+    where T: MixedReal + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedWrapPhase + MixedOps + MixedPi + MixedZero + MixedPowi
+{
+    /// Calculate the inverse Short-Time Fourier Transform (STFT) of the frames×bins matrix self, see [`Stft`].
+    ///
+    /// The signal is reconstructed with weighted overlap-add (WOLA).
+    /// Each frame is inverse transformed, windowed with `window` once more, and added to the output.
+    /// Each output sample is normalized by the sum of the squared windows overlapping it.
+    /// Samples where the sum is zero are set to zero.
+    ///
+    /// Returns `(frames-1)*hop + frame_len` samples.
+    /// The reconstruction is perfect, for the window and hop used in the forward transform, where the sum is non-zero.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window` - The window function used in the forward transform.
+    /// * `hop`    - The number of samples between the start of consecutive frames.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let signal = Vec::osc(0.1f64, 0f64, 256);
+    /// let window = Vec::<f64>::hamming(32);
+    ///
+    /// let restored = signal.stft( &window, 8, 64 ).istft( &window, 8 );
+    ///
+    /// assert_eq!( restored.len(), 256 );
+    /// for idx in 0..256
+    /// {
+    ///     assert!( (restored[idx].re-signal[idx].re).abs() < 1e-12 );
+    ///     assert!( (restored[idx].im-signal[idx].im).abs() < 1e-12 );
+    /// }
+    /// ```
+    fn istft( &self, window: &Vec<T>, hop: usize ) -> Vec<Cartesian<T>>
+    {
+        let frame_len = window.len();
+        if self.len() == 0
+        {
+            core::panic!("The STFT must hold at least one frame.");
+        }
+        if frame_len == 0 || hop == 0
+        {
+            core::panic!("The frame length and hop must be larger than zero. Frame length = {}, hop = {}", frame_len, hop);
+        }
+        let fft_size = self[0].len();
+        if fft_size < frame_len
+        {
+            core::panic!("The FFT size must be at least the frame length. FFT size = {}, frame length = {}", fft_size, frame_len);
+        }
+
+        let len = (self.len()-1)*hop+frame_len;
+        let plan = FftPlan::<T>::new(fft_size, FftDirection::Backward);
+
+        let mut signal = Vec::<Cartesian<T>>::new_with_capacity(len);
+        let mut norm = alloc::vec::Vec::<f64>::with_capacity(len);
+        for _idx in 0..len
+        {
+            signal.push_back(Cartesian::new(T::mixed_zero(), T::mixed_zero()));
+            norm.push(0f64);
+        }
+
+        for frame in 0..self.len()
+        {
+            if self[frame].len() != fft_size
+            {
+                core::panic!("All frames must be of the same size. Frame {} is of size {}, expected {}", frame, self[frame].len(), fft_size);
+            }
+
+            let start = frame*hop;
+            let mut buffer = self[frame].clone();
+            plan.process_vec(&mut buffer);
+
+            for idx in 0..frame_len
+            {
+                let y = buffer[idx];
+                signal[start+idx] = signal[start+idx] + Cartesian::new(y.re*window[idx], y.im*window[idx]);
+
+                let w: f64 = window[idx].mixed_to_num();
+                norm[start+idx] += w*w;
+            }
+        }
+
+        for idx in 0..len
+        {
+            // Avoid amplifying samples where the windows are zero, e.g. the edges of a Hann window.
+            if 1e-12 < norm[idx]
+            {
+                let scale = T::mixed_from_num(1f64/norm[idx]);
+                signal[idx] = Cartesian::new(signal[idx].re*scale, signal[idx].im*scale);
+            }
+            else
+            {
+                signal[idx] = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+            }
+        }
+        return signal;
+    }
+}
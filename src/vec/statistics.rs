@@ -0,0 +1,620 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// Panic if the vector has no items, as the statistics are undefined.
+fn assert_not_empty( len: usize )
+{
+    if len == 0
+    {
+        core::panic!("The vector must have at least one item.");
+    }
+}
+
+/// Convert a real vector to `f64`.
+fn to_f64<T: MixedReal>( vec: &Vec<T> ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = alloc::vec::Vec::<f64>::with_capacity(vec.len());
+    for idx in 0..vec.len()
+    {
+        converted.push(vec[idx].mixed_to_num());
+    }
+    return converted;
+}
+
+/// The squared magnitudes of a complex vector in `f64`.
+fn power_to_f64<T: MixedReal>( vec: &Vec<Cartesian<T>> ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = alloc::vec::Vec::<f64>::with_capacity(vec.len());
+    for idx in 0..vec.len()
+    {
+        let re: f64 = vec[idx].re.mixed_to_num();
+        let im: f64 = vec[idx].im.mixed_to_num();
+        converted.push(re*re + im*im);
+    }
+    return converted;
+}
+
+/// The magnitudes of a complex vector in `f64`.
+fn magnitude_to_f64<T: MixedReal>( vec: &Vec<Cartesian<T>> ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = power_to_f64(vec);
+    for idx in 0..converted.len()
+    {
+        converted[idx] = converted[idx].mixed_sqrt();
+    }
+    return converted;
+}
+
+/// The `order`-th central moment, with the divisor `N`.
+fn central_moment( values: &[f64], order: i32 ) -> f64
+{
+    let mut mean = 0f64;
+    for value in values
+    {
+        mean = mean + value;
+    }
+    mean = mean/(values.len() as f64);
+
+    let mut moment = 0f64;
+    for value in values
+    {
+        moment = moment + (value-mean).mixed_powi(order);
+    }
+    return moment/(values.len() as f64);
+}
+
+/// The skewness from the central moments. Zero for constant values.
+fn skewness( values: &[f64] ) -> f64
+{
+    let m2 = central_moment(values, 2);
+    if m2 == 0f64
+    {
+        return 0f64;
+    }
+    return central_moment(values, 3)/(m2*m2.mixed_sqrt());
+}
+
+/// The excess kurtosis from the central moments. Zero for constant values.
+fn kurtosis( values: &[f64] ) -> f64
+{
+    let m2 = central_moment(values, 2);
+    if m2 == 0f64
+    {
+        return 0f64;
+    }
+    return central_moment(values, 4)/(m2*m2) - 3f64;
+}
+
+/// The `q`-th quantile, interpolated linearly between the closest ranks.
+fn quantile( values: &[f64], q: f64 ) -> f64
+{
+    if !(0f64..=1f64).contains(&q)
+    {
+        core::panic!("The quantile must be in the range 0 to 1, got {}.", q);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let position = q*((sorted.len()-1) as f64);
+    let lower = position.mixed_floor() as usize;
+    if lower == sorted.len()-1
+    {
+        return sorted[lower];
+    }
+    let fraction = position - lower as f64;
+    return sorted[lower] + fraction*(sorted[lower+1]-sorted[lower]);
+}
+
+/// The index of the highest value, or the lowest if `lowest`. The first index on ties.
+fn extremum_index<V: PartialOrd>( values: &[V], lowest: bool ) -> usize
+{
+    let mut best = 0;
+    for idx in 1..values.len()
+    {
+        if (lowest && values[idx] < values[best]) || (!lowest && values[best] < values[idx])
+        {
+            best = idx;
+        }
+    }
+    return best;
+}
+
+/// The variance from the sum of squared deviations, with the divisor `N-ddof`.
+fn variance( squared_deviation: f64, len: usize, ddof: usize ) -> f64
+{
+    if len <= ddof
+    {
+        core::panic!("The delta degrees of freedom ({}) must be less than the length of the vector ({}).", ddof, len);
+    }
+    return squared_deviation/((len-ddof) as f64);
+}
+
+/// The ratio of the peak to the RMS, from the squared magnitudes. Zero for an all-zero vector.
+fn crest_factor( power: &[f64] ) -> f64
+{
+    let mut peak = 0f64;
+    let mut mean = 0f64;
+    for value in power
+    {
+        peak = if peak < *value { *value } else { peak };
+        mean = mean + value;
+    }
+    mean = mean/(power.len() as f64);
+    if mean == 0f64
+    {
+        return 0f64;
+    }
+    return (peak/mean).mixed_sqrt();
+}
+
+impl <T: MixedReal> Variance<T> for Vec<T>
+{
+    /// Estimate the variance of the vector, `Σ(x-μ)²/(N-ddof)`.
+    ///
+    /// Use `ddof` zero for the maximum likelihood estimate of a Gaussian, and one for the unbiased estimate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ddof` - The delta degrees of freedom.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![2f64, 4f64, 4f64, 4f64, 5f64, 5f64, 7f64, 9f64] );
+    /// assert_eq!( test_vec.variance(0), 4f64 );
+    /// assert_eq!( test_vec.variance(1), 32f64/7f64 );
+    /// ```
+    fn variance( &self, ddof: usize ) -> T
+    {
+        assert_not_empty(self.len());
+        let values = to_f64(self);
+        let squared_deviation = central_moment(&values, 2)*(values.len() as f64);
+        return T::mixed_from_num(variance(squared_deviation, values.len(), ddof));
+    }
+
+    /// Estimate the standard deviation of the vector, the square root of [`Variance::variance`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `ddof` - The delta degrees of freedom.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![2f64, 4f64, 4f64, 4f64, 5f64, 5f64, 7f64, 9f64] );
+    /// assert_eq!( test_vec.std(0), 2f64 );
+    /// ```
+    fn std( &self, ddof: usize ) -> T
+    {
+        let variance: f64 = self.variance(ddof).mixed_to_num();
+        return T::mixed_from_num(variance.mixed_sqrt());
+    }
+}
+
+impl <T: MixedReal> Variance<T> for Vec<Cartesian<T>>
+{
+    /// Estimate the variance of the complex vector, `Σ|x-μ|²/(N-ddof)`.
+    ///
+    /// This is the sum of the variances of the real and imaginary parts.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ddof` - The delta degrees of freedom.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(1f64, 1f64), Cartesian::new(-1f64, -1f64)] );
+    /// assert_eq!( test_vec.variance(0), 2f64 );
+    /// assert_eq!( test_vec.std(1), 2f64 );
+    /// ```
+    fn variance( &self, ddof: usize ) -> T
+    {
+        assert_not_empty(self.len());
+        let re = to_f64(&self.re());
+        let im = to_f64(&self.im());
+        let squared_deviation = (central_moment(&re, 2) + central_moment(&im, 2))*(self.len() as f64);
+        return T::mixed_from_num(variance(squared_deviation, self.len(), ddof));
+    }
+
+    /// Estimate the standard deviation of the complex vector, the square root of [`Variance::variance`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `ddof` - The delta degrees of freedom.
+    ///
+    fn std( &self, ddof: usize ) -> T
+    {
+        let variance: f64 = self.variance(ddof).mixed_to_num();
+        return T::mixed_from_num(variance.mixed_sqrt());
+    }
+}
+
+impl <T: MixedReal> Rms<T> for Vec<T>
+{
+    /// Compute the Root Mean Square (RMS) of the vector, `√(Σx²/N)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![3f64, -3f64, 3f64, -3f64] );
+    /// assert_eq!( test_vec.rms(), 3f64 );
+    /// ```
+    fn rms( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        let mut power = 0f64;
+        for value in to_f64(self)
+        {
+            power = power + value*value;
+        }
+        return T::mixed_from_num((power/(self.len() as f64)).mixed_sqrt());
+    }
+}
+
+impl <T: MixedReal> Rms<T> for Vec<Cartesian<T>>
+{
+    /// Compute the Root Mean Square (RMS) of the complex vector, `√(Σ|x|²/N)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::osc( 1f64, 0f64, 16 );
+    /// assert!( (test_vec.rms()-1f64).abs() < 1e-12 );
+    /// ```
+    fn rms( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        let mut power = 0f64;
+        for value in power_to_f64(self)
+        {
+            power = power + value;
+        }
+        return T::mixed_from_num((power/(self.len() as f64)).mixed_sqrt());
+    }
+}
+
+impl <T: MixedReal> ArgMax<T> for Vec<T>
+{
+    /// Find the index of the highest item in the vector. The first index if several are equal.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![1f32, 5f32, -7f32, 5f32] );
+    /// assert_eq!( test_vec.argmax(), 1 );
+    /// ```
+    fn argmax( &self ) -> usize
+    {
+        assert_not_empty(self.len());
+        return extremum_index(&self.vec, false);
+    }
+}
+
+impl <T: MixedReal> ArgMax<T> for Vec<Cartesian<T>>
+{
+    /// Find the index of the item with the highest magnitude in the complex vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(1f32, 0f32), Cartesian::new(0f32, -2f32)] );
+    /// assert_eq!( test_vec.argmax(), 1 );
+    /// ```
+    fn argmax( &self ) -> usize
+    {
+        assert_not_empty(self.len());
+        return extremum_index(&power_to_f64(self), false);
+    }
+}
+
+impl <T: MixedReal> ArgMin<T> for Vec<T>
+{
+    /// Find the index of the lowest item in the vector. The first index if several are equal.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![1f32, 5f32, -7f32, 5f32] );
+    /// assert_eq!( test_vec.argmin(), 2 );
+    /// ```
+    fn argmin( &self ) -> usize
+    {
+        assert_not_empty(self.len());
+        return extremum_index(&self.vec, true);
+    }
+}
+
+impl <T: MixedReal> ArgMin<T> for Vec<Cartesian<T>>
+{
+    /// Find the index of the item with the lowest magnitude in the complex vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(1f32, 0f32), Cartesian::new(0f32, -2f32)] );
+    /// assert_eq!( test_vec.argmin(), 0 );
+    /// ```
+    fn argmin( &self ) -> usize
+    {
+        assert_not_empty(self.len());
+        return extremum_index(&power_to_f64(self), true);
+    }
+}
+
+impl <T: MixedReal> Median<T> for Vec<T>
+{
+    /// Find the median of the vector. The mean of the two middle items for an even length.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![3f64, 1f64, 4f64, 1f64, 5f64] );
+    /// assert_eq!( test_vec.median(), 3f64 );
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![3f64, 1f64, 4f64, 1f64] );
+    /// assert_eq!( test_vec.median(), 2f64 );
+    /// ```
+    fn median( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(quantile(&to_f64(self), 0.5f64));
+    }
+}
+
+impl <T: MixedReal> Median<T> for Vec<Cartesian<T>>
+{
+    /// Find the median of the magnitudes of the complex vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(3f64, 4f64), Cartesian::new(0f64, -1f64), Cartesian::new(-2f64, 0f64)] );
+    /// assert_eq!( test_vec.median(), 2f64 );
+    /// ```
+    fn median( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(quantile(&magnitude_to_f64(self), 0.5f64));
+    }
+}
+
+impl <T: MixedReal> Percentile<T> for Vec<T>
+{
+    /// Find the `p`-th percentile of the vector, interpolated linearly between the closest ranks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The percentile, in the range 0 to 100.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::lin_range( 0f64, 10f64, 11 );
+    /// assert_eq!( test_vec.percentile(90f64), 9f64 );
+    /// assert_eq!( test_vec.percentile(95f64), 9.5f64 );
+    /// ```
+    fn percentile( &self, p: T ) -> T
+    {
+        let p: f64 = p.mixed_to_num();
+        return self.quantile(T::mixed_from_num(p/100f64));
+    }
+
+    /// Find the `q`-th quantile of the vector, interpolated linearly between the closest ranks.
+    ///
+    /// The quantile at `q` lies at the fractional position `q(N-1)` of the sorted vector.
+    ///
+    /// ## Arguments
+    ///
+    /// * `q` - The quantile, in the range 0 to 1.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![4f64, 1f64, 3f64, 2f64] );
+    /// assert_eq!( test_vec.quantile(0f64), 1f64 );
+    /// assert_eq!( test_vec.quantile(0.5f64), 2.5f64 );
+    /// assert_eq!( test_vec.quantile(1f64), 4f64 );
+    /// ```
+    fn quantile( &self, q: T ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(quantile(&to_f64(self), q.mixed_to_num()));
+    }
+}
+
+impl <T: MixedReal> Percentile<T> for Vec<Cartesian<T>>
+{
+    /// Find the `p`-th percentile of the magnitudes of the complex vector, interpolated linearly between the closest ranks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The percentile, in the range 0 to 100.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(0f64, 1f64), Cartesian::new(-3f64, 0f64)] );
+    /// assert_eq!( test_vec.percentile(50f64), 2f64 );
+    /// ```
+    fn percentile( &self, p: T ) -> T
+    {
+        let p: f64 = p.mixed_to_num();
+        return self.quantile(T::mixed_from_num(p/100f64));
+    }
+
+    /// Find the `q`-th quantile of the magnitudes of the complex vector, interpolated linearly between the closest ranks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `q` - The quantile, in the range 0 to 1.
+    ///
+    fn quantile( &self, q: T ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(quantile(&magnitude_to_f64(self), q.mixed_to_num()));
+    }
+}
+
+impl <T: MixedReal> Skewness<T> for Vec<T>
+{
+    /// Estimate the skewness of the vector, `m₃/m₂^(3/2)` from the central moments with the divisor `N`.
+    ///
+    /// Zero for a symmetric distribution, and positive for a longer tail above the mean.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![1f64, 2f64, 3f64] );
+    /// assert_eq!( test_vec.skewness(), 0f64 );
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![0f64, 0f64, 0f64, 1f64] );
+    /// assert!( (test_vec.skewness()-1.1547005383792517f64).abs() < 1e-12 );
+    /// ```
+    fn skewness( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(skewness(&to_f64(self)));
+    }
+}
+
+impl <T: MixedReal> Skewness<T> for Vec<Cartesian<T>>
+{
+    /// Estimate the skewness of the magnitudes of the complex vector, see [`Skewness`] for real vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(0f64, 1f64), Cartesian::new(-2f64, 0f64), Cartesian::new(0f64, -3f64)] );
+    /// assert_eq!( test_vec.skewness(), 0f64 );
+    /// ```
+    fn skewness( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(skewness(&magnitude_to_f64(self)));
+    }
+}
+
+impl <T: MixedReal> Kurtosis<T> for Vec<T>
+{
+    /// Estimate the excess kurtosis of the vector, `m₄/m₂²-3` from the central moments with the divisor `N`.
+    ///
+    /// Zero for a Gaussian distribution, -1.5 for a sinusoid and -2 for a binary signal.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![1f64, -1f64, 1f64, -1f64] );
+    /// assert_eq!( test_vec.kurtosis(), -2f64 );
+    /// ```
+    fn kurtosis( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(kurtosis(&to_f64(self)));
+    }
+}
+
+impl <T: MixedReal> Kurtosis<T> for Vec<Cartesian<T>>
+{
+    /// Estimate the excess kurtosis of the magnitudes of the complex vector, see [`Kurtosis`] for real vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let test_vec = Vec::new_from_vec( vec![Cartesian::new(1f64, 0f64), Cartesian::new(0f64, 3f64)] );
+    /// assert_eq!( test_vec.kurtosis(), -2f64 );
+    /// ```
+    fn kurtosis( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(kurtosis(&magnitude_to_f64(self)));
+    }
+}
+
+impl <T: MixedReal> CrestFactor<T> for Vec<T>
+{
+    /// Compute the crest factor, the ratio of the peak magnitude to the RMS. Zero for an all-zero vector.
+    ///
+    /// In dB, use [`Decibel::mag2db`] on the result, e.g. 3.01 dB for a sinusoid.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::new_from_vec( vec![0f64, 1f64, 0f64, -1f64] );
+    /// assert_eq!( test_vec.crest_factor(), 2f64.sqrt() );
+    /// ```
+    fn crest_factor( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        let mut power = to_f64(self);
+        for idx in 0..power.len()
+        {
+            power[idx] = power[idx]*power[idx];
+        }
+        return T::mixed_from_num(crest_factor(&power));
+    }
+}
+
+impl <T: MixedReal> CrestFactor<T> for Vec<Cartesian<T>>
+{
+    /// Compute the crest factor of the complex vector, the ratio of the peak magnitude to the RMS. Zero for an all-zero vector.
+    ///
+    /// One for a complex exponential.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// let test_vec = Vec::osc( 0.3f64, 0f64, 64 );
+    /// assert!( (test_vec.crest_factor()-1f64).abs() < 1e-12 );
+    /// ```
+    fn crest_factor( &self ) -> T
+    {
+        assert_not_empty(self.len());
+        return T::mixed_from_num(crest_factor(&power_to_f64(self)));
+    }
+}
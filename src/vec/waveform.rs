@@ -0,0 +1,662 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::traits::*;
+use crate::vec::*;
+
+/// The frequency sweep of a chirp, from the start frequency `ω₀` at the first sample to the stop frequency `ω₁` at the last, see [`Chirp`].
+///
+/// With `t` the sample index and `T` the index of the last sample, the instantaneous frequency is
+///
+/// * Linear:      `ω(t) = ω₀ + (ω₁-ω₀)t/T`.
+/// * Quadratic:   `ω(t) = ω₀ + (ω₁-ω₀)(t/T)²`.
+/// * Logarithmic: `ω(t) = ω₀(ω₁/ω₀)^(t/T)`, an exponential sweep with a constant number of samples per octave.
+/// * Hyperbolic:  `ω(t) = ω₀ω₁T/((ω₀-ω₁)t + ω₁T)`, with a linear sweep of the period.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChirpSweep {
+    Linear,
+    Quadratic,
+    /// The start and stop frequencies must be non-zero, and of equal sign.
+    Logarithmic,
+    /// The start and stop frequencies must be non-zero, and of equal sign.
+    Hyperbolic,
+}
+
+/// The phases of the tones of a multitone signal, see [`Multitone`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MultitonePhases<T> {
+    /// All tones start in phase, for the highest crest factor.
+    Zero,
+    /// The start phase of each tone in radians.
+    Given(Vec<T>),
+    /// Schroeder's phases, `φₖ = -2π Σₗ₍ₗ₌₁..ₖ₋₁₎ (k-l)pₗ` with `pₗ` the fraction of the power in tone `l`, for a low crest factor.
+    ///
+    /// The phases only depend on the order and power of the tones, not on their frequencies.
+    /// The crest factor is only low for tones with equally spaced, harmonically related frequencies, `ωₖ = ω₀ + kΔω`, given in increasing order.
+    /// For other frequency plans the crest factor is in general not reduced.
+    Schroeder,
+}
+
+/// The phase of each sample of a chirp, in radians.
+fn chirp_phase( len: usize, start_freq: f64, stop_freq: f64, sweep: ChirpSweep, phase_rad: f64 ) -> alloc::vec::Vec<f64>
+{
+    let duration = if len < 2 { 1f64 } else { (len-1) as f64 };
+    let constant = start_freq == stop_freq;
+
+    if (sweep == ChirpSweep::Logarithmic || sweep == ChirpSweep::Hyperbolic) && start_freq*stop_freq <= 0f64
+    {
+        core::panic!("The start and stop frequencies must be non-zero and of equal sign for a {:?} sweep.", sweep);
+    }
+
+    let mut phase = alloc::vec::Vec::<f64>::with_capacity(len);
+    for idx in 0..len
+    {
+        let t = idx as f64;
+        let sweep_phase = if constant { start_freq*t } else {
+            match sweep {
+                ChirpSweep::Linear      => start_freq*t + (stop_freq-start_freq)*t*t/(2f64*duration),
+                ChirpSweep::Quadratic   => start_freq*t + (stop_freq-start_freq)*t*t*t/(3f64*duration*duration),
+                ChirpSweep::Logarithmic => {
+                    let log_ratio = (stop_freq/start_freq).mixed_log();
+                    start_freq*duration/log_ratio*((log_ratio*t/duration).mixed_exp() - 1f64)
+                },
+                ChirpSweep::Hyperbolic  => {
+                    // The frequency has a pole at t = singular, outside 0..=T.
+                    let singular = -stop_freq*duration/(start_freq-stop_freq);
+                    -singular*start_freq*(1f64 - t/singular).mixed_abs().mixed_log()
+                },
+            }
+        };
+        phase.push(sweep_phase + phase_rad);
+    }
+    return phase;
+}
+
+/// The start phase of each tone, in radians.
+fn multitone_phases<T: MixedReal>( amplitudes: &[f64], phases: &MultitonePhases<T> ) -> alloc::vec::Vec<f64>
+{
+    let num_tones = amplitudes.len();
+    match phases {
+        MultitonePhases::Zero          => return alloc::vec![0f64; num_tones],
+        MultitonePhases::Given(phases) => {
+            if phases.len() != num_tones
+            {
+                core::panic!("One phase per tone is required, got {} phases for {} tones.", phases.len(), num_tones);
+            }
+            let mut converted = alloc::vec::Vec::<f64>::with_capacity(num_tones);
+            for idx in 0..num_tones
+            {
+                converted.push(phases[idx].mixed_to_num());
+            }
+            return converted;
+        },
+        MultitonePhases::Schroeder     => {
+            let mut total_power = 0f64;
+            for amplitude in amplitudes
+            {
+                total_power = total_power + amplitude*amplitude;
+            }
+
+            let mut schroeder = alloc::vec::Vec::<f64>::with_capacity(num_tones);
+            for k in 0..num_tones
+            {
+                let mut sum = 0f64;
+                for l in 0..k
+                {
+                    sum = sum + ((k-l) as f64)*amplitudes[l]*amplitudes[l]/total_power;
+                }
+                schroeder.push(-2f64*core::f64::consts::PI*sum);
+            }
+            return schroeder;
+        },
+    }
+}
+
+/// Sum the complex tones `aₖe^(j(ωₖn+φₖ))` for each sample.
+fn sum_tones<T: MixedReal>( len: usize, frequencies: &Vec<T>, amplitudes: &Vec<T>, phases: &MultitonePhases<T> ) -> alloc::vec::Vec<(f64, f64)>
+{
+    if frequencies.len() != amplitudes.len()
+    {
+        core::panic!("One amplitude per tone is required, got {} amplitudes for {} tones.", amplitudes.len(), frequencies.len());
+    }
+
+    let mut tone_amplitudes = alloc::vec::Vec::<f64>::with_capacity(amplitudes.len());
+    for idx in 0..amplitudes.len()
+    {
+        tone_amplitudes.push(amplitudes[idx].mixed_to_num());
+    }
+    let tone_phases = multitone_phases(&tone_amplitudes, phases);
+
+    let mut samples = alloc::vec![(0f64, 0f64); len];
+    for tone in 0..frequencies.len()
+    {
+        let frequency: f64 = frequencies[tone].mixed_to_num();
+        for idx in 0..len
+        {
+            let (sin, cos) = (frequency*(idx as f64) + tone_phases[tone]).mixed_sincos();
+            samples[idx] = (samples[idx].0 + tone_amplitudes[tone]*cos, samples[idx].1 + tone_amplitudes[tone]*sin);
+        }
+    }
+    return samples;
+}
+
+/// The shape of a band-limited waveform.
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    Square,
+    Sawtooth,
+    Triangle,
+}
+
+/// The Fourier series coefficient of `sin(kωt)` of a waveform with unit amplitude.
+fn harmonic_amplitude( shape: Shape, k: usize ) -> f64
+{
+    let pi = core::f64::consts::PI;
+    match shape {
+        Shape::Square   => return if k%2 == 1 { 4f64/(pi*(k as f64)) } else { 0f64 },
+        Shape::Sawtooth => return if k%2 == 1 { 2f64/(pi*(k as f64)) } else { -2f64/(pi*(k as f64)) },
+        Shape::Triangle => {
+            if k.is_multiple_of(2)
+            {
+                return 0f64;
+            }
+            let sign = if (k/2).is_multiple_of(2) { 1f64 } else { -1f64 };
+            return sign*8f64/(pi*pi*(k as f64)*(k as f64));
+        },
+    }
+}
+
+/// Sum the analytic harmonics `bₖe^(j(k(ωn+θ)-π/2))` below the Nyquist frequency, whose real parts are the Fourier series `Σbₖsin(k(ωn+θ))`.
+fn sum_harmonics( shape: Shape, len: usize, angular_freq_rad: f64, phase_rad: f64 ) -> alloc::vec::Vec<(f64, f64)>
+{
+    let fundamental = angular_freq_rad.mixed_abs();
+    if fundamental == 0f64 || core::f64::consts::PI <= fundamental
+    {
+        core::panic!("The frequency must be non-zero and below the Nyquist frequency, got {} radians per sample.", angular_freq_rad);
+    }
+
+    let mut samples = alloc::vec![(0f64, 0f64); len];
+    let mut k = 1;
+    while (k as f64)*fundamental < core::f64::consts::PI
+    {
+        let amplitude = harmonic_amplitude(shape, k);
+        if amplitude != 0f64
+        {
+            for idx in 0..len
+            {
+                let (sin, cos) = ((k as f64)*(angular_freq_rad*(idx as f64) + phase_rad)).mixed_sincos();
+                samples[idx] = (samples[idx].0 + amplitude*sin, samples[idx].1 - amplitude*cos);
+            }
+        }
+        k = k + 1;
+    }
+    return samples;
+}
+
+/// Rectangular pulses of `width` samples of one, starting every `period` samples.
+fn pulses( len: usize, period: usize, width: usize ) -> alloc::vec::Vec<f64>
+{
+    if period == 0 || period < width
+    {
+        core::panic!("The period must be non-zero and not shorter than the width, got period {} and width {}.", period, width);
+    }
+
+    let mut samples = alloc::vec::Vec::<f64>::with_capacity(len);
+    for idx in 0..len
+    {
+        samples.push(if idx%period < width { 1f64 } else { 0f64 });
+    }
+    return samples;
+}
+
+/// Convert `f64` samples to a real vector.
+fn real_from_f64<T: MixedReal>( samples: &[f64] ) -> Vec<T>
+{
+    let mut vec = Vec::<T>::new_with_capacity(samples.len());
+    for sample in samples
+    {
+        vec.push_back(T::mixed_from_num(*sample));
+    }
+    return vec;
+}
+
+/// Convert `f64` samples to a complex vector.
+fn complex_from_f64<T: MixedReal>( samples: &[(f64, f64)] ) -> Vec<Cartesian<T>>
+{
+    let mut vec = Vec::<Cartesian<T>>::new_with_capacity(samples.len());
+    for (re, im) in samples
+    {
+        vec.push_back(Cartesian::new(T::mixed_from_num(*re), T::mixed_from_num(*im)));
+    }
+    return vec;
+}
+
+/// Convert a real `f64` signal to a complex vector, with zero imaginary part.
+fn complex_from_real_f64<T: MixedReal>( samples: &[f64] ) -> Vec<Cartesian<T>>
+{
+    let mut vec = Vec::<Cartesian<T>>::new_with_capacity(samples.len());
+    for sample in samples
+    {
+        vec.push_back(Cartesian::new(T::mixed_from_num(*sample), T::mixed_from_num(0f64)));
+    }
+    return vec;
+}
+
+impl <T: MixedReal> Chirp<T> for Vec<T>
+{
+    /// Create a real chirp, `cos(φ(n)+θ)`, with the phase `φ` integrated from the instantaneous frequency of the sweep.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`        - The number of samples.
+    /// * `start_freq` - The angular frequency of the first sample, in radians per sample (ω₀).
+    /// * `stop_freq`  - The angular frequency of the last sample, in radians per sample (ω₁).
+    /// * `sweep`      - The shape of the frequency sweep.
+    /// * `phase_rad`  - The start phase in radians (θ).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let chirp = Vec::<f64>::chirp( 1000, 0.1f64, 1f64, ChirpSweep::Linear, 0f64 );
+    /// assert_eq!( chirp[0], 1f64 );
+    /// ```
+    fn chirp( len: usize, start_freq: T, stop_freq: T, sweep: ChirpSweep, phase_rad: T ) -> Self
+    {
+        let phase = chirp_phase(len, start_freq.mixed_to_num(), stop_freq.mixed_to_num(), sweep, phase_rad.mixed_to_num());
+        let mut samples = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            samples.push(phase[idx].mixed_cos());
+        }
+        return real_from_f64(&samples);
+    }
+}
+
+impl <T: MixedReal> Chirp<T> for Vec<Cartesian<T>>
+{
+    /// Create a complex chirp, `e^(j(φ(n)+θ))`, with the phase `φ` integrated from the instantaneous frequency of the sweep.
+    ///
+    /// Negative frequencies sweep the lower half of the spectrum.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`        - The number of samples.
+    /// * `start_freq` - The angular frequency of the first sample, in radians per sample (ω₀).
+    /// * `stop_freq`  - The angular frequency of the last sample, in radians per sample (ω₁).
+    /// * `sweep`      - The shape of the frequency sweep.
+    /// * `phase_rad`  - The start phase in radians (θ).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // A Linear Frequency Modulated (LFM) radar pulse, from -π/2 to π/2 radians per sample.
+    /// let pulse = Vec::<Cartesian<f64>>::chirp( 256, -core::f64::consts::FRAC_PI_2, core::f64::consts::FRAC_PI_2, ChirpSweep::Linear, 0f64 );
+    ///
+    /// // The instantaneous frequency is zero at the center of the pulse.
+    /// let x = pulse[128];
+    /// let y = pulse[127];
+    /// let step = (x.im*y.re - x.re*y.im).atan2(x.re*y.re + x.im*y.im);
+    /// assert!( step.abs() < 0.01 );
+    /// ```
+    fn chirp( len: usize, start_freq: T, stop_freq: T, sweep: ChirpSweep, phase_rad: T ) -> Self
+    {
+        let phase = chirp_phase(len, start_freq.mixed_to_num(), stop_freq.mixed_to_num(), sweep, phase_rad.mixed_to_num());
+        let mut samples = alloc::vec::Vec::<(f64, f64)>::with_capacity(len);
+        for idx in 0..len
+        {
+            let (sin, cos) = phase[idx].mixed_sincos();
+            samples.push((cos, sin));
+        }
+        return complex_from_f64(&samples);
+    }
+}
+
+impl <T: MixedReal> Multitone<T> for Vec<T>
+{
+    /// Create a real multitone signal, `Σaₖcos(ωₖn+φₖ)`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`         - The number of samples.
+    /// * `frequencies` - The angular frequency of each tone, in radians per sample (ωₖ).
+    /// * `amplitudes`  - The amplitude of each tone (aₖ).
+    /// * `phases`      - The start phase of each tone (φₖ), given or chosen for a low crest factor. [`MultitonePhases::Schroeder`] requires equally spaced frequencies in increasing order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// // 16 tones of equal amplitude, on the bins of a 256-point FFT.
+    /// let frequencies = Vec::lin_range( 2f64*core::f64::consts::PI/256f64, 32f64*core::f64::consts::PI/256f64, 16 );
+    /// let amplitudes  = Vec::<f64>::ones(16);
+    ///
+    /// let in_phase  = Vec::<f64>::multitone( 256, &frequencies, &amplitudes, MultitonePhases::Zero );
+    /// let schroeder = Vec::<f64>::multitone( 256, &frequencies, &amplitudes, MultitonePhases::Schroeder );
+    ///
+    /// assert!( (in_phase.crest_factor()-32f64.sqrt()).abs() < 1e-9 );
+    /// assert!( schroeder.crest_factor() < 2f64 );
+    /// ```
+    fn multitone( len: usize, frequencies: &Vec<T>, amplitudes: &Vec<T>, phases: MultitonePhases<T> ) -> Self
+    {
+        let tones = sum_tones(len, frequencies, amplitudes, &phases);
+        let mut samples = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            samples.push(tones[idx].0);
+        }
+        return real_from_f64(&samples);
+    }
+}
+
+impl <T: MixedReal> Multitone<T> for Vec<Cartesian<T>>
+{
+    /// Create a complex multitone signal, `Σaₖe^(j(ωₖn+φₖ))`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`         - The number of samples.
+    /// * `frequencies` - The angular frequency of each tone, in radians per sample (ωₖ).
+    /// * `amplitudes`  - The amplitude of each tone (aₖ).
+    /// * `phases`      - The start phase of each tone (φₖ), given or chosen for a low crest factor. [`MultitonePhases::Schroeder`] requires equally spaced frequencies in increasing order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// // Two tones on the bins 3 and 5 of a 32-point FFT.
+    /// let frequencies = Vec::new_from_vec( vec![3f64, 5f64] )*(2f64*core::f64::consts::PI/32f64);
+    /// let amplitudes  = Vec::new_from_vec( vec![1f64, 0.5f64] );
+    ///
+    /// let mut spectrum = Vec::<Cartesian<f64>>::multitone( 32, &frequencies, &amplitudes, MultitonePhases::Zero );
+    /// spectrum.fft();
+    ///
+    /// assert!( (spectrum[3].re-1f64).abs() < 1e-9 );
+    /// assert!( (spectrum[5].re-0.5f64).abs() < 1e-9 );
+    /// ```
+    fn multitone( len: usize, frequencies: &Vec<T>, amplitudes: &Vec<T>, phases: MultitonePhases<T> ) -> Self
+    {
+        return complex_from_f64(&sum_tones(len, frequencies, amplitudes, &phases));
+    }
+}
+
+impl <T: MixedReal> BandLimitedWaveform<T> for Vec<T>
+{
+    /// Create a band-limited square wave with unit amplitude, `(4/π)Σsin(k(ωn+θ))/k` over the odd harmonics below the Nyquist frequency.
+    ///
+    /// As the harmonics are band-limited there is no aliasing, but there is Gibbs ringing at the edges.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ). The wave rises through zero at zero phase.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let square = Vec::<f64>::square( 64, 2f64*core::f64::consts::PI/64f64, 0f64 );
+    /// assert!( (square[16]-1f64).abs() < 0.05 );
+    /// assert!( (square[48]+1f64).abs() < 0.05 );
+    /// ```
+    fn square( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        let samples = sum_harmonics(Shape::Square, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num());
+        let mut real = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            real.push(samples[idx].0);
+        }
+        return real_from_f64(&real);
+    }
+
+    /// Create a band-limited sawtooth wave with unit amplitude, `(2/π)Σ(-1)^(k+1)sin(k(ωn+θ))/k` over the harmonics below the Nyquist frequency.
+    ///
+    /// The wave ramps up through zero at zero phase, and falls at a phase of π.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let sawtooth = Vec::<f64>::sawtooth( 256, 2f64*core::f64::consts::PI/256f64, 0f64 );
+    /// assert!( sawtooth[0].abs() < 1e-12 );
+    /// assert!( (sawtooth[64]-0.5f64).abs() < 0.01 );
+    /// ```
+    fn sawtooth( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        let samples = sum_harmonics(Shape::Sawtooth, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num());
+        let mut real = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            real.push(samples[idx].0);
+        }
+        return real_from_f64(&real);
+    }
+
+    /// Create a band-limited triangle wave with unit amplitude, `(8/π²)Σ(-1)^((k-1)/2)sin(k(ωn+θ))/k²` over the odd harmonics below the Nyquist frequency.
+    ///
+    /// The wave rises through zero at zero phase, and peaks at a phase of π/2.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let triangle = Vec::<f64>::triangle( 256, 2f64*core::f64::consts::PI/256f64, 0f64 );
+    /// assert!( (triangle[64]-1f64).abs() < 0.01 );
+    /// assert!( (triangle[32]-0.5f64).abs() < 0.01 );
+    /// ```
+    fn triangle( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        let samples = sum_harmonics(Shape::Triangle, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num());
+        let mut real = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            real.push(samples[idx].0);
+        }
+        return real_from_f64(&real);
+    }
+}
+
+impl <T: MixedReal> BandLimitedWaveform<T> for Vec<Cartesian<T>>
+{
+    /// Create the analytic band-limited square wave, whose real part is the real square wave, see [`BandLimitedWaveform::square`] for `Vec<T>`.
+    ///
+    /// Only the positive harmonics are present, and the imaginary part is the Hilbert transform of the real part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let omega = 2f64*core::f64::consts::PI/64f64;
+    /// let analytic = Vec::<Cartesian<f64>>::square( 64, omega, 0f64 );
+    /// assert_eq!( analytic.re(), Vec::<f64>::square( 64, omega, 0f64 ) );
+    /// ```
+    fn square( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        return complex_from_f64(&sum_harmonics(Shape::Square, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num()));
+    }
+
+    /// Create the analytic band-limited sawtooth wave, whose real part is the real sawtooth wave, see [`BandLimitedWaveform::sawtooth`] for `Vec<T>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ).
+    fn sawtooth( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        return complex_from_f64(&sum_harmonics(Shape::Sawtooth, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num()));
+    }
+
+    /// Create the analytic band-limited triangle wave, whose real part is the real triangle wave, see [`BandLimitedWaveform::triangle`] for `Vec<T>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`              - The number of samples.
+    /// * `angular_freq_rad` - The fundamental angular frequency, in radians per sample (ω).
+    /// * `phase_rad`        - The start phase in radians (θ).
+    fn triangle( len: usize, angular_freq_rad: T, phase_rad: T ) -> Self
+    {
+        return complex_from_f64(&sum_harmonics(Shape::Triangle, len, angular_freq_rad.mixed_to_num(), phase_rad.mixed_to_num()));
+    }
+}
+
+impl <T: MixedReal> Pulses<T> for Vec<T>
+{
+    /// Create a unit impulse, one at `position` and zero elsewhere.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `position` - The index of the impulse.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// assert_eq!( Vec::<f32>::impulse( 4, 1 ).to_string(), "[ 0, 1, 0, 0 ]" );
+    /// ```
+    fn impulse( len: usize, position: usize ) -> Self
+    {
+        let mut samples = alloc::vec![0f64; len];
+        if position < len
+        {
+            samples[position] = 1f64;
+        }
+        return real_from_f64(&samples);
+    }
+
+    /// Create a unit step, zero before `position` and one from it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `position` - The index of the first sample of one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// assert_eq!( Vec::<f32>::step( 4, 1 ).to_string(), "[ 0, 1, 1, 1 ]" );
+    /// ```
+    fn step( len: usize, position: usize ) -> Self
+    {
+        let mut samples = alloc::vec::Vec::<f64>::with_capacity(len);
+        for idx in 0..len
+        {
+            samples.push(if position <= idx { 1f64 } else { 0f64 });
+        }
+        return real_from_f64(&samples);
+    }
+
+    /// Create a train of rectangular pulses of `width` samples of one, starting every `period` samples from the first.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`    - The number of samples.
+    /// * `period` - The number of samples between the start of consecutive pulses.
+    /// * `width`  - The number of samples of one in each pulse, at most `period`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// assert_eq!( Vec::<f32>::pulse_train( 8, 4, 1 ).to_string(), "[ 1, 0, 0, 0, 1, 0, 0, 0 ]" );
+    /// assert_eq!( Vec::<f32>::pulse_train( 7, 3, 2 ).to_string(), "[ 1, 1, 0, 1, 1, 0, 1 ]" );
+    /// ```
+    fn pulse_train( len: usize, period: usize, width: usize ) -> Self
+    {
+        return real_from_f64(&pulses(len, period, width));
+    }
+}
+
+impl <T: MixedReal> Pulses<T> for Vec<Cartesian<T>>
+{
+    /// Create a unit impulse, one at `position` and zero elsewhere, with zero imaginary part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `position` - The index of the impulse.
+    fn impulse( len: usize, position: usize ) -> Self
+    {
+        return complex_from_real_f64(&Vec::<f64>::impulse(len, position).vec);
+    }
+
+    /// Create a unit step, zero before `position` and one from it, with zero imaginary part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`      - The number of samples.
+    /// * `position` - The index of the first sample of one.
+    fn step( len: usize, position: usize ) -> Self
+    {
+        return complex_from_real_f64(&Vec::<f64>::step(len, position).vec);
+    }
+
+    /// Create a train of rectangular pulses of `width` samples of one, starting every `period` samples from the first, with zero imaginary part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `len`    - The number of samples.
+    /// * `period` - The number of samples between the start of consecutive pulses.
+    /// * `width`  - The number of samples of one in each pulse, at most `period`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::*;
+    ///
+    /// let mut spectrum = Vec::<Cartesian<f64>>::pulse_train( 16, 4, 1 );
+    /// spectrum.fft();
+    /// // A comb, with a line every fourth bin.
+    /// assert!( (spectrum[4].re-0.25f64).abs() < 1e-12 );
+    /// assert!( spectrum[5].re.abs() < 1e-12 );
+    /// ```
+    fn pulse_train( len: usize, period: usize, width: usize ) -> Self
+    {
+        return complex_from_real_f64(&pulses(len, period, width));
+    }
+}
@@ -2,9 +2,14 @@
 // Norsk Datateknikk AS //
 //----------------------//
 
+extern crate alloc;
+
 use crate::*;
 use mixed_num::*;
 
+pub mod metrics;
+pub use metrics::*;
+
 impl <T: MixedNum + MixedOps + MixedNumConversion<usize> + MixedConsts + MixedCos + MixedZero + MixedOne + MixedAbs> Vec<T>
 {
     /// Generate a Hamming window funciton.
@@ -140,3 +145,820 @@ impl <T: MixedNum + MixedOps + MixedNumConversion<usize> + MixedConsts + MixedCo
         return r_vec;
     }
 }
+
+/// The coefficients of the flat-top window, with a scalloping loss below 0.01 dB.
+const FLAT_TOP: [f64; 5] = [0.21557895, 0.41663158, 0.277263158, 0.083578947, 0.006947368];
+
+/// The coefficients of the 4-term Blackman-Harris window, with sidelobes below -92 dB.
+const BLACKMAN_HARRIS: [f64; 4] = [0.35875, 0.48829, 0.14128, 0.01168];
+
+/// Generate a window from `w(n, size)`, where `size` is `len-1` for a symmetric window, and `len` for a periodic one.
+/// 
+/// The periodic window is the symmetric window of length `len+1`, with the last sample dropped.
+fn generate_window<T: MixedReal>( len: usize, periodic: bool, w: impl Fn(f64, f64) -> f64 ) -> Vec<T>
+{
+    let mut r_vec = crate::Vec::<T>::new_with_capacity(len);
+    if len == 0
+    {
+        return r_vec;
+    }
+    if len == 1
+    {
+        r_vec.push_back(T::mixed_from_num(1f64));
+        return r_vec;
+    }
+
+    let size = if periodic { len as f64 } else { (len-1) as f64 };
+    for idx in 0..len {
+        r_vec.push_back(T::mixed_from_num(w(idx as f64, size)));
+    }
+    return r_vec;
+}
+
+/// `Σ(-1)ᵏaₖcos(2πkn/size)`.
+fn cosine_sum( coefficients: &[f64], n: f64, size: f64 ) -> f64
+{
+    let mut w = 0f64;
+    for k in 0..coefficients.len()
+    {
+        let term = coefficients[k]*(2f64*core::f64::consts::PI*(k as f64)*n/size).mixed_cos();
+        w = if k.is_multiple_of(2) { w + term } else { w - term };
+    }
+    return w;
+}
+
+/// Convert the coefficients of a cosine-sum window to `f64`.
+fn coefficients_to_f64<T: MixedReal>( coefficients: &[T] ) -> alloc::vec::Vec<f64>
+{
+    let mut converted = alloc::vec::Vec::<f64>::with_capacity(coefficients.len());
+    for coefficient in coefficients
+    {
+        converted.push(coefficient.mixed_to_num());
+    }
+    return converted;
+}
+
+/// `I0(β√(1-(2n/size-1)²))/I0(β)`.
+fn kaiser( beta: f64, n: f64, size: f64 ) -> f64
+{
+    let x = 2f64*n/size - 1f64;
+    return bessel_i0(beta*(1f64-x*x).mixed_sqrt())/bessel_i0(beta);
+}
+
+/// Flat in the middle, with cosine tapers over `alpha/2` of the window at each end.
+fn tukey( alpha: f64, n: f64, size: f64 ) -> f64
+{
+    if !(0f64..=1f64).contains(&alpha)
+    {
+        core::panic!("The taper fraction of a Tukey window must lie between 0 and 1.");
+    }
+    let taper = alpha*size/2f64;
+    let distance = if n < size/2f64 { n } else { size-n };
+    if taper <= distance
+    {
+        return 1f64;
+    }
+    return 0.5f64*(1f64 - (core::f64::consts::PI*distance/taper).mixed_cos());
+}
+
+/// `exp(-½((n-size/2)/σ)²)`.
+fn gaussian( sigma: f64, n: f64, size: f64 ) -> f64
+{
+    if sigma <= 0f64
+    {
+        core::panic!("The standard deviation of a Gaussian window must be positive.");
+    }
+    let x = (n-size/2f64)/sigma;
+    return (-0.5f64*x*x).mixed_exp();
+}
+
+/// The Chebyshev polynomial of the first kind, `Tₙ(x)`, for any real `x`.
+fn chebyshev_polynomial( order: f64, x: f64 ) -> f64
+{
+    if x.mixed_abs() <= 1f64
+    {
+        return (order*x.mixed_acos()).mixed_cos();
+    }
+    let magnitude = (order*x.mixed_abs().mixed_acosh()).mixed_cosh();
+    if x < 0f64 && (order as usize)%2 == 1
+    {
+        return -magnitude;
+    }
+    return magnitude;
+}
+
+/// The symmetric Dolph-Chebyshev window, by the inverse DFT of the equiripple response `T_{N-1}(β cos(πk/N))`, normalized to a peak of one.
+fn dolph_chebyshev( len: usize, attenuation_db: f64 ) -> alloc::vec::Vec<f64>
+{
+    if len <= 1
+    {
+        return alloc::vec![1f64; len];
+    }
+    let order = (len-1) as f64;
+    let size = len as f64;
+    let beta = ((10f64.mixed_pow(attenuation_db.mixed_abs()/20f64)).mixed_acosh()/order).mixed_cosh();
+
+    let mut response = alloc::vec::Vec::<f64>::with_capacity(len);
+    for k in 0..len
+    {
+        response.push(chebyshev_polynomial(order, beta*(core::f64::consts::PI*(k as f64)/size).mixed_cos()));
+    }
+
+    // The window is centered at (N-1)/2, which is half a sample off the DFT grid for even lengths.
+    let center = order/2f64;
+    let mut window = alloc::vec::Vec::<f64>::with_capacity(len);
+    let mut peak = 0f64;
+    for idx in 0..len
+    {
+        let m = idx as f64 - center;
+        let mut w = 0f64;
+        for k in 0..len
+        {
+            w = w + response[k]*(2f64*core::f64::consts::PI*(k as f64)*m/size).mixed_cos();
+        }
+        if peak < w
+        {
+            peak = w;
+        }
+        window.push(w);
+    }
+    for w in window.iter_mut()
+    {
+        *w = *w/peak;
+    }
+    return window;
+}
+
+/// The number of eigenvalues below `x` of the symmetric tridiagonal matrix with `diagonal`, and `off_diagonal[i]` coupling `i` and `i+1`.
+fn sturm_count( diagonal: &[f64], off_diagonal: &[f64], x: f64 ) -> usize
+{
+    let mut count = 0;
+    let mut q = 1f64;
+    for i in 0..diagonal.len()
+    {
+        q = if i == 0 {
+            diagonal[i] - x
+        } else {
+            diagonal[i] - x - off_diagonal[i-1]*off_diagonal[i-1]/q
+        };
+        if q == 0f64
+        {
+            q = f64::EPSILON;
+        }
+        if q < 0f64
+        {
+            count = count+1;
+        }
+    }
+    return count;
+}
+
+/// Solve `(T-shift·I)x = rhs` for a symmetric tridiagonal `T`, by Gaussian elimination with partial pivoting.
+fn solve_tridiagonal( diagonal: &[f64], off_diagonal: &[f64], shift: f64, rhs: &mut [f64] )
+{
+    let n = diagonal.len();
+    let tiny = f64::EPSILON*(shift.mixed_abs()+1f64);
+
+    // The rows of the upper triangular factor, with the entries on and one and two above the diagonal.
+    let mut u0 = alloc::vec::Vec::<f64>::with_capacity(n);
+    let mut u1 = alloc::vec![0f64; n];
+    let mut u2 = alloc::vec![0f64; n];
+    for i in 0..n
+    {
+        u0.push(diagonal[i]-shift);
+        if i+1 < n
+        {
+            u1[i] = off_diagonal[i];
+        }
+    }
+
+    for i in 0..n-1
+    {
+        // Row i has entries in columns i and i+1, and row i+1 in columns i, i+1 and i+2.
+        let mut pivot = (u0[i], u1[i], 0f64, rhs[i]);
+        let mut other = (off_diagonal[i], u0[i+1], u1[i+1], rhs[i+1]);
+        if pivot.0.mixed_abs() < other.0.mixed_abs()
+        {
+            core::mem::swap(&mut pivot, &mut other);
+        }
+        if pivot.0 == 0f64
+        {
+            pivot.0 = tiny;
+        }
+
+        let m = other.0/pivot.0;
+        u0[i] = pivot.0;
+        u1[i] = pivot.1;
+        u2[i] = pivot.2;
+        rhs[i] = pivot.3;
+        u0[i+1] = other.1 - m*pivot.1;
+        u1[i+1] = other.2 - m*pivot.2;
+        rhs[i+1] = other.3 - m*pivot.3;
+    }
+    if u0[n-1] == 0f64
+    {
+        u0[n-1] = tiny;
+    }
+
+    for i in (0..n).rev()
+    {
+        let mut x = rhs[i];
+        if i+1 < n
+        {
+            x = x - u1[i]*rhs[i+1];
+        }
+        if i+2 < n
+        {
+            x = x - u2[i]*rhs[i+2];
+        }
+        rhs[i] = x/u0[i];
+    }
+}
+
+/// The discrete prolate spheroidal sequences, as the eigenvectors of the largest eigenvalues of the commuting tridiagonal matrix,
+/// with the diagonal `((N-1-2n)/2)²cos(2πW)` and off-diagonal `n(N-n)/2`.
+pub(crate) fn dpss( len: usize, half_bandwidth: f64, num_tapers: usize ) -> alloc::vec::Vec<alloc::vec::Vec<f64>>
+{
+    let mut diagonal = alloc::vec::Vec::<f64>::with_capacity(len);
+    let mut off_diagonal = alloc::vec::Vec::<f64>::with_capacity(len);
+    for n in 0..len
+    {
+        let x = ((len-1) as f64 - 2f64*(n as f64))/2f64;
+        diagonal.push(x*x*(2f64*core::f64::consts::PI*half_bandwidth).mixed_cos());
+        if n+1 < len
+        {
+            off_diagonal.push(((n+1) as f64)*((len-n-1) as f64)/2f64);
+        }
+    }
+
+    // Gershgorin bounds on the eigenvalues.
+    let mut lower = f64::MAX;
+    let mut upper = f64::MIN;
+    for n in 0..len
+    {
+        let mut radius = 0f64;
+        if 0 < n
+        {
+            radius = radius + off_diagonal[n-1].mixed_abs();
+        }
+        if n+1 < len
+        {
+            radius = radius + off_diagonal[n].mixed_abs();
+        }
+        lower = lower.min(diagonal[n]-radius);
+        upper = upper.max(diagonal[n]+radius);
+    }
+
+    let mut tapers = alloc::vec::Vec::<alloc::vec::Vec<f64>>::with_capacity(num_tapers);
+    for k in 0..num_tapers
+    {
+        // Bisect for the eigenvalue with index len-1-k in ascending order.
+        let index = len-1-k;
+        let mut low = lower;
+        let mut high = upper;
+        for _ in 0..200
+        {
+            let middle = (low+high)/2f64;
+            if high-low <= 4f64*f64::EPSILON*(low.mixed_abs().max(high.mixed_abs())+1f64)
+            {
+                break;
+            }
+            if sturm_count(&diagonal, &off_diagonal, middle) <= index
+            {
+                low = middle;
+            }
+            else
+            {
+                high = middle;
+            }
+        }
+        let eigenvalue = (low+high)/2f64;
+
+        // Inverse iteration, from a start with both even and odd components, kept orthogonal to the previous tapers.
+        let mut taper = alloc::vec::Vec::<f64>::with_capacity(len);
+        for n in 0..len
+        {
+            taper.push(1f64 + (n as f64)/(len as f64));
+        }
+        for _ in 0..4
+        {
+            solve_tridiagonal(&diagonal, &off_diagonal, eigenvalue, &mut taper);
+            for previous in tapers.iter()
+            {
+                let mut projection = 0f64;
+                for n in 0..len
+                {
+                    projection = projection + taper[n]*previous[n];
+                }
+                for n in 0..len
+                {
+                    taper[n] = taper[n] - projection*previous[n];
+                }
+            }
+            let mut energy = 0f64;
+            for n in 0..len
+            {
+                energy = energy + taper[n]*taper[n];
+            }
+            let norm = energy.mixed_sqrt();
+            for n in 0..len
+            {
+                taper[n] = taper[n]/norm;
+            }
+        }
+
+        // Even tapers have a positive sum, and odd tapers start with a positive lobe.
+        let mut sign = 0f64;
+        for n in 0..len
+        {
+            sign = if k.is_multiple_of(2) {
+                sign + taper[n]
+            } else {
+                sign + ((len-1) as f64 - 2f64*(n as f64))*taper[n]
+            };
+        }
+        if sign < 0f64
+        {
+            for n in 0..len
+            {
+                taper[n] = -taper[n];
+            }
+        }
+        tapers.push(taper);
+    }
+    return tapers;
+}
+
+/// The fraction of the energy of a unit energy taper within the half bandwidth `W`, from its autocorrelation `r`,
+/// `λ = 2Wr[0] + 2Σr[m]sin(2πWm)/(πm)`.
+pub(crate) fn dpss_concentration( taper: &[f64], half_bandwidth: f64 ) -> f64
+{
+    let len = taper.len();
+    let mut concentration = 0f64;
+    for m in 0..len
+    {
+        let mut r = 0f64;
+        for n in 0..len-m
+        {
+            r = r + taper[n]*taper[n+m];
+        }
+        concentration = if m == 0 {
+            2f64*half_bandwidth*r
+        } else {
+            concentration + 2f64*r*(2f64*core::f64::consts::PI*half_bandwidth*(m as f64)).mixed_sin()/(core::f64::consts::PI*(m as f64))
+        };
+    }
+    return concentration;
+}
+
+/// Symmetric windows are used for filter design, while periodic (DFT-even) windows are used for spectral analysis,
+/// where they have exactly the spectral properties of the window, sampled at the DFT bins.
+/// A periodic window of length `N` is the symmetric window of length `N+1`, with the last sample dropped.
+impl <T: MixedReal> Vec<T>
+{
+    /// Generate a symmetric Kaiser window function, `w[n] = I0(β√(1-(2n/(N-1)-1)²))/I0(β)`.
+    /// 
+    /// The shape parameter `β` trades main lobe width for sidelobe level, see [`kaiser_beta`](crate::kaiser_beta).
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`  - The length of the window.
+    /// * `beta` - The shape parameter. Zero yields a rectangular window.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::kaiser(9, 8.6f64);
+    /// 
+    /// assert_eq!( window[4], 1f64 );
+    /// assert!( (window[0]-window[8]).abs() < 1e-15 );
+    /// assert!( window[0] < 2e-3 );
+    /// ```
+    pub fn kaiser(len: usize, beta: T) -> Self {
+        let beta: f64 = beta.mixed_to_num();
+        return generate_window(len, false, |n, size| kaiser(beta, n, size));
+    }
+
+    /// Generate a periodic Kaiser window function, see [`Vec::kaiser`].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`  - The length of the window.
+    /// * `beta` - The shape parameter. Zero yields a rectangular window.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::kaiser_periodic(8, 8.6f64);
+    /// 
+    /// assert_eq!( window[4], 1f64 );
+    /// assert!( (window[1]-window[7]).abs() < 1e-15 );
+    /// ```
+    pub fn kaiser_periodic(len: usize, beta: T) -> Self {
+        let beta: f64 = beta.mixed_to_num();
+        return generate_window(len, true, |n, size| kaiser(beta, n, size));
+    }
+
+    /// Generate a symmetric generalized cosine-sum window function, `w[n] = Σ(-1)ᵏaₖcos(2πkn/(N-1))`.
+    /// 
+    /// The Hann, Hamming, Blackman, Nuttall, Blackman-Harris and flat-top windows are all cosine-sum windows.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`          - The length of the window.
+    /// * `coefficients` - The coefficients `aₖ`, from `a₀`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// // The Hamming window.
+    /// let window = Vec::<f64>::cosine_sum(64, &[25f64/46f64, 21f64/46f64]);
+    /// let reference = Vec::<f64>::hamming(64);
+    /// 
+    /// for idx in 0..64
+    /// {
+    ///     assert!( (window[idx]-reference[idx]).abs() < 1e-12 );
+    /// }
+    /// ```
+    pub fn cosine_sum(len: usize, coefficients: &[T]) -> Self {
+        let coefficients = coefficients_to_f64(coefficients);
+        return generate_window(len, false, |n, size| cosine_sum(&coefficients, n, size));
+    }
+
+    /// Generate a periodic generalized cosine-sum window function, `w[n] = Σ(-1)ᵏaₖcos(2πkn/N)`.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`          - The length of the window.
+    /// * `coefficients` - The coefficients `aₖ`, from `a₀`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// // The periodic Hann window sums to a constant when overlapped by half its length.
+    /// let window = Vec::<f64>::cosine_sum_periodic(64, &[0.5f64, 0.5f64]);
+    /// 
+    /// for idx in 0..32
+    /// {
+    ///     assert!( (window[idx]+window[idx+32]-1f64).abs() < 1e-12 );
+    /// }
+    /// ```
+    pub fn cosine_sum_periodic(len: usize, coefficients: &[T]) -> Self {
+        let coefficients = coefficients_to_f64(coefficients);
+        return generate_window(len, true, |n, size| cosine_sum(&coefficients, n, size));
+    }
+
+    /// Generate a symmetric Hann window function, `w[n] = 0.5 - 0.5cos(2πn/(N-1))`.
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::Cartesian;
+    /// 
+    /// let mut vec = Vec::<f32>::hann(512);
+    /// 
+    /// vec.simple_plot("./figures/hann_test.png", "Hann Window Function");
+    /// 
+    /// let c_vec = Vec::<Cartesian<f32>>::new_from_real(vec);
+    /// c_vec.plot_psd( 1f32, -180f32, "./figures/hann_psd_test.png", "Hann Window Function" );
+    /// ```
+    /// 
+    /// The resulting plots are shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/hann_test.png)
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/hann_psd_test.png)
+    pub fn hann(len: usize) -> Self {
+        return generate_window(len, false, |n, size| cosine_sum(&[0.5f64, 0.5f64], n, size));
+    }
+
+    /// Generate a periodic Hann window function, `w[n] = 0.5 - 0.5cos(2πn/N)`.
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::hann_periodic(4);
+    /// 
+    /// assert_eq!( window[0], 0f64 );
+    /// assert!( (window[1]-0.5f64).abs() < 1e-15 );
+    /// assert_eq!( window[2], 1f64 );
+    /// ```
+    pub fn hann_periodic(len: usize) -> Self {
+        return generate_window(len, true, |n, size| cosine_sum(&[0.5f64, 0.5f64], n, size));
+    }
+
+    /// Generate a symmetric 5-term flat-top window function.
+    /// 
+    /// The pass band of the window is flat within 0.01 dB, such that the amplitude of a tone is measured accurately between DFT bins.
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::Cartesian;
+    /// 
+    /// let mut vec = Vec::<f32>::flat_top(512);
+    /// 
+    /// vec.simple_plot("./figures/flat_top_test.png", "Flat-Top Window Function");
+    /// 
+    /// let c_vec = Vec::<Cartesian<f32>>::new_from_real(vec);
+    /// c_vec.plot_psd( 1f32, -180f32, "./figures/flat_top_psd_test.png", "Flat-Top Window Function" );
+    /// ```
+    /// 
+    /// The resulting plots are shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/flat_top_test.png)
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/flat_top_psd_test.png)
+    pub fn flat_top(len: usize) -> Self {
+        return generate_window(len, false, |n, size| cosine_sum(&FLAT_TOP, n, size));
+    }
+
+    /// Generate a periodic 5-term flat-top window function, see [`Vec::flat_top`].
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::flat_top_periodic(64);
+    /// 
+    /// assert!( (window[32]-1f64).abs() < 1e-8 );
+    /// assert!( (window[1]-window[63]).abs() < 1e-15 );
+    /// ```
+    pub fn flat_top_periodic(len: usize) -> Self {
+        return generate_window(len, true, |n, size| cosine_sum(&FLAT_TOP, n, size));
+    }
+
+    /// Generate a symmetric 4-term Blackman-Harris window function.
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::Cartesian;
+    /// 
+    /// let mut vec = Vec::<f32>::blackman_harris(512);
+    /// 
+    /// vec.simple_plot("./figures/blackman_harris_test.png", "Blackman-Harris Window Function");
+    /// 
+    /// let c_vec = Vec::<Cartesian<f32>>::new_from_real(vec);
+    /// c_vec.plot_psd( 1f32, -180f32, "./figures/blackman_harris_psd_test.png", "Blackman-Harris Window Function" );
+    /// ```
+    /// 
+    /// The resulting plots are shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/blackman_harris_test.png)
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/blackman_harris_psd_test.png)
+    pub fn blackman_harris(len: usize) -> Self {
+        return generate_window(len, false, |n, size| cosine_sum(&BLACKMAN_HARRIS, n, size));
+    }
+
+    /// Generate a periodic 4-term Blackman-Harris window function, see [`Vec::blackman_harris`].
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::blackman_harris_periodic(64);
+    /// 
+    /// assert!( (window[0]-6e-5f64).abs() < 1e-12 );
+    /// assert!( (window[32]-1f64).abs() < 1e-12 );
+    /// ```
+    pub fn blackman_harris_periodic(len: usize) -> Self {
+        return generate_window(len, true, |n, size| cosine_sum(&BLACKMAN_HARRIS, n, size));
+    }
+
+    /// Generate a symmetric Tukey (tapered cosine) window function.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`   - The length of the window.
+    /// * `alpha` - The fraction of the window inside the cosine tapers, between 0 and 1. Zero yields a rectangular window, and one a Hann window.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let mut vec = Vec::<f32>::tukey(512, 0.5f32);
+    /// 
+    /// vec.simple_plot("./figures/tukey_test.png", "Tukey Window Function");
+    /// 
+    /// assert_eq!( vec[0], 0f32 );
+    /// assert_eq!( vec[128], 1f32 );
+    /// assert_eq!( vec[383], 1f32 );
+    /// ```
+    /// 
+    /// The resulting plot is shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/tukey_test.png)
+    pub fn tukey(len: usize, alpha: T) -> Self {
+        let alpha: f64 = alpha.mixed_to_num();
+        return generate_window(len, false, |n, size| tukey(alpha, n, size));
+    }
+
+    /// Generate a periodic Tukey (tapered cosine) window function, see [`Vec::tukey`].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`   - The length of the window.
+    /// * `alpha` - The fraction of the window inside the cosine tapers, between 0 and 1.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::tukey_periodic(16, 1f64);
+    /// let reference = Vec::<f64>::hann_periodic(16);
+    /// 
+    /// for idx in 0..16
+    /// {
+    ///     assert!( (window[idx]-reference[idx]).abs() < 1e-15 );
+    /// }
+    /// ```
+    pub fn tukey_periodic(len: usize, alpha: T) -> Self {
+        let alpha: f64 = alpha.mixed_to_num();
+        return generate_window(len, true, |n, size| tukey(alpha, n, size));
+    }
+
+    /// Generate a symmetric Gaussian window function, `w[n] = exp(-½((n-(N-1)/2)/σ)²)`.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`   - The length of the window.
+    /// * `sigma` - The standard deviation in samples.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::Cartesian;
+    /// 
+    /// let mut vec = Vec::<f32>::gaussian(512, 64f32);
+    /// 
+    /// vec.simple_plot("./figures/gaussian_test.png", "Gaussian Window Function");
+    /// 
+    /// let c_vec = Vec::<Cartesian<f32>>::new_from_real(vec);
+    /// c_vec.plot_psd( 1f32, -180f32, "./figures/gaussian_psd_test.png", "Gaussian Window Function" );
+    /// ```
+    /// 
+    /// The resulting plots are shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/gaussian_test.png)
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/gaussian_psd_test.png)
+    pub fn gaussian(len: usize, sigma: T) -> Self {
+        let sigma: f64 = sigma.mixed_to_num();
+        return generate_window(len, false, |n, size| gaussian(sigma, n, size));
+    }
+
+    /// Generate a periodic Gaussian window function, `w[n] = exp(-½((n-N/2)/σ)²)`.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`   - The length of the window.
+    /// * `sigma` - The standard deviation in samples.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::gaussian_periodic(8, 2f64);
+    /// 
+    /// assert_eq!( window[4], 1f64 );
+    /// assert!( (window[0]-(-2f64).exp()).abs() < 1e-15 );
+    /// ```
+    pub fn gaussian_periodic(len: usize, sigma: T) -> Self {
+        let sigma: f64 = sigma.mixed_to_num();
+        return generate_window(len, true, |n, size| gaussian(sigma, n, size));
+    }
+
+    /// Generate a symmetric Dolph-Chebyshev window function.
+    /// 
+    /// All sidelobes of the window are at the specified attenuation below the main lobe,
+    /// which is the narrowest main lobe possible for that sidelobe level.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`            - The length of the window.
+    /// * `attenuation_db` - The sidelobe attenuation in dB, positive.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// use mixed_num::Cartesian;
+    /// 
+    /// let mut vec = Vec::<f32>::chebyshev(512, 100f32);
+    /// 
+    /// vec.simple_plot("./figures/chebyshev_test.png", "Dolph-Chebyshev Window Function");
+    /// 
+    /// let c_vec = Vec::<Cartesian<f32>>::new_from_real(vec);
+    /// c_vec.plot_psd( 1f32, -180f32, "./figures/chebyshev_psd_test.png", "Dolph-Chebyshev Window Function" );
+    /// ```
+    /// 
+    /// The resulting plots are shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/chebyshev_test.png)
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/chebyshev_psd_test.png)
+    pub fn chebyshev(len: usize, attenuation_db: T) -> Self {
+        let window = dolph_chebyshev(len, attenuation_db.mixed_to_num());
+        return generate_window(len, false, |n, _| window[n as usize]);
+    }
+
+    /// Generate a periodic Dolph-Chebyshev window function, see [`Vec::chebyshev`].
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`            - The length of the window.
+    /// * `attenuation_db` - The sidelobe attenuation in dB, positive.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let window = Vec::<f64>::chebyshev_periodic(64, 80f64);
+    /// 
+    /// assert!( (window[32]-1f64).abs() < 1e-12 );
+    /// assert!( (window[1]-window[63]).abs() < 1e-12 );
+    /// ```
+    pub fn chebyshev_periodic(len: usize, attenuation_db: T) -> Self {
+        let window = dolph_chebyshev(len+1, attenuation_db.mixed_to_num());
+        return generate_window(len, true, |n, _| window[n as usize]);
+    }
+
+    /// Generate discrete prolate spheroidal sequences (DPSS), or Slepian tapers.
+    /// 
+    /// The tapers are orthonormal, and the `k`th taper has the `k`th largest concentration of energy within the half bandwidth `NW/N`,
+    /// among sequences orthogonal to the previous tapers. The first `2NW-1` or so tapers are well concentrated.
+    /// They are computed as eigenvectors of a tridiagonal matrix, by bisection and inverse iteration.
+    /// 
+    /// Even tapers are symmetric with a positive sum, and odd tapers are antisymmetric and start with a positive lobe.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `len`        - The length of each taper.
+    /// * `nw`         - The time half bandwidth product `NW`, where `W` is the half bandwidth normalized to the sample rate.
+    /// * `num_tapers` - The number of tapers, at most `len`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use ndsp::*;
+    /// 
+    /// let tapers = Vec::<f64>::dpss(256, 4f64, 7);
+    /// 
+    /// assert_eq!( tapers.len(), 7 );
+    /// assert!( ((&tapers[0]*&tapers[0]).sum()-1f64).abs() < 1e-12 );
+    /// assert!( (&tapers[0]*&tapers[1]).sum().abs() < 1e-12 );
+    /// 
+    /// let tapers = Vec::<f32>::dpss(256, 2.5f32, 3);
+    /// let x_vec = Vec::lin_range(0f32, 255f32, 256);
+    /// x_vec.plot_multiple(&[&tapers[0], &tapers[1], &tapers[2]], "./figures/dpss_test.png", "DPSS Window Functions", "n [idx]", "w", &["k=0", "k=1", "k=2"] );
+    /// ```
+    /// 
+    /// The resulting plot is shown below.
+    /// 
+    /// ![Alt version](https://raw.githubusercontent.com/norsk-datateknikk/NDSP/main/figures/dpss_test.png)
+    pub fn dpss(len: usize, nw: T, num_tapers: usize) -> Vec<Vec<T>> {
+        if num_tapers > len
+        {
+            core::panic!("The number of tapers can not exceed the length of the tapers.");
+        }
+        let nw: f64 = nw.mixed_to_num();
+        if nw <= 0f64 || (len as f64)/2f64 <= nw
+        {
+            core::panic!("The time half bandwidth product must lie between 0 and half the length.");
+        }
+
+        let tapers = dpss(len, nw/(len as f64), num_tapers);
+        let mut r_vec = Vec::<Vec<T>>::new_with_capacity(num_tapers);
+        for taper in tapers
+        {
+            r_vec.push_back(generate_window(len, false, |n, _| taper[n as usize]));
+        }
+        return r_vec;
+    }
+}
+
+/// The modified Bessel function of the first kind, of order zero, by its power series.
+pub(crate) fn bessel_i0( x: f64 ) -> f64
+{
+    let half_x = x/2f64;
+    let mut term = 1f64;
+    let mut sum  = 1f64;
+    let mut k = 1f64;
+    while sum*1e-17 < term
+    {
+        term = term*(half_x/k)*(half_x/k);
+        sum  = sum+term;
+        k    = k+1f64;
+    }
+    return sum;
+}
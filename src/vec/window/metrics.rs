@@ -0,0 +1,208 @@
+//----------------------//
+// Norsk Datateknikk AS //
+//----------------------//
+
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::assign_op_pattern)]
+
+extern crate alloc;
+
+use mixed_num::*;
+
+use crate::vec::*;
+
+/// The number of points per DFT bin on which the response of a window is evaluated.
+const POINTS_PER_BIN: usize = 16;
+
+/// Figures of merit of a window function, see [`Vec::window_metrics`].
+///
+/// Frequencies are in DFT bins, i.e. normalized to the sample rate divided by the length of the window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowMetrics<T> {
+    /// The gain of a tone centered on a bin, relative to a rectangular window, `Σw/N`.
+    pub coherent_gain: T,
+    /// The equivalent noise bandwidth in bins, `NΣw²/(Σw)²`.
+    /// The width of a rectangular filter with the same peak gain, which passes the same noise power.
+    pub enbw_bins: T,
+    /// The gain in signal to noise ratio of a tone centered on a bin, over a single sample, in dB, `10log10((Σw)²/Σw²)`.
+    pub processing_gain_db: T,
+    /// The loss of a tone halfway between two bins, relative to a tone centered on a bin, in dB, positive.
+    pub scalloping_loss_db: T,
+    /// The level of the highest sidelobe relative to the main lobe, in dB, negative.
+    /// The level at the Nyquist frequency if the response has no sidelobes.
+    pub highest_sidelobe_db: T,
+    /// The width of the main lobe where the response is 3 dB below its peak, in bins.
+    pub bandwidth_3db_bins: T,
+    /// The width of the main lobe where the response is 6 dB below its peak, in bins.
+    pub bandwidth_6db_bins: T,
+}
+
+/// The magnitude of the response of a window at a frequency in bins.
+fn amplitude( window: &[f64], bins: f64 ) -> f64
+{
+    let omega = 2f64*core::f64::consts::PI*bins/(window.len() as f64);
+    let mut re = 0f64;
+    let mut im = 0f64;
+    for n in 0..window.len()
+    {
+        let (sin, cos) = (omega*(n as f64)).mixed_sincos();
+        re = re + window[n]*cos;
+        im = im - window[n]*sin;
+    }
+    return (re*re + im*im).mixed_sqrt();
+}
+
+/// The full width of the main lobe where the amplitude falls to `level` of its peak, by bisection after the first grid point below.
+fn main_lobe_width( window: &[f64], grid: &[f64], peak: f64, level: f64 ) -> f64
+{
+    let target = peak*level;
+    for k in 1..grid.len()
+    {
+        if grid[k] < target
+        {
+            let step = 1f64/(POINTS_PER_BIN as f64);
+            let mut low = ((k-1) as f64)*step;
+            let mut high = (k as f64)*step;
+            for _ in 0..50
+            {
+                let middle = (low+high)/2f64;
+                if amplitude(window, middle) < target
+                {
+                    high = middle;
+                }
+                else
+                {
+                    low = middle;
+                }
+            }
+            return low+high;
+        }
+    }
+    // The response does not fall to the level below the Nyquist frequency.
+    return window.len() as f64;
+}
+
+impl <T: MixedReal> Vec<T>
+{
+    /// Compute the figures of merit of a window, such as its coherent gain, equivalent noise bandwidth and highest sidelobe.
+    ///
+    /// The response is evaluated from DC to the Nyquist frequency with 16 points per bin, using an FFT of the window zero padded to 16 times its length.
+    /// The band edges and the peak of the highest sidelobe are refined from there by evaluating the response directly.
+    /// The main lobe ends at the first local minimum of the response.
+    ///
+    /// The coherent gain scales the amplitude of tones, while the equivalent noise bandwidth scales the power of noise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndsp::*;
+    ///
+    /// let metrics = Vec::<f64>::hann_periodic(1024).window_metrics();
+    ///
+    /// assert!( (metrics.coherent_gain-0.5f64).abs() < 1e-12 );
+    /// assert!( (metrics.enbw_bins-1.5f64).abs() < 1e-12 );
+    /// assert!( (metrics.scalloping_loss_db-1.42f64).abs() < 0.01 );
+    /// assert!( (metrics.highest_sidelobe_db+31.47f64).abs() < 0.01 );
+    /// assert!( (metrics.bandwidth_3db_bins-1.44f64).abs() < 0.01 );
+    /// assert!( (metrics.bandwidth_6db_bins-2.00f64).abs() < 0.01 );
+    /// ```
+    pub fn window_metrics( &self ) -> WindowMetrics<T>
+    {
+        let len = self.len();
+        if len == 0
+        {
+            core::panic!("The window must have at least one sample.");
+        }
+
+        let mut window = alloc::vec::Vec::<f64>::with_capacity(len);
+        let mut sum = 0f64;
+        let mut energy = 0f64;
+        for idx in 0..len
+        {
+            let w: f64 = self[idx].mixed_to_num();
+            window.push(w);
+            sum = sum + w;
+            energy = energy + w*w;
+        }
+        if sum == 0f64
+        {
+            core::panic!("The window must have a non-zero sum.");
+        }
+
+        let coherent_gain = sum/(len as f64);
+        let enbw_bins = (len as f64)*energy/(sum*sum);
+        let processing_gain_db = 10f64*(sum*sum/energy).mixed_log10();
+
+        let peak = amplitude(&window, 0f64);
+        let scalloping_loss_db = -20f64*(amplitude(&window, 0.5f64)/peak).mixed_log10();
+
+        // The response from DC to the Nyquist frequency, at N/2 bins, from the FFT of the window zero padded to 16N samples.
+        // The FFT is scaled by 1/16N.
+        let fft_len = len*POINTS_PER_BIN;
+        let mut padded = window.clone();
+        padded.resize(fft_len, 0f64);
+        let spectrum = Vec::new_from_vec(padded).rfft();
+
+        let num_points = len*POINTS_PER_BIN/2 + 1;
+        let step = 1f64/(POINTS_PER_BIN as f64);
+        let mut grid = alloc::vec::Vec::<f64>::with_capacity(num_points);
+        for k in 0..num_points
+        {
+            grid.push((fft_len as f64)*(spectrum[k].re*spectrum[k].re + spectrum[k].im*spectrum[k].im).mixed_sqrt());
+        }
+
+        let mut main_lobe_end = num_points-1;
+        for k in 1..num_points
+        {
+            if grid[k-1] < grid[k]
+            {
+                main_lobe_end = k-1;
+                break;
+            }
+        }
+
+        let mut highest_sidelobe = grid[num_points-1];
+        let mut highest_idx = num_points-1;
+        for k in main_lobe_end..num_points
+        {
+            if highest_sidelobe < grid[k]
+            {
+                highest_sidelobe = grid[k];
+                highest_idx = k;
+            }
+        }
+        if main_lobe_end < highest_idx && highest_idx < num_points-1
+        {
+            // Refine the peak by ternary search between the neighbouring grid points.
+            let mut low = ((highest_idx-1) as f64)*step;
+            let mut high = ((highest_idx+1) as f64)*step;
+            for _ in 0..60
+            {
+                let a = low + (high-low)/3f64;
+                let b = high - (high-low)/3f64;
+                if amplitude(&window, a) < amplitude(&window, b)
+                {
+                    low = a;
+                }
+                else
+                {
+                    high = b;
+                }
+            }
+            highest_sidelobe = highest_sidelobe.max(amplitude(&window, (low+high)/2f64));
+        }
+        let highest_sidelobe_db = 20f64*(highest_sidelobe.max(1e-20*peak)/peak).mixed_log10();
+
+        let bandwidth_3db_bins = main_lobe_width(&window, &grid, peak, 10f64.mixed_pow(-3f64/20f64));
+        let bandwidth_6db_bins = main_lobe_width(&window, &grid, peak, 10f64.mixed_pow(-6f64/20f64));
+
+        return WindowMetrics {
+            coherent_gain:       T::mixed_from_num(coherent_gain),
+            enbw_bins:           T::mixed_from_num(enbw_bins),
+            processing_gain_db:  T::mixed_from_num(processing_gain_db),
+            scalloping_loss_db:  T::mixed_from_num(scalloping_loss_db),
+            highest_sidelobe_db: T::mixed_from_num(highest_sidelobe_db),
+            bandwidth_3db_bins:  T::mixed_from_num(bandwidth_3db_bins),
+            bandwidth_6db_bins:  T::mixed_from_num(bandwidth_6db_bins),
+        };
+    }
+}
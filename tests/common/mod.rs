@@ -0,0 +1,66 @@
+// Each test crate uses a subset of the helpers.
+#![allow(dead_code)]
+// Cartesian<T> compound assignment is avoided, as `+=` is not equivalent to `+` in mixed_num.
+#![allow(clippy::assign_op_pattern)]
+
+use ndsp::*;
+use mixed_num::*;
+
+/// Unscaled reference DFT.
+pub fn dft( input: &Vec<Cartesian<f64>>, direction: FftDirection ) -> Vec<Cartesian<f64>>
+{
+    let n = input.len();
+    let sign = match direction {
+        FftDirection::Forward  => -1f64,
+        FftDirection::Backward =>  1f64,
+    };
+    let mut output = Vec::<Cartesian<f64>>::new_with_capacity(n);
+    for k in 0..n
+    {
+        let mut acc = Cartesian::new(0f64, 0f64);
+        for idx in 0..n
+        {
+            let angle = sign*f64::mixed_tau()*(((idx*k)%n) as f64)/(n as f64);
+            acc = acc + input[idx]*Cartesian::new(angle.cos(), angle.sin());
+        }
+        output.push_back(acc);
+    }
+    output
+}
+
+/// A deterministic real signal of two tones and a sawtooth, starting `seed` samples in.
+pub fn test_signal( n: usize, seed: f64 ) -> Vec<f64>
+{
+    let mut signal = Vec::<f64>::new_with_capacity(n);
+    for idx in 0..n
+    {
+        let x = idx as f64 + seed;
+        signal.push_back((0.3*x).sin() + 0.5*(1.7*x).cos() + 0.1*(x%7.0));
+    }
+    signal
+}
+
+/// A deterministic complex signal, with the real and imaginary parts from [`test_signal`] at different offsets.
+pub fn test_complex_signal( n: usize, seed: f64 ) -> Vec<Cartesian<f64>>
+{
+    let re = test_signal(n, seed);
+    let im = test_signal(n, seed+100f64);
+    let mut signal = Vec::<Cartesian<f64>>::new_with_capacity(n);
+    for idx in 0..n
+    {
+        signal.push_back(Cartesian::new(re[idx], im[idx]));
+    }
+    signal
+}
+
+/// Uniform noise on `[-1, 1)`, from the crate's generator with the given seed.
+pub fn uniform_noise( n: usize, seed: u64 ) -> Vec<f64>
+{
+    let mut rng = Prng::new(seed);
+    let mut signal = Vec::<f64>::new_with_capacity(n);
+    for _ in 0..n
+    {
+        signal.push_back(2f64*rng.uniform() - 1f64);
+    }
+    signal
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+/// The full convolution by the definition.
+fn reference( x: &Vec<Cartesian<f64>>, h: &Vec<Cartesian<f64>> ) -> std::vec::Vec<Cartesian<f64>>
+{
+    let mut y = vec![Cartesian::new(0f64, 0f64); x.len()+h.len()-1];
+    for (i, a) in x.to_alloc_vec().iter().enumerate()
+    {
+        for (j, b) in h.to_alloc_vec().iter().enumerate()
+        {
+            y[i+j] = Cartesian::new(y[i+j].re + a.re*b.re - a.im*b.im, y[i+j].im + a.re*b.im + a.im*b.re);
+        }
+    }
+    y
+}
+
+#[test]
+fn direct_and_fft_convolution_match_the_definition()
+{
+    for (n, m) in [(1, 1), (10, 3), (3, 10), (200, 65), (65, 200), (1000, 100), (129, 129), (5000, 70)]
+    {
+        let x = test_complex_signal(n, 0f64);
+        let h = test_complex_signal(m, 3f64);
+        let full = reference(&x, &h);
+
+        for (mode, first, len) in [(ConvolutionMode::Full, 0, n+m-1), (ConvolutionMode::Same, (n.min(m)-1)/2, n.max(m)), (ConvolutionMode::Valid, n.min(m)-1, n.max(m)-n.min(m)+1)]
+        {
+            let y = x.convolve(&h, mode);
+            assert_eq!( y.len(), len );
+            for idx in 0..len
+            {
+                assert!( (y[idx].re-full[first+idx].re).abs() < 1e-9, "n = {}, m = {}, {:?}", n, m, mode );
+                assert!( (y[idx].im-full[first+idx].im).abs() < 1e-9, "n = {}, m = {}, {:?}", n, m, mode );
+            }
+
+            let real = x.re().convolve(&h.re(), mode);
+            let real_full = reference(&Vec::new_from_real(x.re()), &Vec::new_from_real(h.re()));
+            for idx in 0..len
+            {
+                assert!( (real[idx]-real_full[first+idx].re).abs() < 1e-9, "n = {}, m = {}, {:?}", n, m, mode );
+            }
+        }
+    }
+}
+
+#[test]
+fn correlation_finds_the_delay()
+{
+    for template_len in [16, 100]
+    {
+        let template = test_complex_signal(template_len, 0f64);
+        let delay = 321;
+
+        let mut signal = Vec::<Cartesian<f64>>::new_with_capacity(1000);
+        for idx in 0..1000
+        {
+            let x = if (delay..delay+template_len).contains(&idx) { template[idx-delay] } else { Cartesian::new(0f64, 0f64) };
+            signal.push_back(x);
+        }
+
+        let y = signal.correlate(&template, ConvolutionMode::Valid);
+        let mut best = 0;
+        for idx in 0..y.len()
+        {
+            if y[best].re < y[idx].re
+            {
+                best = idx;
+            }
+        }
+        assert_eq!( best, delay );
+
+        // At the delay, the correlation is the energy of the template.
+        let mut energy = 0f64;
+        for idx in 0..template_len
+        {
+            energy += template[idx].re*template[idx].re + template[idx].im*template[idx].im;
+        }
+        assert!( (y[delay].re-energy).abs() < 1e-9 );
+        assert!( y[delay].im.abs() < 1e-9 );
+    }
+}
+
+#[test]
+fn overlap_save_streams_any_block_size()
+{
+    let kernel = test_signal(37, 1f64);
+    let signal = test_signal(3000, 2f64);
+    let full = signal.convolve(&kernel, ConvolutionMode::Full);
+
+    for block_size in [1, 7, 100, 1024]
+    {
+        let mut filter = OverlapSave::<f64>::new(&kernel);
+        assert_eq!( filter.block_len(), 256-36 );
+
+        let mut output = std::vec::Vec::<f64>::new();
+        let mut start = 0;
+        while start < signal.len()
+        {
+            let mut block = Vec::<f64>::new_with_capacity(block_size);
+            for idx in start..(start+block_size).min(signal.len())
+            {
+                block.push_back(signal[idx]);
+            }
+            let filtered = filter.process_real(&block);
+            assert_eq!( filtered.len()%filter.block_len(), 0 );
+            output.extend_from_slice(filtered.to_alloc_vec());
+            start += block_size;
+        }
+
+        assert_eq!( output.len(), (signal.len()/filter.block_len())*filter.block_len() );
+        for (idx, y) in output.iter().enumerate()
+        {
+            assert!( (y-full[idx]).abs() < 1e-9 );
+        }
+    }
+}
+
+#[test]
+fn overlap_save_reset_clears_history()
+{
+    let kernel = test_complex_signal(10, 0f64);
+    let signal = test_complex_signal(64, 1f64);
+
+    let mut filter = OverlapSave::<f64>::new_complex(&kernel);
+    let first = filter.process(&signal);
+    filter.reset();
+    let second = filter.process(&signal);
+
+    assert_eq!( first.len(), filter.block_len() );
+    for idx in 0..first.len()
+    {
+        assert_eq!( first[idx], second[idx] );
+    }
+}
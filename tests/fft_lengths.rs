@@ -0,0 +1,84 @@
+// Cartesian<T> compound assignment is avoided, as `+=` is not equivalent to `+` in mixed_num.
+#![allow(clippy::assign_op_pattern)]
+
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+fn max_error( a: &Vec<Cartesian<f64>>, b: &Vec<Cartesian<f64>> ) -> f64
+{
+    let mut error = 0f64;
+    for idx in 0..a.len()
+    {
+        error = error.max((a[idx].re-b[idx].re).abs()).max((a[idx].im-b[idx].im).abs());
+    }
+    error
+}
+
+#[test]
+fn fft_matches_dft_for_arbitrary_lengths()
+{
+    // Power of two, mixed radix and Bluestein lengths.
+    for n in [1usize, 2, 3, 5, 6, 7, 12, 13, 15, 16, 30, 45, 60, 97, 100, 120, 125, 243, 250, 251]
+    {
+        for direction in [FftDirection::Forward, FftDirection::Backward]
+        {
+            let signal = test_complex_signal(n, 0f64);
+            let mut spectrum = signal.clone();
+            FftPlan::new(n, direction).process_vec(&mut spectrum);
+
+            // The forward transform is scaled by 1/N.
+            let mut reference = dft(&signal, direction);
+            if direction == FftDirection::Forward
+            {
+                for k in 0..n
+                {
+                    reference[k] = reference[k]/(n as f64);
+                }
+            }
+            assert!( max_error(&spectrum, &reference) < 1e-9, "n = {}, direction = {:?}", n, direction );
+        }
+    }
+}
+
+#[test]
+fn fft_round_trip_lte_lengths()
+{
+    for n in [1000usize, 1536]
+    {
+        let signal = test_complex_signal(n, 0f64);
+        let mut buffer = signal.clone();
+        buffer.fft();
+        buffer.ifft();
+        assert!( max_error(&buffer, &signal) < 1e-9, "n = {}", n );
+    }
+}
+
+#[test]
+fn fft_fixed_point_mixed_radix_and_bluestein()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U24 as U;
+
+    for n in [12usize, 60, 13]
+    {
+        let signal = test_complex_signal(n, 0f64);
+        let reference = dft(&signal, FftDirection::Forward);
+
+        let mut buffer = Vec::<Cartesian<F<U>>>::new_with_capacity(n);
+        for idx in 0..n
+        {
+            buffer.push_back(Cartesian::new(F::<U>::from_num(signal[idx].re*0.5), F::<U>::from_num(signal[idx].im*0.5)));
+        }
+        buffer.fft();
+
+        for idx in 0..n
+        {
+            assert!( (buffer[idx].re.to_num::<f64>()*2.0-reference[idx].re/(n as f64)).abs() < 1e-3, "n = {}", n );
+            assert!( (buffer[idx].im.to_num::<f64>()*2.0-reference[idx].im/(n as f64)).abs() < 1e-3, "n = {}", n );
+        }
+    }
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+#[test]
+fn rfft_matches_complex_fft()
+{
+    for n in [1usize, 2, 3, 4, 7, 8, 12, 13, 16, 100, 101, 1000]
+    {
+        let signal = test_signal(n, 0f64);
+        let spectrum = signal.rfft();
+
+        let mut reference = Vec::<Cartesian<f64>>::new_from_real(signal.clone());
+        reference.fft();
+
+        assert_eq!( spectrum.len(), n/2+1 );
+        for k in 0..spectrum.len()
+        {
+            assert!( (spectrum[k].re-reference[k].re).abs() < 1e-12, "n = {}, k = {}", n, k );
+            assert!( (spectrum[k].im-reference[k].im).abs() < 1e-12, "n = {}, k = {}", n, k );
+        }
+
+        let restored = spectrum.irfft(n);
+        assert_eq!( restored.len(), n );
+        for idx in 0..n
+        {
+            assert!( (restored[idx]-signal[idx]).abs() < 1e-12, "n = {}, idx = {}", n, idx );
+        }
+    }
+}
+
+#[test]
+fn rfft_plans_are_reusable()
+{
+    for n in [1usize, 8, 13, 100]
+    {
+        let forward = RfftPlan::<f64>::new(n);
+        let backward = IrfftPlan::<f64>::new(n);
+        for block in 1..4
+        {
+            let mut signal = test_signal(n, 0f64);
+            signal *= block as f64;
+            let spectrum = forward.process(signal.to_alloc_vec());
+            assert_eq!( spectrum, signal.rfft() );
+            assert_eq!( backward.process(spectrum.to_alloc_vec()), spectrum.irfft(n) );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn rfft_plan_rejects_other_lengths()
+{
+    RfftPlan::<f64>::new(8).process(&[0f64; 7]);
+}
+
+#[test]
+fn rfft_fixed_point()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U24 as U;
+
+    let n = 64;
+    let signal = test_signal(n, 0f64);
+    let reference = signal.rfft();
+
+    let fixed_signal = Vec::<F<U>>::vec_from_num(&Vec::<f32>::vec_from_num(&signal));
+    let spectrum = fixed_signal.rfft();
+    for k in 0..spectrum.len()
+    {
+        assert!( (spectrum[k].re.to_num::<f64>()-reference[k].re).abs() < 1e-4 );
+        assert!( (spectrum[k].im.to_num::<f64>()-reference[k].im).abs() < 1e-4 );
+    }
+
+    let restored = spectrum.irfft(n);
+    for idx in 0..n
+    {
+        assert!( (restored[idx].to_num::<f64>()-signal[idx]).abs() < 1e-3 );
+    }
+}
+
+#[test]
+fn hilbert_odd_and_even_lengths()
+{
+    for n in [16usize, 15]
+    {
+        // Three whole periods of a cosine.
+        let mut phase = Vec::<f64>::new_with_capacity(n);
+        for idx in 0..n
+        {
+            phase.push_back(3.0*f64::mixed_tau()*(idx as f64)/(n as f64));
+        }
+        let mut signal = phase.clone();
+        signal.cos();
+
+        let mut analytic = Vec::<Cartesian<f64>>::zeros(n);
+        signal.hilbert(&mut analytic);
+
+        for idx in 0..n
+        {
+            assert!( (analytic[idx].re-phase[idx].cos()).abs() < 1e-12, "n = {}", n );
+            assert!( (analytic[idx].im-phase[idx].sin()).abs() < 1e-12, "n = {}", n );
+        }
+    }
+}
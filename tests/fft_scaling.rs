@@ -0,0 +1,118 @@
+// Cartesian<T> compound assignment is avoided, as `+=` is not equivalent to `+` in mixed_num.
+#![allow(clippy::assign_op_pattern)]
+
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+#[test]
+fn fft_scaling_policies()
+{
+    // Radix-2, mixed-radix and Bluestein lengths.
+    for n in [16usize, 12, 13]
+    {
+        for direction in [FftDirection::Forward, FftDirection::Backward]
+        {
+            let signal = test_complex_signal(n, 0f64);
+            let reference = dft(&signal, direction);
+
+            for (scaling, factor) in [(FftScaling::Unscaled, 1f64),
+                                      (FftScaling::OneOverN, 1f64/(n as f64)),
+                                      (FftScaling::OneOverSqrtN, 1f64/(n as f64).sqrt()),
+                                      (FftScaling::PerStage, 1f64/(n as f64)),
+                                      (FftScaling::BlockFloatingPoint, 1f64)]
+            {
+                let mut buffer = signal.clone();
+                let exponent = FftPlan::new_with_scaling(n, direction, scaling).process_vec(&mut buffer);
+                // Floating point types are never shifted.
+                assert_eq!( exponent, 0, "n = {}, scaling = {:?}", n, scaling );
+
+                let gain = 2f64.powi(exponent);
+                for k in 0..n
+                {
+                    assert!( (buffer[k].re*gain - reference[k].re*factor).abs() < 1e-9, "n = {}, scaling = {:?}", n, scaling );
+                    assert!( (buffer[k].im*gain - reference[k].im*factor).abs() < 1e-9, "n = {}, scaling = {:?}", n, scaling );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fft_block_floating_point_preserves_small_fixed_point_signals()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U24 as U;
+
+    let n = 1024;
+    let mut signal = test_complex_signal(n, 0f64);
+    for k in 0..n
+    {
+        signal[k] = signal[k]*1e-3;
+    }
+    let reference = dft(&signal, FftDirection::Forward);
+
+    let mut max_error = [0f64; 2];
+    for (idx, scaling) in [FftScaling::PerStage, FftScaling::BlockFloatingPoint].into_iter().enumerate()
+    {
+        let mut buffer = Vec::<Cartesian<F<U>>>::new_with_capacity(n);
+        for k in 0..n
+        {
+            buffer.push_back(Cartesian::new(F::<U>::from_num(signal[k].re), F::<U>::from_num(signal[k].im)));
+        }
+        let exponent = FftPlan::new_with_scaling(n, FftDirection::Forward, scaling).process_vec(&mut buffer);
+
+        let mut gain = 2f64.powi(exponent);
+        if scaling == FftScaling::PerStage
+        {
+            gain *= n as f64;
+        }
+        for k in 0..n
+        {
+            let error = (buffer[k].re.to_num::<f64>()*gain - reference[k].re).abs()
+                       +(buffer[k].im.to_num::<f64>()*gain - reference[k].im).abs();
+            max_error[idx] = max_error[idx].max(error);
+        }
+    }
+    // The unscaled transform fits the fixed point type, hence no shifts are needed.
+    assert!( max_error[1] < 1e-3 );
+    assert!( max_error[1]*10.0 < max_error[0] );
+}
+
+#[test]
+fn fft_bluestein_preserves_small_fixed_point_signals()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    // Radix-2 and Bluestein lengths, with a small tone on bin 1.
+    for n in [16usize, 13, 127]
+    {
+        let amplitude = 2f64.powi(-10);
+        let mut signal = Vec::<Cartesian<f64>>::new_with_capacity(n);
+        for idx in 0..n
+        {
+            let angle = f64::mixed_tau()*(idx as f64)/(n as f64);
+            signal.push_back(Cartesian::new(amplitude*angle.cos(), amplitude*angle.sin()));
+        }
+
+        for scaling in [FftScaling::BlockFloatingPoint, FftScaling::Unscaled]
+        {
+            let mut buffer = Vec::<Cartesian<F<U>>>::new_with_capacity(n);
+            for k in 0..n
+            {
+                buffer.push_back(Cartesian::new(F::<U>::from_num(signal[k].re), F::<U>::from_num(signal[k].im)));
+            }
+            let exponent = FftPlan::new_with_scaling(n, FftDirection::Forward, scaling).process_vec(&mut buffer);
+            assert_eq!( exponent, 0, "n = {}, scaling = {:?}", n, scaling );
+
+            // Within a few quantization steps of the ideal bin.
+            let expected = amplitude*(n as f64);
+            let relative_error = (buffer[1].re.to_num::<f64>()-expected).abs()/expected;
+            assert!( relative_error < 0.02, "n = {}, scaling = {:?}, relative error = {}", n, scaling, relative_error );
+        }
+    }
+}
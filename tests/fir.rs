@@ -0,0 +1,89 @@
+// Cartesian<T> compound assignment is avoided, as `+=` is not equivalent to `+` in mixed_num.
+#![allow(clippy::assign_op_pattern)]
+
+use ndsp::*;
+use mixed_num::Cartesian;
+
+/// Reference convolution, truncated to the length of the input.
+fn convolve( input: &[Cartesian<f64>], taps: &[Cartesian<f64>] ) -> std::vec::Vec<Cartesian<f64>>
+{
+    let mut output = std::vec::Vec::with_capacity(input.len());
+    for n in 0..input.len()
+    {
+        let mut acc = Cartesian::new(0f64, 0f64);
+        for k in 0..taps.len().min(n+1)
+        {
+            acc = acc + input[n-k]*taps[k];
+        }
+        output.push(acc);
+    }
+    output
+}
+
+#[test]
+fn fir_blocks_are_seamless()
+{
+    let mut taps = Vec::<Cartesian<f64>>::new_with_capacity(7);
+    for k in 0..7
+    {
+        taps.push_back(Cartesian::new(1.0/(k as f64+1.0), 0.1*k as f64 - 0.3));
+    }
+    let mut input = Vec::<Cartesian<f64>>::new_with_capacity(100);
+    for n in 0..100
+    {
+        input.push_back(Cartesian::new((0.2*n as f64).sin(), (n%5) as f64));
+    }
+    let reference = convolve(input.to_alloc_vec(), taps.to_alloc_vec());
+
+    let mut filter = Fir::<Cartesian<f64>, Cartesian<f64>>::new(taps);
+    let mut output = std::vec::Vec::new();
+    // Blocks both shorter and longer than the filter.
+    let mut start = 0;
+    for block_len in [1usize, 3, 7, 20, 2, 40, 27]
+    {
+        let block = Vec::new_from_vec(input.to_alloc_vec()[start..start+block_len].to_vec());
+        for value in filter.process(&block)
+        {
+            output.push(value);
+        }
+        start += block_len;
+    }
+
+    assert_eq!( output.len(), reference.len() );
+    for n in 0..output.len()
+    {
+        assert!( (output[n].re-reference[n].re).abs() < 1e-12, "n = {}", n );
+        assert!( (output[n].im-reference[n].im).abs() < 1e-12, "n = {}", n );
+    }
+
+    // The filter restarts from a zeroed state after a reset.
+    filter.reset();
+    let restarted = filter.process(&Vec::new_from_vec(input.to_alloc_vec()[0..10].to_vec()));
+    for n in 0..10
+    {
+        assert_eq!( restarted[n], output[n] );
+    }
+}
+
+#[test]
+fn fir_real_taps_on_complex_samples()
+{
+    let taps = Vec::new_from_vec(vec![0.25f64, -0.5, 1.0]);
+    let mut complex_filter = Fir::<Cartesian<f64>, f64>::new(taps.clone());
+    let mut real_filter = Fir::<f64, f64>::new(taps);
+
+    let mut input = Vec::<Cartesian<f64>>::new_with_capacity(16);
+    for n in 0..16
+    {
+        input.push_back(Cartesian::new(n as f64, -(n as f64)*0.5));
+    }
+    let mut output = input.clone();
+    complex_filter.process_in_place(&mut output);
+
+    let real_output = real_filter.process(&input.re());
+    for n in 0..16
+    {
+        assert_eq!( output[n].re, real_output[n] );
+        assert_eq!( output[n].im, -0.5*real_output[n] );
+    }
+}
@@ -0,0 +1,73 @@
+use ndsp::*;
+
+/// Magnitude of the frequency response at `f` cycles per sample.
+fn magnitude( taps: &Vec<f64>, f: f64 ) -> f64
+{
+    let mut re = 0f64;
+    let mut im = 0f64;
+    for n in 0..taps.len()
+    {
+        let phase = -2.0*std::f64::consts::PI*f*n as f64;
+        re += taps[n]*phase.cos();
+        im += taps[n]*phase.sin();
+    }
+    (re*re+im*im).sqrt()
+}
+
+/// Check the response against the ripple in the pass and stop bands, outside the transition bands.
+fn check_specification( band: FilterBand<f64>, passes: fn(f64) -> Option<bool> )
+{
+    let attenuation_db = 60f64;
+    let taps = Vec::fir_kaiser(band, 0.04, attenuation_db);
+
+    // The Kaiser estimates are not exact, and the ripple from the two edges of a band may add.
+    let ripple = 2.0*10f64.powf(-attenuation_db/20.0);
+    for k in 0..=1000
+    {
+        let f = 0.5*k as f64/1000.0;
+        match passes(f)
+        {
+            Some(true)  => assert!( (magnitude(&taps, f)-1.0).abs() < ripple, "{:?}, f = {}", band, f ),
+            Some(false) => assert!( magnitude(&taps, f) < ripple, "{:?}, f = {}", band, f ),
+            None => {},
+        }
+    }
+
+    // Linear phase.
+    for n in 0..taps.len()
+    {
+        assert!( (taps[n]-taps[taps.len()-1-n]).abs() < 1e-15 );
+    }
+}
+
+#[test]
+fn kaiser_designs_meet_specification()
+{
+    check_specification(FilterBand::Lowpass(0.1), |f| if f < 0.08 { Some(true) } else if 0.12 < f { Some(false) } else { None });
+    check_specification(FilterBand::Highpass(0.3), |f| if f < 0.28 { Some(false) } else if 0.32 < f { Some(true) } else { None });
+    check_specification(FilterBand::Bandpass(0.1, 0.3), |f| {
+        if (0.12..0.28).contains(&f) { Some(true) } else if !(0.08..=0.32).contains(&f) { Some(false) } else { None }
+    });
+    check_specification(FilterBand::Bandstop(0.1, 0.3), |f| {
+        if (0.12..0.28).contains(&f) { Some(false) } else if !(0.08..=0.32).contains(&f) { Some(true) } else { None }
+    });
+}
+
+#[test]
+fn windowed_sinc_any_window()
+{
+    for window in [Vec::<f64>::hamming(41), Vec::<f64>::blackman(41), Vec::<f64>::nuttall(41), Vec::<f64>::kaiser(41, 6.0)]
+    {
+        let taps = Vec::fir_windowed_sinc(FilterBand::Bandpass(0.15, 0.25), &window);
+        assert!( (magnitude(&taps, 0.2)-1.0).abs() < 1e-12 );
+        assert!( magnitude(&taps, 0.0) < 1e-2 );
+        assert!( magnitude(&taps, 0.45) < 1e-2 );
+    }
+}
+
+#[test]
+#[should_panic]
+fn windowed_sinc_highpass_even_length()
+{
+    Vec::fir_windowed_sinc(FilterBand::Highpass(0.2f64), &Vec::<f64>::hamming(40));
+}
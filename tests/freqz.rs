@@ -0,0 +1,99 @@
+use ndsp::*;
+
+/// Fourth order Butterworth lowpass with cutoff 0.1 of the sample rate, as two sections.
+const SOS: [[f64; 6]; 2] = [
+    [0.004824343357716228, 0.009648686715432456, 0.004824343357716228, 1.0, -1.0485995763626117, 0.2961403575616696],
+    [1.0, 2.0, 1.0, 1.0, -1.3209134308194261, 0.6327387928852763],
+];
+
+/// Multiply two polynomials in z^-1.
+fn convolve( a: &[f64], b: &[f64] ) -> std::vec::Vec<f64>
+{
+    let mut c = vec![0f64; a.len()+b.len()-1];
+    for (i, x) in a.iter().enumerate()
+    {
+        for (j, y) in b.iter().enumerate()
+        {
+            c[i+j] += x*y;
+        }
+    }
+    c
+}
+
+#[test]
+fn moving_average_matches_dirichlet_kernel()
+{
+    let len = 8;
+    let taps = Vec::new_from_vec( vec![1f64/(len as f64); len] );
+    // Off the zeros at multiples of 1/8.
+    let frequencies = Vec::lin_range( 0.01f64, 0.49f64, 50 );
+
+    let h = freqz( &taps, &Vec::new_from_vec( vec![1f64] ), &frequencies, 1f64 );
+
+    for idx in 0..frequencies.len()
+    {
+        let f = frequencies[idx];
+        let expected = (std::f64::consts::PI*f*(len as f64)).sin()/((len as f64)*(std::f64::consts::PI*f).sin());
+        let magnitude = (h.response[idx].re.powi(2) + h.response[idx].im.powi(2)).sqrt();
+        assert!( (magnitude-expected.abs()).abs() < 1e-12 );
+        assert!( (h.magnitude_db[idx]-20f64*expected.abs().log10()).abs() < 1e-9 );
+    }
+}
+
+#[test]
+fn linear_phase_has_constant_group_delay()
+{
+    let taps = Vec::fir_windowed_sinc( FilterBand::Lowpass(0.2f64), &Vec::<f64>::hamming(31) );
+    let frequencies = Vec::lin_range( 0f64, 0.15f64, 64 );
+
+    let h = freqz( &taps, &Vec::new_from_vec( vec![1f64] ), &frequencies, 1f64 );
+
+    for idx in 0..frequencies.len()
+    {
+        assert!( (h.group_delay[idx]-15f64).abs() < 1e-9 );
+        // The unwrapped phase is linear in the pass band.
+        let expected = -2f64*std::f64::consts::PI*frequencies[idx]*15f64;
+        assert!( (h.phase[idx]-expected).abs() < 1e-9 );
+    }
+}
+
+#[test]
+fn sections_match_expanded_polynomials()
+{
+    let b = convolve( &SOS[0][0..3], &SOS[1][0..3] );
+    let a = convolve( &SOS[0][3..6], &SOS[1][3..6] );
+    let frequencies = Vec::lin_range( 0f64, 4e3f64, 101 );
+
+    let h_sos = freqz_sos( &SOS, &frequencies, 8e3f64 );
+    let h_ba  = freqz( &Vec::new_from_vec(b), &Vec::new_from_vec(a), &frequencies, 8e3f64 );
+
+    for idx in 0..frequencies.len()
+    {
+        assert!( (h_sos.response[idx].re-h_ba.response[idx].re).abs() < 1e-12 );
+        assert!( (h_sos.response[idx].im-h_ba.response[idx].im).abs() < 1e-12 );
+        // The phase and group delay are undefined at the zeros on the Nyquist frequency.
+        if idx < 100
+        {
+            assert!( (h_sos.phase[idx]-h_ba.phase[idx]).abs() < 1e-9 );
+            assert!( (h_sos.group_delay[idx]-h_ba.group_delay[idx]).abs() < 1e-6 );
+        }
+    }
+
+    // Four poles and four zeros at the Nyquist frequency, a phase of -2π at the end of the grid.
+    assert!( (h_sos.phase[99]+2f64*std::f64::consts::PI).abs() < 0.1 );
+    assert!( h_sos.magnitude_db[100] < -300f64 );
+}
+
+#[test]
+fn group_delay_matches_phase_derivative()
+{
+    let frequencies = Vec::lin_range( 0.05f64, 0.15f64, 1001 );
+    let h = freqz_sos( &SOS, &frequencies, 1f64 );
+
+    let step = 2f64*std::f64::consts::PI*(frequencies[1]-frequencies[0]);
+    for idx in 1..frequencies.len()-1
+    {
+        let derivative = -(h.phase[idx+1]-h.phase[idx-1])/(2f64*step);
+        assert!( (h.group_delay[idx]-derivative).abs() < 1e-3 );
+    }
+}
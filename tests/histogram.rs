@@ -0,0 +1,88 @@
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+#[test]
+fn bin_rules() {
+    let x = uniform_noise(1000, 1);
+
+    // ⌈log₂1000⌉+1 = 11 bins.
+    let histogram = x.histogram(HistogramBins::Sturges);
+    assert_eq!(histogram.counts.len(), 11);
+    assert_eq!(histogram.total(), 1000);
+    assert_eq!(histogram.edges[0], x.min());
+    assert_eq!(histogram.edges[11], x.max());
+
+    // The IQR of a uniform distribution on [-1, 1) is one, for a width of 2/10 and 10 bins.
+    let histogram = x.histogram(HistogramBins::FreedmanDiaconis);
+    assert!((9..=11).contains(&histogram.counts.len()));
+    assert_eq!(histogram.total(), 1000);
+
+    // σ = 1/√3, for a width of 3.49/(√3·10) and 10 bins.
+    let histogram = x.histogram(HistogramBins::Scott);
+    assert!((9..=11).contains(&histogram.counts.len()));
+    assert_eq!(histogram.total(), 1000);
+}
+
+#[test]
+fn density_of_a_uniform_distribution() {
+    let x = uniform_noise(100000, 2);
+    let edges = Vec::lin_range(-1f64, 1f64, 11);
+    let histogram = x.histogram(HistogramBins::Edges(edges));
+
+    let density = histogram.density();
+    let centers = histogram.centers();
+    let cdf = histogram.cdf();
+    for idx in 0..10
+    {
+        assert!((density[idx]-0.5f64).abs() < 0.02);
+        assert!((centers[idx]-(-0.9f64+0.2f64*(idx as f64))).abs() < 1e-12);
+        assert!((cdf[idx]-0.1f64*((idx+1) as f64)).abs() < 0.01);
+    }
+    assert_eq!(cdf[9], 1f64);
+
+    let (values, ecdf) = x.ecdf();
+    assert_eq!(values[0], x.min());
+    assert_eq!(ecdf[ecdf.len()-1], 1f64);
+    assert!((ecdf[49999]-0.5f64).abs() < 1e-12);
+}
+
+#[test]
+fn values_outside_the_edges_are_not_counted() {
+    let x = Vec::new_from_vec(vec![-2f64, 0f64, 0.5f64, 1f64, 3f64]);
+    let histogram = x.histogram(HistogramBins::Edges(Vec::new_from_vec(vec![0f64, 0.5f64, 1f64])));
+
+    assert_eq!(histogram.counts.to_string(), "[ 1, 2 ]");
+    assert_eq!(histogram.total(), 3);
+}
+
+#[test]
+fn iq_density() {
+    let re = uniform_noise(10000, 3);
+    let im = uniform_noise(10000, 4);
+    let mut x = Vec::<Cartesian<f64>>::new_with_capacity(re.len());
+    for idx in 0..re.len()
+    {
+        x.push_back(Cartesian::new(re[idx], 0.5f64*im[idx]));
+    }
+
+    let edges = Vec::lin_range(-1f64, 1f64, 5);
+    let histogram = x.histogram_2d(HistogramBins::Edges(edges.clone()), HistogramBins::Edges(edges));
+
+    // The imaginary part only spans the two inner bins.
+    let mut total = 0;
+    for bin_re in 0..4
+    {
+        assert_eq!(histogram.counts[bin_re][0], 0);
+        assert_eq!(histogram.counts[bin_re][3], 0);
+        for bin_im in 1..3
+        {
+            assert!((histogram.counts[bin_re][bin_im] as f64 - 1250f64).abs() < 150f64);
+            total += histogram.counts[bin_re][bin_im];
+        }
+    }
+    assert_eq!(total, 10000);
+}
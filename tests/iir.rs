@@ -0,0 +1,127 @@
+use ndsp::*;
+use mixed_num::Cartesian;
+
+/// Fourth order Butterworth lowpass with cutoff 0.1 of the sample rate, as two sections.
+const SOS: [[f64; 6]; 2] = [
+    [0.004824343357716228, 0.009648686715432456, 0.004824343357716228, 1.0, -1.0485995763626117, 0.2961403575616696],
+    [1.0, 2.0, 1.0, 1.0, -1.3209134308194261, 0.6327387928852763],
+];
+
+/// Reference cascade of difference equations.
+fn reference( input: &[f64] ) -> std::vec::Vec<f64>
+{
+    let mut signal = input.to_vec();
+    for section in SOS
+    {
+        let mut output = vec![0f64; signal.len()];
+        for n in 0..signal.len()
+        {
+            let x = |k: usize| if k <= n { signal[n-k] } else { 0f64 };
+            let y = |k: usize, output: &[f64]| if k <= n { output[n-k] } else { 0f64 };
+            output[n] = (section[0]*x(0) + section[1]*x(1) + section[2]*x(2) - section[4]*y(1, &output) - section[5]*y(2, &output))/section[3];
+        }
+        signal = output;
+    }
+    signal
+}
+
+#[test]
+fn biquad_forms_match_difference_equation()
+{
+    let input: std::vec::Vec<f64> = (0..200).map(|n| (0.05*n as f64).sin() + if n%7 == 0 { 1.0 } else { -0.2 }).collect();
+    let expected = reference(&input);
+
+    for form in [BiquadForm::DirectForm1, BiquadForm::DirectForm2Transposed]
+    {
+        let mut filter = BiquadCascade::<f64, f64>::new(&SOS, form);
+        assert_eq!( filter.num_sections(), 2 );
+
+        // Blocks of varying length.
+        let mut output = std::vec::Vec::new();
+        let mut start = 0;
+        for block_len in [1usize, 2, 5, 50, 3, 139]
+        {
+            let mut block = Vec::new_from_vec(input[start..start+block_len].to_vec());
+            filter.process(&mut block);
+            output.extend_from_slice(block.to_alloc_vec());
+            start += block_len;
+        }
+
+        for n in 0..expected.len()
+        {
+            assert!( (output[n]-expected[n]).abs() < 1e-12, "{:?}, n = {}", form, n );
+        }
+
+        filter.reset();
+        assert_eq!( filter.process_sample(input[0]), output[0] );
+    }
+}
+
+#[test]
+fn biquad_complex_samples()
+{
+    let mut filter = BiquadCascade::<Cartesian<f64>, f64>::new(&SOS, BiquadForm::DirectForm2Transposed);
+
+    let re: std::vec::Vec<f64> = (0..64).map(|n| (n%9) as f64).collect();
+    let im: std::vec::Vec<f64> = (0..64).map(|n| -((n%4) as f64)).collect();
+
+    let mut buffer = Vec::<Cartesian<f64>>::new_with_capacity(64);
+    for n in 0..64
+    {
+        buffer.push_back(Cartesian::new(re[n], im[n]));
+    }
+    filter.process(&mut buffer);
+
+    let expected_re = reference(&re);
+    let expected_im = reference(&im);
+    for n in 0..64
+    {
+        assert!( (buffer[n].re-expected_re[n]).abs() < 1e-12 );
+        assert!( (buffer[n].im-expected_im[n]).abs() < 1e-12 );
+    }
+}
+
+#[test]
+fn biquad_fixed_point_coefficient_scaling()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U29 as U;
+
+    // Unnormalized a0, and b1 = 4 after normalization, which does not fit the range of the coefficient type, [-4, 4).
+    let sos = [[4.0*SOS[1][0], 4.0*SOS[1][1], 4.0*SOS[1][2], 2.0, 2.0*SOS[1][4], 2.0*SOS[1][5]]];
+
+    let input: std::vec::Vec<f64> = (0..100).map(|n| 0.1*((n%10) as f64)).collect();
+    let mut expected = Vec::new_from_vec(input.clone());
+    BiquadCascade::<f64, f64>::new(&sos, BiquadForm::DirectForm1).process(&mut expected);
+
+    for form in [BiquadForm::DirectForm1, BiquadForm::DirectForm2Transposed]
+    {
+        let mut filter = BiquadCascade::<F<U>, F<U>>::new(&sos, form);
+        let mut buffer = Vec::<F<U>>::new_with_capacity(100);
+        for value in &input
+        {
+            buffer.push_back(F::<U>::from_num(value/16.0));
+        }
+        filter.process(&mut buffer);
+
+        for n in 0..100
+        {
+            assert!( (buffer[n].to_num::<f64>()*16.0-expected[n]).abs() < 1e-5, "{:?}, n = {}", form, n );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn biquad_infinite_coefficient_panics()
+{
+    BiquadCascade::<f64, f64>::new(&[[1.0, f64::INFINITY, 1.0, 1.0, 0.5, 0.25]], BiquadForm::DirectForm1);
+}
+
+#[test]
+#[should_panic]
+fn biquad_subnormal_a0_panics()
+{
+    // The normalized coefficients overflow to infinity.
+    BiquadCascade::<f64, f64>::new(&[[1.0, 2.0, 1.0, 1e-320, 0.5, 0.25]], BiquadForm::DirectForm1);
+}
@@ -0,0 +1,169 @@
+use ndsp::*;
+
+/// Magnitude of the response of second-order sections at `f` cycles per sample.
+fn magnitude( sos: &[[f64; 6]], f: f64 ) -> f64
+{
+    let w = 2.0*std::f64::consts::PI*f;
+    let mut gain = 1f64;
+    for s in sos
+    {
+        let polynomial = |c0: f64, c1: f64, c2: f64| {
+            let re = c0 + c1*w.cos() + c2*(2.0*w).cos();
+            let im = -c1*w.sin() - c2*(2.0*w).sin();
+            (re*re+im*im).sqrt()
+        };
+        gain *= polynomial(s[0], s[1], s[2])/polynomial(s[3], s[4], s[5]);
+    }
+    gain
+}
+
+/// The maximum and minimum magnitude over `[lower, upper]`.
+fn extremes( sos: &[[f64; 6]], lower: f64, upper: f64 ) -> (f64, f64)
+{
+    let mut max = 0f64;
+    let mut min = f64::MAX;
+    for k in 0..=2000
+    {
+        let m = magnitude(sos, lower + (upper-lower)*k as f64/2000.0);
+        max = max.max(m);
+        min = min.min(m);
+    }
+    (max, min)
+}
+
+fn db2mag( db: f64 ) -> f64
+{
+    10f64.powf(db/20.0)
+}
+
+#[test]
+fn butterworth_transforms()
+{
+    let half_power = 0.5f64.sqrt();
+
+    for order in 1..=7
+    {
+        let sos = Zpk::<f64>::design(order, IirPrototype::Butterworth, FilterBand::Lowpass(0.1)).to_sos();
+        assert_eq!( sos.len(), order.div_ceil(2) );
+        assert!( (magnitude(&sos, 0.0)-1.0).abs() < 1e-12 );
+        assert!( (magnitude(&sos, 0.1)-half_power).abs() < 1e-12 );
+        assert!( magnitude(&sos, 0.5) < 1e-12 );
+
+        let sos = Zpk::<f64>::design(order, IirPrototype::Butterworth, FilterBand::Highpass(0.2)).to_sos();
+        assert!( magnitude(&sos, 0.0) < 1e-12 );
+        assert!( (magnitude(&sos, 0.2)-half_power).abs() < 1e-12 );
+        assert!( (magnitude(&sos, 0.5)-1.0).abs() < 1e-12 );
+    }
+
+    let sos = Zpk::<f64>::design(3, IirPrototype::Butterworth, FilterBand::Bandpass(0.1, 0.2)).to_sos();
+    assert_eq!( sos.len(), 3 );
+    assert!( (magnitude(&sos, 0.1)-half_power).abs() < 1e-10 );
+    assert!( (magnitude(&sos, 0.2)-half_power).abs() < 1e-10 );
+    assert!( (extremes(&sos, 0.1, 0.2).0-1.0).abs() < 1e-6 );
+
+    let sos = Zpk::<f64>::design(3, IirPrototype::Butterworth, FilterBand::Bandstop(0.1, 0.2)).to_sos();
+    assert!( (magnitude(&sos, 0.1)-half_power).abs() < 1e-10 );
+    assert!( (magnitude(&sos, 0.2)-half_power).abs() < 1e-10 );
+    assert!( (magnitude(&sos, 0.0)-1.0).abs() < 1e-10 );
+    assert!( (magnitude(&sos, 0.5)-1.0).abs() < 1e-10 );
+    assert!( extremes(&sos, 0.1, 0.2).1 < 1e-6 );
+}
+
+#[test]
+fn chebyshev_ripple()
+{
+    for order in [3usize, 4]
+    {
+        let sos = Zpk::<f64>::design(order, IirPrototype::Chebyshev1 { ripple_db: 1.0 }, FilterBand::Lowpass(0.15)).to_sos();
+        let (max, min) = extremes(&sos, 0.0, 0.15);
+        assert!( (max-1.0).abs() < 1e-6, "order = {}", order );
+        assert!( (min-db2mag(-1.0)).abs() < 1e-9, "order = {}", order );
+        assert!( (magnitude(&sos, 0.15)-db2mag(-1.0)).abs() < 1e-9 );
+
+        let sos = Zpk::<f64>::design(order, IirPrototype::Chebyshev2 { attenuation_db: 40.0 }, FilterBand::Lowpass(0.15)).to_sos();
+        let (max, _min) = extremes(&sos, 0.15, 0.5);
+        assert!( (max-db2mag(-40.0)).abs() < 1e-9, "order = {}", order );
+        assert!( (magnitude(&sos, 0.0)-1.0).abs() < 1e-9 );
+    }
+}
+
+#[test]
+fn elliptic_ripple()
+{
+    for order in [1usize, 2, 3, 4, 5]
+    {
+        let sos = Zpk::<f64>::design(order, IirPrototype::Elliptic { ripple_db: 0.5, attenuation_db: 50.0 }, FilterBand::Lowpass(0.1)).to_sos();
+
+        let (max, min) = extremes(&sos, 0.0, 0.1);
+        assert!( (max-1.0).abs() < 1e-6, "order = {}", order );
+        assert!( (min-db2mag(-0.5)).abs() < 1e-9, "order = {}", order );
+
+        // The stop band, from the first frequency reaching the attenuation, is equiripple at the attenuation.
+        if 1 < order
+        {
+            let mut edge = 0.1;
+            while db2mag(-50.0) < magnitude(&sos, edge)
+            {
+                edge += 1e-5;
+            }
+            let (max, _min) = extremes(&sos, edge, 0.5);
+            assert!( max < db2mag(-50.0)*(1.0+1e-6), "order = {}", order );
+            assert!( (max-db2mag(-50.0)).abs() < 1e-3*db2mag(-50.0), "order = {}", order );
+        }
+    }
+
+    // Elliptic highpass in single precision, filtering data.
+    let sos = Zpk::<f32>::design(4, IirPrototype::Elliptic { ripple_db: 0.1, attenuation_db: 60.0 }, FilterBand::Highpass(0.3)).to_sos();
+    let mut filter = BiquadCascade::<f32, f32>::new(&sos, BiquadForm::DirectForm2Transposed);
+    let mut signal = Vec::<f32>::ones(1000);
+    filter.process(&mut signal);
+    // The gain at DC of an even order elliptic filter is at the stop band attenuation.
+    assert!( (signal[999].abs() as f64-db2mag(-60.0)).abs() < 1e-5 );
+}
+
+#[test]
+fn prototype_building_blocks()
+{
+    // The design is the prewarped and transformed prototype.
+    let analog = Zpk::<f64>::chebyshev1(4, 0.5).bandstop(prewarp(0.2*0.3f64.sqrt(), 1.0), 0.3);
+    let digital = analog.bilinear(1.0);
+    assert_eq!( digital.zeros.len(), 8 );
+    assert_eq!( digital.poles.len(), 8 );
+
+    for idx in 0..digital.poles.len()
+    {
+        assert!( (digital.poles[idx].re.powi(2)+digital.poles[idx].im.powi(2)).sqrt() < 1.0 );
+    }
+    let sos = digital.to_sos();
+    assert_eq!( sos.len(), 4 );
+    let center = (prewarp(0.2*0.3f64.sqrt(), 1.0)/2.0).atan()/std::f64::consts::PI;
+    assert!( magnitude(&sos, center) < 1e-9 );
+}
+
+#[test]
+#[should_panic]
+fn elliptic_attenuation_not_above_ripple_panics()
+{
+    Zpk::<f64>::design(4, IirPrototype::Elliptic{ ripple_db: 1.0, attenuation_db: 1.0 }, FilterBand::Lowpass(0.1));
+}
+
+#[test]
+#[should_panic]
+fn elliptic_nan_ripple_panics()
+{
+    Zpk::<f64>::elliptic(4, f64::NAN, 40.0);
+}
+
+#[test]
+#[should_panic]
+fn chebyshev1_zero_ripple_panics()
+{
+    Zpk::<f64>::design(4, IirPrototype::Chebyshev1{ ripple_db: 0.0 }, FilterBand::Lowpass(0.1));
+}
+
+#[test]
+#[should_panic]
+fn chebyshev2_nan_attenuation_panics()
+{
+    Zpk::<f64>::chebyshev2(4, f64::NAN);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+/// Uniform noise of variance 1.
+fn noise( n: usize ) -> Vec<f64>
+{
+    let mut signal = uniform_noise(n, 0x2545F4914F6CDD1D);
+    signal *= 3f64.sqrt();
+    signal
+}
+
+#[test]
+fn white_noise_level_and_degrees_of_freedom()
+{
+    let n = 1024;
+    let sample_rate = 2e3f64;
+    let signal = noise(n);
+
+    let (_frequencies, psd, degrees_of_freedom) = signal.multitaper(4f64, 7, sample_rate, PsdSides::TwoSided);
+
+    let mut mean_psd = 0f64;
+    let mut mean_degrees_of_freedom = 0f64;
+    for k in 0..n
+    {
+        mean_psd += psd[k]/(n as f64);
+        mean_degrees_of_freedom += degrees_of_freedom[k]/(n as f64);
+        assert!( degrees_of_freedom[k] <= 14f64+1e-9 );
+    }
+    assert!( (mean_psd*sample_rate-1f64).abs() < 0.05 );
+    assert!( 12f64 < mean_degrees_of_freedom );
+}
+
+#[test]
+fn real_and_complex_estimates_agree()
+{
+    let n = 200;
+    let signal = noise(n);
+    let complex_signal = Vec::<Cartesian<f64>>::new_from_real(signal.clone());
+
+    let (frequencies, psd, degrees_of_freedom) = signal.multitaper(3f64, 5, 1f64, PsdSides::TwoSided);
+    let (complex_frequencies, complex_psd, complex_degrees_of_freedom) = complex_signal.multitaper(3f64, 5, 1f64, PsdSides::TwoSided);
+
+    for k in 0..n
+    {
+        assert_eq!( frequencies[k], complex_frequencies[k] );
+        assert!( (psd[k]-complex_psd[k]).abs() < 1e-9*psd[k] );
+        assert!( (degrees_of_freedom[k]-complex_degrees_of_freedom[k]).abs() < 1e-6 );
+    }
+
+    // The one-sided estimate folds the negative frequencies onto the positive ones.
+    let (_frequencies, one_sided, _degrees_of_freedom) = signal.multitaper(3f64, 5, 1f64, PsdSides::OneSided);
+    assert_eq!( one_sided.len(), n/2+1 );
+    assert_eq!( one_sided[0], psd[0] );
+    assert!( (one_sided[10]-2f64*psd[10]).abs() < 1e-12 );
+    assert_eq!( one_sided[n/2], psd[n/2] );
+}
+
+#[test]
+fn resolves_close_tones_in_short_captures()
+{
+    // Two complex tones 8 bins apart in 128 samples.
+    let n = 128;
+    let first = Vec::osc(f64::mixed_tau()*20f64/(n as f64), 0f64, n);
+    let second = Vec::osc(f64::mixed_tau()*28f64/(n as f64), 0f64, n);
+    let mut signal = Vec::<Cartesian<f64>>::new_with_capacity(n);
+    for idx in 0..n
+    {
+        signal.push_back(Cartesian::new(first[idx].re+second[idx].re, first[idx].im+second[idx].im));
+    }
+
+    let (_frequencies, psd, _degrees_of_freedom) = signal.multitaper(2f64, 3, 1f64, PsdSides::TwoSided);
+
+    assert!( psd[24] < 1e-3*psd[20] );
+    assert!( psd[24] < 1e-3*psd[28] );
+    assert!( (psd[20]-psd[28]).abs() < 1e-2*psd[20] );
+}
+
+#[test]
+#[should_panic]
+fn complex_one_sided_panics()
+{
+    let signal = Vec::<Cartesian<f64>>::new_from_real(noise(64));
+    let _estimate = signal.multitaper(2f64, 3, 1f64, PsdSides::OneSided);
+}
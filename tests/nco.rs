@@ -0,0 +1,115 @@
+use ndsp::*;
+use mixed_num::*;
+
+fn max_error( a: &Vec<Cartesian<f64>>, b: &Vec<Cartesian<f64>> ) -> f64
+{
+    let mut error = 0f64;
+    for idx in 0..a.len()
+    {
+        error = error.max((a[idx].re-b[idx].re).abs()).max((a[idx].im-b[idx].im).abs());
+    }
+    error
+}
+
+#[test]
+fn continuous_across_buffers() {
+    let omega = 0.123f64;
+    let mut nco = Nco::<f64>::new(omega, 0.4f64);
+    let whole = nco.generate(1000);
+
+    nco.reset();
+    let mut blocks = Vec::<Cartesian<f64>>::new_with_capacity(1000);
+    for len in [1, 7, 100, 256, 636]
+    {
+        let mut buffer = Vec::<Cartesian<f64>>::zeros(len);
+        nco.fill(&mut buffer);
+        for idx in 0..len
+        {
+            blocks.push_back(buffer[idx]);
+        }
+    }
+    assert_eq!(whole, blocks);
+
+    // The accumulator does not drift from the ideal phase.
+    let reference = Vec::osc(omega, 0.4f64, 1000);
+    assert!(max_error(&whole, &reference) < 1e-6);
+}
+
+#[test]
+fn retune_is_phase_continuous() {
+    let mut nco = Nco::<f64>::new(0.1f64, 0f64);
+    nco.generate(10);
+    assert!((nco.phase()-1f64).abs() < 1e-8);
+
+    nco.set_frequency(-0.3f64);
+    assert!((nco.frequency()-(2f64*core::f64::consts::PI-0.3f64)).abs() < 1e-8);
+    let signal = nco.generate(2);
+    assert!((signal[0].re-1f64.cos()).abs() < 1e-8);
+    assert!((signal[1].im-0.7f64.sin()).abs() < 1e-8);
+
+    // A constant frequency offset is the same as retuning.
+    let mut fm = Nco::<f64>::new(0.1f64, 0f64);
+    let mut tuned = Nco::<f64>::new(0.25f64, 0f64);
+    let offsets = Vec::new_from_vec(vec![0.15f64; 64]);
+    assert!(max_error(&fm.generate_fm(&offsets), &tuned.generate(64)) < 1e-12);
+}
+
+#[test]
+fn lut_spurious_free_dynamic_range() {
+    let omega = 0.0123f32;
+    let mut lut = Nco::<f32>::new_with_lut(omega, 0f32, 12);
+    let mut exact = Nco::<f64>::new(omega as f64, 0f64);
+
+    let lut_signal = lut.generate(4096);
+    let exact_signal = exact.generate(4096);
+    // Half a table step of phase error.
+    for idx in 0..4096
+    {
+        assert!((lut_signal[idx].re as f64-exact_signal[idx].re).abs() < core::f64::consts::PI/4096f64);
+        assert!((lut_signal[idx].im as f64-exact_signal[idx].im).abs() < core::f64::consts::PI/4096f64);
+    }
+}
+
+#[test]
+fn up_and_down_conversion() {
+    let baseband = Vec::<Cartesian<f64>>::wgn_with_rng(512, 1f64, &mut Prng::new(5));
+
+    let mut signal = baseband.clone();
+    Nco::<f64>::new(0.7f64, 0f64).mix(&mut signal);
+    Nco::<f64>::new(-0.7f64, 0f64).mix(&mut signal);
+    assert!(max_error(&signal, &baseband) < 1e-9);
+
+    // The up-converted spectrum is the baseband spectrum, shifted by 16 of 512 bins.
+    let mut shifted = baseband.clone();
+    Nco::<f64>::new(2f64*core::f64::consts::PI*16f64/512f64, 0f64).mix(&mut shifted);
+    let mut spectrum = baseband.clone();
+    spectrum.fft();
+    shifted.fft();
+    for k in 0..512
+    {
+        let shifted_bin = shifted[(k+16)%512];
+        assert!((shifted_bin.re-spectrum[k].re).abs() < 1e-9 && (shifted_bin.im-spectrum[k].im).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn phase_words_match_radians() {
+    let mut rng = Prng::new(7);
+    let mut offsets = Vec::<f64>::new_with_capacity(256);
+    let mut words = std::vec::Vec::<u32>::with_capacity(256);
+    for _ in 0..256
+    {
+        let offset = 2f64*rng.uniform()-1f64;
+        offsets.push_back(offset);
+        words.push(Nco::<f64>::phase_word(offset));
+    }
+
+    let mut fm = Nco::<f64>::new(0.3f64, 0.2f64);
+    let mut fm_words = fm.clone();
+    assert_eq!(fm.generate_fm(&offsets).to_alloc_vec(), fm_words.generate_fm_words(&words).to_alloc_vec());
+    assert_eq!(fm.phase(), fm_words.phase());
+
+    let mut pm = Nco::<f64>::new_with_lut(0.3f64, 0.2f64, 10);
+    let mut pm_words = pm.clone();
+    assert_eq!(pm.generate_pm(&offsets).to_alloc_vec(), pm_words.generate_pm_words(&words).to_alloc_vec());
+}
@@ -0,0 +1,123 @@
+use ndsp::*;
+use mixed_num::*;
+
+#[test]
+fn real_noise_is_white_and_gaussian() {
+    let mut rng = Prng::new(7);
+    let noise = Vec::<f64>::wgn_with_rng(100000, 0.25f64, &mut rng);
+
+    assert!(noise.mean().abs() < 0.005);
+    assert!((noise.variance(0)-0.25f64).abs() < 0.005);
+    assert!(noise.skewness().abs() < 0.05);
+    assert!(noise.kurtosis().abs() < 0.05);
+
+    let (lags, r) = noise.autocorr(Some(8), XcorrScaling::Coefficient);
+    for idx in 0..lags.len()
+    {
+        if lags[idx] != 0
+        {
+            assert!(r[idx].abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn complex_noise_is_circularly_symmetric() {
+    let mut rng = Prng::new(8);
+    let noise = Vec::<Cartesian<f32>>::wgn_with_rng(100000, 4f32, &mut rng);
+
+    assert!((noise.variance(0)-4f32).abs() < 0.05);
+    assert!((noise.re().variance(0)-2f32).abs() < 0.05);
+    assert!((noise.im().variance(0)-2f32).abs() < 0.05);
+
+    // The pseudo-covariance E[x²] vanishes.
+    let mut pseudo_re = 0f64;
+    let mut pseudo_im = 0f64;
+    for idx in 0..noise.len()
+    {
+        let (re, im) = (noise[idx].re as f64, noise[idx].im as f64);
+        pseudo_re += re*re - im*im;
+        pseudo_im += 2f64*re*im;
+    }
+    assert!(pseudo_re.abs()/(noise.len() as f64) < 0.05);
+    assert!(pseudo_im.abs()/(noise.len() as f64) < 0.05);
+}
+
+#[test]
+fn awgn_at_snr() {
+    let signal = Vec::<Cartesian<f64>>::osc(0.2f64, 0f64, 50000);
+    let mut rng = Prng::new(9);
+
+    for snr_db in [-10f64, 0f64, 20f64]
+    {
+        let mut noisy = signal.clone();
+        noisy.add_awgn_with_rng(snr_db, &mut rng);
+
+        let noise = &noisy - &signal;
+        let measured_snr_db = 10f64*(1f64/noise.rms().powi(2)).log10();
+        assert!((measured_snr_db-snr_db).abs() < 0.1, "{} dB measured as {} dB", snr_db, measured_snr_db);
+    }
+}
+
+#[test]
+fn default_seed_is_reproducible() {
+    let first = Vec::<f64>::wgn(32, 1f64);
+    let second = Vec::<f64>::wgn(32, 1f64);
+    assert_eq!(first, second);
+
+    let mut rng = Prng::new(DEFAULT_SEED);
+    assert_eq!(first, Vec::<f64>::wgn_with_rng(32, 1f64, &mut rng));
+}
+
+/// The mean one-sided PSD on `lower..upper` in cycles per sample, averaged over a Welch estimate.
+fn band_psd( signal: &Vec<f64>, lower: f64, upper: f64 ) -> f64
+{
+    let window = Vec::<f64>::hann(1024);
+    let (frequencies, psd) = signal.welch(&window, 512, 1f64, PsdScaling::Density, PsdSides::OneSided);
+
+    let mut sum = 0f64;
+    let mut count = 0;
+    for idx in 0..frequencies.len()
+    {
+        if (lower..upper).contains(&frequencies[idx])
+        {
+            sum += psd[idx];
+            count += 1;
+        }
+    }
+    sum/(count as f64)
+}
+
+#[test]
+fn colored_noise_slopes() {
+    let mut rng = Prng::new(10);
+    let pink = Vec::<f64>::pink_noise(1 << 18, 1f64, &mut rng);
+    let brown = Vec::<f64>::brown_noise(1 << 18, 1f64, &mut rng);
+    let white = Vec::<f64>::wgn_with_rng(1 << 18, 1f64, &mut rng);
+
+    // The power of a realization varies, as it is concentrated in the few lowest bins.
+    assert!((pink.variance(0)-1f64).abs() < 0.15);
+
+    // The change in dB per octave, between the bands of two octaves.
+    let slope_db = |signal: &Vec<f64>| 10f64*(band_psd(signal, 0.1f64, 0.2f64)/band_psd(signal, 0.025f64, 0.05f64)).log10()/2f64;
+    assert!((slope_db(&pink)+3.01f64).abs() < 0.3, "pink slope {}", slope_db(&pink));
+    assert!((slope_db(&brown)+6.02f64).abs() < 0.3, "brown slope {}", slope_db(&brown));
+    assert!(slope_db(&white).abs() < 0.3, "white slope {}", slope_db(&white));
+}
+
+#[test]
+fn shaped_noise_follows_the_mask() {
+    let mut rng = Prng::new(11);
+    // 20 dB more power below 0.2 cycles per sample than above 0.3.
+    let mask = Vec::new_from_vec(vec![100f64, 100f64, 100f64, 1f64, 1f64, 1f64]);
+    let noise = Vec::<f64>::shaped_noise(1 << 17, &mask, 2f64, &mut rng);
+
+    assert!((noise.variance(0)-2f64).abs() < 0.05);
+    let ratio_db = 10f64*(band_psd(&noise, 0.02f64, 0.18f64)/band_psd(&noise, 0.32f64, 0.48f64)).log10();
+    assert!((ratio_db-20f64).abs() < 0.5, "ratio {} dB", ratio_db);
+
+    // The same generator state gives the same noise.
+    let first = Vec::<Cartesian<f64>>::pink_noise(256, 1f64, &mut Prng::new(12));
+    let second = Vec::<Cartesian<f64>>::pink_noise(256, 1f64, &mut Prng::new(12));
+    assert_eq!(first, second);
+}
@@ -0,0 +1,129 @@
+use ndsp::*;
+
+/// Frequency response at `f` cycles per sample, relative to the linear phase of the center tap.
+fn response( taps: &Vec<f64>, f: f64 ) -> (f64, f64)
+{
+    let center = (taps.len()-1) as f64/2.0;
+    let mut re = 0f64;
+    let mut im = 0f64;
+    for n in 0..taps.len()
+    {
+        let phase = -2.0*std::f64::consts::PI*f*(n as f64-center);
+        re += taps[n]*phase.cos();
+        im += taps[n]*phase.sin();
+    }
+    (re, im)
+}
+
+/// The maximum deviation of the real response from `gain` over `[lower, upper]`.
+fn max_deviation( taps: &Vec<f64>, lower: f64, upper: f64, gain: f64 ) -> f64
+{
+    let mut max = 0f64;
+    for k in 0..=500
+    {
+        let f = lower + (upper-lower)*k as f64/500.0;
+        max = max.max((response(taps, f).0-gain).abs());
+    }
+    max
+}
+
+#[test]
+fn remez_lowpass_is_equiripple()
+{
+    // Type I and type II.
+    for len in [41usize, 42]
+    {
+        let taps = Vec::<f64>::remez(len, &[0.0, 0.1, 0.15, 0.5], &[1.0, 0.0], &[1.0, 5.0], RemezKind::Bandpass);
+        let pass = max_deviation(&taps, 0.0, 0.1, 1.0);
+        let stop = max_deviation(&taps, 0.15, 0.5, 0.0);
+
+        assert!( (pass-5.0*stop).abs() < 1e-3*pass, "len = {}, {} {}", len, pass, stop );
+        for n in 0..len
+        {
+            assert!( (taps[n]-taps[len-1-n]).abs() < 1e-12 );
+        }
+    }
+}
+
+#[test]
+fn remez_outperforms_window_design()
+{
+    let len = 51;
+    let taps = Vec::<f64>::remez(len, &[0.0, 0.2, 0.25, 0.5], &[1.0, 0.0], &[1.0, 1.0], RemezKind::Bandpass);
+    let windowed = Vec::fir_windowed_sinc(FilterBand::Lowpass(0.225f64), &Vec::<f64>::hamming(len));
+
+    let equiripple = max_deviation(&taps, 0.25, 0.5, 0.0);
+    assert!( equiripple < max_deviation(&windowed, 0.25, 0.5, 0.0) );
+    assert!( max_deviation(&taps, 0.0, 0.2, 1.0) < 1.01*equiripple );
+}
+
+#[test]
+fn remez_multiband()
+{
+    let taps = Vec::<f64>::remez(63, &[0.0, 0.08, 0.12, 0.2, 0.24, 0.35, 0.4, 0.5], &[0.0, 1.0, 0.0, 0.5], &[1.0, 1.0, 1.0, 1.0], RemezKind::Bandpass);
+    let stop_low  = max_deviation(&taps, 0.0, 0.08, 0.0);
+    let pass      = max_deviation(&taps, 0.12, 0.2, 1.0);
+    let stop_mid  = max_deviation(&taps, 0.24, 0.35, 0.0);
+    let pass_half = max_deviation(&taps, 0.4, 0.5, 0.5);
+
+    for deviation in [stop_low, pass, stop_mid, pass_half]
+    {
+        assert!( deviation < 0.05 );
+        assert!( (deviation-pass).abs() < 3e-2*pass );
+    }
+}
+
+#[test]
+fn remez_differentiator()
+{
+    // Type III and type IV.
+    for len in [31usize, 32]
+    {
+        let taps = Vec::<f64>::remez(len, &[0.0, 0.4], &[1.0], &[1.0], RemezKind::Differentiator);
+        for n in 0..len
+        {
+            assert!( (taps[n]+taps[len-1-n]).abs() < 1e-12 );
+        }
+
+        // The response relative to the linear phase is j2πf.
+        for k in 1..40
+        {
+            let f = 0.01*k as f64;
+            let (re, im) = response(&taps, f);
+            assert!( re.abs() < 1e-9 );
+            assert!( (im-2.0*std::f64::consts::PI*f).abs() < 1e-3*2.0*std::f64::consts::PI*f, "len = {}, f = {}", len, f );
+        }
+    }
+}
+
+#[test]
+fn remez_hilbert_transformer()
+{
+    let len = 61;
+    let taps = Vec::<f64>::remez(len, &[0.03, 0.47], &[1.0], &[1.0], RemezKind::Hilbert);
+
+    // The Hilbert transform of a cosine is a sine, delayed by the filter.
+    let f = 0.13;
+    let delay = (len-1)/2;
+    let mut filter = Fir::<f64, f64>::new(taps.clone());
+    let output = filter.process(&Vec::new_from_vec((0..400).map(|n| (2.0*std::f64::consts::PI*f*n as f64).cos()).collect()));
+    for n in len..400
+    {
+        let expected = (2.0*std::f64::consts::PI*f*(n-delay) as f64).sin();
+        assert!( (output[n]-expected).abs() < 2e-3, "n = {}", n );
+    }
+
+    // Every other tap of a type III Hilbert transformer vanishes.
+    for n in (0..len).step_by(2)
+    {
+        assert!( taps[n].abs() < 1e-4 );
+    }
+}
+
+#[test]
+#[should_panic]
+fn remez_without_transition_bands_panics()
+{
+    // Alternating bands without transitions have too few extremal frequencies.
+    Vec::<f64>::remez( 101, &[0f64, 0.1, 0.1, 0.2, 0.2, 0.3, 0.3, 0.4, 0.4, 0.5], &[1f64, 0f64, 1f64, 0f64, 1f64], &[1f64; 5], RemezKind::Bandpass );
+}
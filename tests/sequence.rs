@@ -0,0 +1,145 @@
+use ndsp::*;
+use mixed_num::*;
+
+/// The periodic cross-correlation `r(k) = Σ a(n+k)b*(n)`, computed with the FFT.
+fn periodic_xcorr( a: &Vec<Cartesian<f64>>, b: &Vec<Cartesian<f64>> ) -> Vec<Cartesian<f64>>
+{
+    let len = a.len();
+    let mut a_spectrum = a.clone();
+    let mut b_spectrum = b.clone();
+    a_spectrum.fft();
+    b_spectrum.fft();
+
+    // The forward FFT is scaled by 1/N and the inverse is not, so the product is scaled back by N.
+    let mut r = Vec::<Cartesian<f64>>::new_with_capacity(len);
+    for k in 0..len
+    {
+        let (x, y) = (a_spectrum[k], b_spectrum[k]);
+        r.push_back(Cartesian::new((x.re*y.re + x.im*y.im)*(len as f64), (x.im*y.re - x.re*y.im)*(len as f64)));
+    }
+    r.ifft();
+    r
+}
+
+/// The distinct rounded real values of a correlation, asserting that it is real.
+fn real_values( r: &Vec<Cartesian<f64>>, skip_zero_lag: bool ) -> std::collections::BTreeSet<i64>
+{
+    let mut values = std::collections::BTreeSet::new();
+    for k in (if skip_zero_lag { 1 } else { 0 })..r.len()
+    {
+        assert!(r[k].im.abs() < 1e-6);
+        assert!((r[k].re-r[k].re.round()).abs() < 1e-6);
+        values.insert(r[k].re.round() as i64);
+    }
+    values
+}
+
+#[test]
+fn lfsr_periods_are_maximal() {
+    for degree in 2..=16
+    {
+        for kind in [LfsrKind::Fibonacci, LfsrKind::Galois]
+        {
+            let mut lfsr = Lfsr::new_maximal(degree, kind);
+            let period = (1usize << degree) - 1;
+            for clock in 1..=period
+            {
+                lfsr.next_bit();
+                assert_eq!(lfsr.state() == 1, clock == period, "degree {} {:?} at clock {}", degree, kind, clock);
+            }
+        }
+    }
+
+    // Both structures give the same sequence, up to a shift.
+    let fibonacci = Vec::<Cartesian<f64>>::from_bits(&Lfsr::new_maximal(7, LfsrKind::Fibonacci).generate(127));
+    let galois = Vec::<Cartesian<f64>>::from_bits(&Lfsr::new_maximal(7, LfsrKind::Galois).generate(127));
+    let r = periodic_xcorr(&fibonacci, &galois);
+    assert!(real_values(&r, false).contains(&127));
+
+    // A reducible polynomial, (x+1)(x²+x+1), does not.
+    let mut lfsr = Lfsr::new(0b1001, 1, LfsrKind::Galois);
+    lfsr.generate(3);
+    assert_eq!(lfsr.state(), 1);
+}
+
+#[test]
+fn m_sequence_autocorrelation_is_two_valued() {
+    for degree in [3, 6, 10]
+    {
+        let m_sequence = Vec::<Cartesian<f64>>::m_sequence(degree);
+        let r = periodic_xcorr(&m_sequence, &m_sequence);
+        assert!((r[0].re-m_sequence.len() as f64).abs() < 1e-6);
+        assert_eq!(real_values(&r, true).into_iter().collect::<std::vec::Vec<i64>>(), vec![-1]);
+    }
+}
+
+#[test]
+fn gold_correlations_are_three_valued() {
+    for (degree, t) in [(5u32, 9i64), (6, 17), (7, 17)]
+    {
+        let allowed = std::collections::BTreeSet::from([-1, -t, t-2]);
+        let family_size = (1usize << degree) + 1;
+        for (first, second) in [(0, 1), (0, 2), (1, 5), (2, 3), (4, family_size-1)]
+        {
+            let a = Vec::<Cartesian<f64>>::gold(degree, first);
+            let b = Vec::<Cartesian<f64>>::gold(degree, second);
+            assert!(real_values(&periodic_xcorr(&a, &b), false).is_subset(&allowed));
+            assert!(real_values(&periodic_xcorr(&a, &a), true).is_subset(&allowed));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn gold_degree_divisible_by_four_panics() {
+    Vec::<f64>::gold(8, 0);
+}
+
+#[test]
+fn kasami_correlations_are_three_valued() {
+    let degree = 8;
+    let s = 17i64;
+    let allowed = std::collections::BTreeSet::from([-1, -s, s-2]);
+    for first in 0..16
+    {
+        let a = Vec::<Cartesian<f64>>::kasami(degree, first);
+        assert!(real_values(&periodic_xcorr(&a, &a), true).is_subset(&allowed));
+        for second in (first+1)..16
+        {
+            let b = Vec::<Cartesian<f64>>::kasami(degree, second);
+            assert!(real_values(&periodic_xcorr(&a, &b), false).is_subset(&allowed));
+        }
+    }
+}
+
+#[test]
+fn zadoff_chu_is_cazac() {
+    let len = 139;
+    let first = Vec::<Cartesian<f64>>::zadoff_chu(len, 25);
+    let second = Vec::<Cartesian<f64>>::zadoff_chu(len, 29);
+
+    let autocorrelation = periodic_xcorr(&first, &first);
+    assert!((autocorrelation[0].re-len as f64).abs() < 1e-9);
+    for k in 0..len
+    {
+        assert!((first[k].re.powi(2) + first[k].im.powi(2) - 1f64).abs() < 1e-12);
+        if k != 0
+        {
+            assert!(autocorrelation[k].re.abs() < 1e-9 && autocorrelation[k].im.abs() < 1e-9);
+        }
+    }
+
+    // For a prime length the cross-correlation is of constant magnitude √N.
+    let crosscorrelation = periodic_xcorr(&first, &second);
+    for k in 0..len
+    {
+        let magnitude = (crosscorrelation[k].re.powi(2) + crosscorrelation[k].im.powi(2)).sqrt();
+        assert!((magnitude-(len as f64).sqrt()).abs() < 1e-9);
+    }
+
+    // The integer phase does not lose precision for long sequences.
+    let long = Vec::<Cartesian<f64>>::zadoff_chu(1_000_003, 7);
+    let n = 1_000_002f64;
+    let expected = -core::f64::consts::PI*7f64*n*(n+1f64)/1_000_003f64;
+    assert!((long[1_000_002].re-expected.cos()).abs() < 1e-6);
+}
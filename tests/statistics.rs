@@ -0,0 +1,73 @@
+mod common;
+
+use ndsp::*;
+use mixed_num::*;
+
+use common::*;
+
+#[test]
+fn moments_of_a_sinusoid() {
+    let x = Vec::<Cartesian<f64>>::osc(2f64*core::f64::consts::PI/64f64, 0f64, 64*50).re();
+
+    assert!((x.rms()-0.5f64.sqrt()).abs() < 1e-12);
+    assert!((x.variance(0)-0.5f64).abs() < 1e-12);
+    assert!((x.std(0)-0.5f64.sqrt()).abs() < 1e-12);
+    assert!(x.skewness().abs() < 1e-12);
+    assert!((x.kurtosis()+1.5f64).abs() < 1e-12);
+
+    let mut crest_factor = Vec::new_from_vec(vec![x.crest_factor()]);
+    crest_factor.mag2db();
+    assert!((crest_factor[0]-3.0103f64).abs() < 1e-4);
+}
+
+#[test]
+fn moments_of_a_uniform_distribution() {
+    let x = uniform_noise(100000, 1);
+
+    assert!(x.mean().abs() < 0.01);
+    assert!((x.variance(1)-1f64/3f64).abs() < 0.005);
+    assert!(x.skewness().abs() < 0.02);
+    assert!((x.kurtosis()+1.2f64).abs() < 0.02);
+
+    assert!((x.median()).abs() < 0.01);
+    assert!((x.percentile(25f64)+0.5f64).abs() < 0.01);
+    assert!((x.quantile(0.9f64)-0.8f64).abs() < 0.01);
+    assert_eq!(x.quantile(0f64), x.min());
+    assert_eq!(x.quantile(1f64), x.max());
+    assert_eq!(x[x.argmax()], x.max());
+    assert_eq!(x[x.argmin()], x.min());
+}
+
+#[test]
+fn complex_statistics_are_magnitude_based() {
+    let re = uniform_noise(1000, 2);
+    let mut x = Vec::<Cartesian<f64>>::new_with_capacity(re.len());
+    for idx in 0..re.len()
+    {
+        x.push_back(Cartesian::new(re[idx], -0.5f64*re[(idx+1)%re.len()]));
+    }
+
+    let real_part = x.re();
+    let imag_part = x.im();
+    assert!((x.variance(1)-real_part.variance(1)-imag_part.variance(1)).abs() < 1e-12);
+    assert!((x.rms().powi(2)-real_part.rms().powi(2)-imag_part.rms().powi(2)).abs() < 1e-12);
+
+    let mut magnitude = Vec::<f64>::new_with_capacity(x.len());
+    for idx in 0..x.len()
+    {
+        magnitude.push_back((x[idx].re.powi(2)+x[idx].im.powi(2)).sqrt());
+    }
+    assert_eq!(x.argmax(), magnitude.argmax());
+    assert_eq!(x.argmin(), magnitude.argmin());
+    assert!((x.crest_factor()-magnitude.max()/x.rms()).abs() < 1e-12);
+    assert_eq!(x.median(), magnitude.median());
+    assert_eq!(x.percentile(90f64), magnitude.percentile(90f64));
+    assert_eq!(x.skewness(), magnitude.skewness());
+    assert_eq!(x.kurtosis(), magnitude.kurtosis());
+}
+
+#[test]
+#[should_panic]
+fn variance_needs_more_items_than_ddof() {
+    Vec::new_from_vec(vec![1f64]).variance(1);
+}
@@ -0,0 +1,63 @@
+use ndsp::*;
+use mixed_num::Cartesian;
+
+/// Read interleaved little-endian complex32 samples, as stored by e.g. GNU Radio.
+fn read_c32( path: &str, max_len: usize ) -> Vec<Cartesian<f64>>
+{
+    let bytes = std::fs::read(path).expect("file wasn't found.");
+    let len = (bytes.len()/8).min(max_len);
+
+    let mut signal = Vec::<Cartesian<f64>>::new_with_capacity(len);
+    for idx in 0..len
+    {
+        let re = f32::from_le_bytes(bytes[8*idx..8*idx+4].try_into().unwrap());
+        let im = f32::from_le_bytes(bytes[8*idx+4..8*idx+8].try_into().unwrap());
+        signal.push_back(Cartesian::new(re as f64, im as f64));
+    }
+    signal
+}
+
+#[test]
+fn stft_round_trip_iq_capture()
+{
+    let signal = read_c32("./tests/c32_iq.bin", 10_000);
+    let window = Vec::<f64>::hamming(256);
+
+    // Hop and FFT sizes which are not a power of two.
+    for (hop, fft_size) in [(64usize, 256usize), (100, 300)]
+    {
+        let spectrogram = signal.stft(&window, hop, fft_size);
+        assert_eq!( spectrogram.len(), (signal.len()-256)/hop+1 );
+        assert_eq!( spectrogram[0].len(), fft_size );
+
+        let restored = spectrogram.istft(&window, hop);
+        assert_eq!( restored.len(), (spectrogram.len()-1)*hop+256 );
+        for idx in 0..restored.len()
+        {
+            assert!( (restored[idx].re-signal[idx].re).abs() < 1e-9, "hop = {}, idx = {}", hop, idx );
+            assert!( (restored[idx].im-signal[idx].im).abs() < 1e-9, "hop = {}, idx = {}", hop, idx );
+        }
+    }
+}
+
+#[test]
+fn stft_frame_matches_fft()
+{
+    let signal = read_c32("./tests/c32_iq.bin", 512);
+    let window = Vec::<f64>::ones(128);
+
+    let spectrogram = signal.stft(&window, 128, 128);
+
+    let mut frame = Vec::<Cartesian<f64>>::new_with_capacity(128);
+    for idx in 0..128
+    {
+        frame.push_back(signal[256+idx]);
+    }
+    frame.fft();
+
+    for k in 0..128
+    {
+        assert!( (spectrogram[2][k].re-frame[k].re).abs() < 1e-12 );
+        assert!( (spectrogram[2][k].im-frame[k].im).abs() < 1e-12 );
+    }
+}
@@ -0,0 +1,113 @@
+use ndsp::*;
+use mixed_num::*;
+
+/// The instantaneous angular frequency between two consecutive samples of a complex signal.
+fn instantaneous_frequency( signal: &Vec<Cartesian<f64>>, idx: usize ) -> f64
+{
+    let (x, y) = (signal[idx+1], signal[idx]);
+    (x.im*y.re - x.re*y.im).atan2(x.re*y.re + x.im*y.im)
+}
+
+#[test]
+fn chirp_sweeps() {
+    let (start, stop) = (0.1f64, 1.5f64);
+    let len = 2001;
+    let duration = (len-1) as f64;
+
+    for sweep in [ChirpSweep::Linear, ChirpSweep::Quadratic, ChirpSweep::Logarithmic, ChirpSweep::Hyperbolic]
+    {
+        let chirp = Vec::<Cartesian<f64>>::chirp(len, start, stop, sweep, 0f64);
+        for idx in [0, 500, 1000, 1999]
+        {
+            // The frequency between two samples is that of their midpoint.
+            let t = (idx as f64 + 0.5f64)/duration;
+            let expected = match sweep {
+                ChirpSweep::Linear      => start + (stop-start)*t,
+                ChirpSweep::Quadratic   => start + (stop-start)*t*t,
+                ChirpSweep::Logarithmic => start*(stop/start).powf(t),
+                ChirpSweep::Hyperbolic  => start*stop/((start-stop)*t + stop),
+            };
+            let measured = instantaneous_frequency(&chirp, idx);
+            assert!((measured-expected).abs() < 1e-4, "{:?} at {}: {} != {}", sweep, idx, measured, expected);
+        }
+
+        // The real chirp is the real part of the complex chirp.
+        let real = Vec::<f64>::chirp(len, start, stop, sweep, 0.3f64);
+        let complex = Vec::<Cartesian<f64>>::chirp(len, start, stop, sweep, 0.3f64);
+        assert_eq!(real, complex.re());
+    }
+}
+
+#[test]
+#[should_panic]
+fn logarithmic_chirp_through_zero_panics() {
+    Vec::<f64>::chirp(100, -0.5f64, 0.5f64, ChirpSweep::Logarithmic, 0f64);
+}
+
+#[test]
+fn schroeder_phases_lower_the_crest_factor() {
+    let num_tones = 64;
+    let frequencies = Vec::lin_range(0.01f64, 0.01f64*(num_tones as f64), num_tones);
+    let amplitudes = Vec::lin_range(1f64, 0.1f64, num_tones);
+    let len = 2*628;
+
+    let in_phase = Vec::<Cartesian<f64>>::multitone(len, &frequencies, &amplitudes, MultitonePhases::Zero);
+    let schroeder = Vec::<Cartesian<f64>>::multitone(len, &frequencies, &amplitudes, MultitonePhases::Schroeder);
+    assert!(schroeder.crest_factor() < in_phase.crest_factor()/2f64);
+
+    // The phases do not change the power.
+    assert!((schroeder.rms()-in_phase.rms()).abs()/in_phase.rms() < 0.05);
+
+    let phases = Vec::new_from_vec(vec![0.5f64; num_tones]);
+    let given = Vec::<f64>::multitone(8, &frequencies, &amplitudes, MultitonePhases::Given(phases));
+    let mut expected = 0f64;
+    for idx in 0..num_tones
+    {
+        expected += amplitudes[idx]*(frequencies[idx]*3f64 + 0.5f64).cos();
+    }
+    assert!((given[3]-expected).abs() < 1e-9);
+}
+
+#[test]
+fn band_limited_waveforms_do_not_alias() {
+    // A fundamental of 10 bins of a 1024-point FFT, whose harmonics above 51 would alias.
+    let omega = 2f64*core::f64::consts::PI*10f64/1024f64;
+    let waveforms = [Vec::<Cartesian<f64>>::square(1024, omega, 0f64), Vec::<Cartesian<f64>>::sawtooth(1024, omega, 0f64), Vec::<Cartesian<f64>>::triangle(1024, omega, 0f64)];
+
+    for waveform in waveforms
+    {
+        let mut spectrum = waveform.clone();
+        spectrum.fft();
+        for k in 0..1024
+        {
+            let magnitude = (spectrum[k].re.powi(2) + spectrum[k].im.powi(2)).sqrt();
+            if k%10 != 0 || 510 < k
+            {
+                assert!(magnitude < 1e-9, "bin {} has magnitude {}", k, magnitude);
+            }
+        }
+    }
+
+    // The square wave has no even harmonics, and its fundamental is 4/π.
+    let square = Vec::<f64>::square(1024, omega, 0f64);
+    let mut spectrum = Vec::<Cartesian<f64>>::square(1024, omega, 0f64);
+    spectrum.fft();
+    assert!((spectrum[10].im+4f64/core::f64::consts::PI).abs() < 1e-9);
+    assert!(spectrum[20].im.abs() < 1e-9);
+    assert!(square.mean().abs() < 1e-9);
+}
+
+#[test]
+fn pulses() {
+    let impulse = Vec::<Cartesian<f32>>::impulse(5, 2);
+    assert_eq!(impulse.re().to_string(), "[ 0, 0, 1, 0, 0 ]");
+    assert_eq!(impulse.im(), Vec::<f32>::zeros(5));
+    assert_eq!(Vec::<f64>::impulse(3, 3), Vec::<f64>::zeros(3));
+
+    assert_eq!(Vec::<f64>::step(5, 0), Vec::<f64>::ones(5));
+    assert_eq!(Vec::<Cartesian<f64>>::step(3, 2).re().to_string(), "[ 0, 0, 1 ]");
+
+    let train = Vec::<f64>::pulse_train(1000, 10, 3);
+    assert!((train.mean()-0.3f64).abs() < 1e-12);
+    assert_eq!(Vec::<f64>::pulse_train(4, 2, 2), Vec::<f64>::ones(4));
+}
@@ -0,0 +1,67 @@
+use ndsp::*;
+
+fn test_signal( n: usize ) -> Vec<f64>
+{
+    let mut signal = Vec::<f64>::new_with_capacity(n);
+    for idx in 0..n
+    {
+        let x = idx as f64;
+        signal.push_back((0.3*x).sin() + 0.5*(1.7*x).cos() + 0.1*(x%7.0));
+    }
+    signal
+}
+
+#[test]
+fn periodogram_density_satisfies_parseval()
+{
+    let sample_rate = 48e3;
+    for n in [64usize, 63]
+    {
+        let signal = test_signal(n);
+        let mut power = 0f64;
+        for idx in 0..n
+        {
+            power += signal[idx]*signal[idx]/(n as f64);
+        }
+
+        for sides in [PsdSides::OneSided, PsdSides::TwoSided]
+        {
+            let (_frequencies, psd) = signal.periodogram(&Vec::<f64>::ones(n), sample_rate, PsdScaling::Density, sides);
+            let mut integral = 0f64;
+            for k in 0..psd.len()
+            {
+                integral += psd[k]*sample_rate/(n as f64);
+            }
+            assert!( (integral-power).abs() < 1e-9, "n = {}, sides = {:?}", n, sides );
+        }
+    }
+}
+
+#[test]
+fn welch_real_and_complex_signals_agree()
+{
+    let signal = test_signal(1000);
+    let complex_signal = Vec::<mixed_num::Cartesian<f64>>::new_from_real(signal.clone());
+    let window = Vec::<f64>::hamming(100);
+
+    for scaling in [PsdScaling::Density, PsdScaling::Spectrum]
+    {
+        let (frequencies, psd) = signal.welch(&window, 60, 2f64, scaling, PsdSides::TwoSided);
+        let (complex_frequencies, complex_psd) = complex_signal.welch(&window, 60, 2f64, scaling, PsdSides::TwoSided);
+
+        assert_eq!( frequencies, complex_frequencies );
+        assert_eq!( psd.len(), 100 );
+        for k in 0..psd.len()
+        {
+            assert!( (psd[k]-complex_psd[k]).abs() < 1e-12 );
+        }
+
+        // The one-sided estimate folds the negative frequencies onto the positive ones.
+        let (one_sided_frequencies, one_sided) = signal.welch(&window, 60, 2f64, scaling, PsdSides::OneSided);
+        assert_eq!( one_sided.len(), 51 );
+        assert_eq!( one_sided_frequencies[50], 1f64 );
+        assert!( (one_sided[0]-psd[0]).abs() < 1e-12 );
+        assert!( (one_sided[10]-2.0*psd[10]).abs() < 1e-12 );
+        assert!( (one_sided[50]-psd[50]).abs() < 1e-12 );
+    }
+}
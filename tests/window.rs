@@ -0,0 +1,222 @@
+use ndsp::*;
+
+fn assert_close( a: &Vec<f64>, b: &Vec<f64>, tolerance: f64 )
+{
+    assert_eq!( a.len(), b.len() );
+    for idx in 0..a.len()
+    {
+        assert!( (a[idx]-b[idx]).abs() < tolerance, "{} != {} at {}", a[idx], b[idx], idx );
+    }
+}
+
+/// The periodic window is the symmetric window one sample longer, with the last sample dropped.
+fn assert_periodic( periodic: &Vec<f64>, symmetric: &Vec<f64> )
+{
+    assert_eq!( periodic.len()+1, symmetric.len() );
+    for idx in 0..periodic.len()
+    {
+        assert!( (periodic[idx]-symmetric[idx]).abs() < 1e-12 );
+    }
+}
+
+#[test]
+fn periodic_windows_drop_the_last_sample()
+{
+    let len = 33;
+    assert_periodic( &Vec::hann_periodic(len),                 &Vec::hann(len+1) );
+    assert_periodic( &Vec::flat_top_periodic(len),             &Vec::flat_top(len+1) );
+    assert_periodic( &Vec::blackman_harris_periodic(len),      &Vec::blackman_harris(len+1) );
+    assert_periodic( &Vec::kaiser_periodic(len, 6f64),         &Vec::kaiser(len+1, 6f64) );
+    assert_periodic( &Vec::tukey_periodic(len, 0.3f64),        &Vec::tukey(len+1, 0.3f64) );
+    assert_periodic( &Vec::gaussian_periodic(len, 5f64),       &Vec::gaussian(len+1, 5f64) );
+    assert_periodic( &Vec::cosine_sum_periodic(len, &[0.42f64, 0.5f64, 0.08f64]), &Vec::cosine_sum(len+1, &[0.42f64, 0.5f64, 0.08f64]) );
+}
+
+#[test]
+fn symmetric_windows_are_symmetric()
+{
+    for len in [1, 2, 16, 17]
+    {
+        for window in [Vec::hann(len), Vec::flat_top(len), Vec::blackman_harris(len), Vec::kaiser(len, 6f64), Vec::tukey(len, 0.3f64), Vec::gaussian(len, 5f64)]
+        {
+            assert_eq!( window.len(), len );
+            for idx in 0..len
+            {
+                assert!( (window[idx]-window[len-1-idx]).abs() < 1e-12 );
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_length_windows_are_empty()
+{
+    for window in [Vec::hann(0), Vec::flat_top(0), Vec::blackman_harris(0), Vec::kaiser(0, 6f64), Vec::tukey(0, 0.3f64), Vec::gaussian(0, 5f64), Vec::chebyshev(0, 60f64),
+                   Vec::hann_periodic(0), Vec::flat_top_periodic(0), Vec::blackman_harris_periodic(0), Vec::kaiser_periodic(0, 6f64), Vec::tukey_periodic(0, 0.3f64), Vec::gaussian_periodic(0, 5f64), Vec::chebyshev_periodic(0, 60f64)]
+    {
+        assert_eq!( window.len(), 0 );
+    }
+}
+
+#[test]
+fn cosine_sum_generalizes_existing_windows()
+{
+    let len = 128;
+    assert_close( &Vec::cosine_sum(len, &[7938f64/18608f64, 9240f64/18608f64, 1430f64/18608f64]), &Vec::blackman(len), 1e-12 );
+    assert_close( &Vec::cosine_sum(len, &[0.355768f64, 0.487396f64, 0.144232f64, 0.012604f64]), &Vec::nuttall(len), 1e-12 );
+    assert_close( &Vec::tukey(len, 1f64), &Vec::hann(len), 1e-12 );
+    assert_close( &Vec::tukey(len, 0f64), &Vec::ones(len), 1e-12 );
+    assert_close( &Vec::kaiser(len, 0f64), &Vec::ones(len), 1e-12 );
+}
+
+#[test]
+fn flat_top_measures_amplitude_between_bins()
+{
+    // The gain for a tone halfway between DFT bins differs from the gain on a bin by less than 0.02 dB.
+    let len = 256;
+    let window = Vec::<f64>::flat_top_periodic(len);
+
+    let gain = |frequency: f64| {
+        let mut re = 0f64;
+        let mut im = 0f64;
+        for (n, w) in window.to_alloc_vec().iter().enumerate()
+        {
+            re += w*(2f64*std::f64::consts::PI*frequency*(n as f64)).cos();
+            im -= w*(2f64*std::f64::consts::PI*frequency*(n as f64)).sin();
+        }
+        (re*re + im*im).sqrt()
+    };
+
+    let loss_db = 20f64*(gain(0f64)/gain(0.5f64/(len as f64))).log10();
+    assert!( loss_db.abs() < 0.02f64 );
+}
+
+#[test]
+fn chebyshev_sidelobes_are_at_the_attenuation()
+{
+    for (len, attenuation_db) in [(31, 60f64), (32, 100f64)]
+    {
+        let window = Vec::<f64>::chebyshev(len, attenuation_db);
+
+        // The main lobe ends where β cos(πf) = 1.
+        let order = (len-1) as f64;
+        let beta = (10f64.powf(attenuation_db/20f64).acosh()/order).cosh();
+        let main_lobe = (1f64/beta).acos()/std::f64::consts::PI;
+
+        let frequencies = Vec::lin_range( main_lobe, 0.5f64, 2000 );
+        let h = freqz( &window, &Vec::new_from_vec( vec![1f64] ), &frequencies, 1f64 );
+        let peak_db = freqz( &window, &Vec::new_from_vec( vec![1f64] ), &Vec::new_from_vec( vec![0f64] ), 1f64 ).magnitude_db[0];
+
+        let mut highest_sidelobe_db = f64::MIN;
+        for idx in 0..frequencies.len()
+        {
+            highest_sidelobe_db = highest_sidelobe_db.max(h.magnitude_db[idx]-peak_db);
+        }
+        assert!( highest_sidelobe_db < -attenuation_db+1e-6 );
+        assert!( -attenuation_db-0.1f64 < highest_sidelobe_db );
+    }
+}
+
+/// The fraction of the energy of a taper within the half bandwidth `w`.
+fn concentration( taper: &Vec<f64>, w: f64 ) -> f64
+{
+    let mut energy = 0f64;
+    for m in 0..taper.len()
+    {
+        for n in 0..taper.len()
+        {
+            let kernel = if m == n { 2f64*w } else {
+                let d = (m as f64)-(n as f64);
+                (2f64*std::f64::consts::PI*w*d).sin()/(std::f64::consts::PI*d)
+            };
+            energy += taper[m]*taper[n]*kernel;
+        }
+    }
+    energy
+}
+
+#[test]
+fn dpss_tapers_are_orthonormal_and_concentrated()
+{
+    let len = 128;
+    let nw = 4f64;
+    let tapers = Vec::<f64>::dpss(len, nw, 8);
+
+    let mut previous_concentration = 1f64;
+    for k in 0..tapers.len()
+    {
+        for j in 0..tapers.len()
+        {
+            let inner = (&tapers[k]*&tapers[j]).sum();
+            let expected = if j == k { 1f64 } else { 0f64 };
+            assert!( (inner-expected).abs() < 1e-12 );
+        }
+
+        // Alternating even and odd symmetry.
+        let parity = if k%2 == 0 { 1f64 } else { -1f64 };
+        for n in 0..len
+        {
+            assert!( (tapers[k][n]-parity*tapers[k][len-1-n]).abs() < 1e-12 );
+        }
+        // Even tapers have a positive sum, and odd tapers start with a positive lobe.
+        let first = tapers[k].to_alloc_vec().iter().find(|w| 1e-3 < w.abs()).unwrap();
+        assert!( 0f64 < *first );
+        if k%2 == 0
+        {
+            assert!( 0f64 < tapers[k].sum() );
+        }
+
+        let lambda = concentration(&tapers[k], nw/(len as f64));
+        assert!( lambda < previous_concentration+1e-12 );
+        previous_concentration = lambda;
+        if k < 6
+        {
+            assert!( 0.99f64 < lambda );
+        }
+    }
+    assert!( previous_concentration < 0.9f64 );
+}
+
+#[test]
+#[should_panic]
+fn dpss_bandwidth_must_fit_the_length()
+{
+    let _tapers = Vec::<f64>::dpss(16, 8f64, 1);
+}
+
+#[test]
+fn window_metrics_match_harris()
+{
+    let len = 1024;
+
+    let rectangular = Vec::<f64>::ones(len).window_metrics();
+    assert!( (rectangular.coherent_gain-1f64).abs() < 1e-12 );
+    assert!( (rectangular.enbw_bins-1f64).abs() < 1e-12 );
+    assert!( (rectangular.processing_gain_db-10f64*(len as f64).log10()).abs() < 1e-9 );
+    assert!( (rectangular.scalloping_loss_db-3.92f64).abs() < 0.01 );
+    assert!( (rectangular.highest_sidelobe_db+13.26f64).abs() < 0.01 );
+    assert!( (rectangular.bandwidth_3db_bins-0.89f64).abs() < 0.01 );
+    assert!( (rectangular.bandwidth_6db_bins-1.21f64).abs() < 0.01 );
+
+    let hamming = Vec::<f64>::cosine_sum_periodic(len, &[0.54f64, 0.46f64]).window_metrics();
+    assert!( (hamming.coherent_gain-0.54f64).abs() < 1e-12 );
+    assert!( (hamming.enbw_bins-1.36f64).abs() < 0.01 );
+    // Harris tabulates 1.78 dB, while the response halfway between bins is 1.75 dB below the peak.
+    assert!( (hamming.scalloping_loss_db-1.75f64).abs() < 0.01 );
+    assert!( (hamming.highest_sidelobe_db+42.7f64).abs() < 0.1 );
+
+    let blackman_harris = Vec::<f64>::blackman_harris_periodic(len).window_metrics();
+    assert!( (blackman_harris.enbw_bins-2.00f64).abs() < 0.01 );
+    assert!( (blackman_harris.scalloping_loss_db-0.83f64).abs() < 0.01 );
+    assert!( (blackman_harris.highest_sidelobe_db+92f64).abs() < 0.1 );
+}
+
+#[test]
+fn window_metrics_of_designed_windows()
+{
+    let flat_top = Vec::<f64>::flat_top_periodic(256).window_metrics();
+    assert!( flat_top.scalloping_loss_db.abs() < 0.01 );
+
+    let chebyshev = Vec::<f64>::chebyshev(255, 80f64).window_metrics();
+    assert!( (chebyshev.highest_sidelobe_db+80f64).abs() < 0.01 );
+}
@@ -0,0 +1,97 @@
+use ndsp::*;
+use mixed_num::*;
+
+/// A Gaussian pulse centered at `center`.
+fn pulse( n: usize, center: f64, width: f64 ) -> Vec<f64>
+{
+    let mut signal = Vec::<f64>::new_with_capacity(n);
+    for idx in 0..n
+    {
+        let t = (idx as f64 - center)/width;
+        signal.push_back((-0.5*t*t).exp());
+    }
+    signal
+}
+
+#[test]
+fn fractional_delay_estimate() {
+    let reference = pulse(128, 40f64, 4f64);
+
+    for delay in [0f64, 7.3f64, 12.5f64, 20.8f64]
+    {
+        let received = pulse(128, 40f64+delay, 4f64);
+        let (lags, r) = received.xcorr(&reference, Some(32), XcorrScaling::Coefficient);
+
+        let peak = r.argmax_interpolated();
+        let estimate = lags[peak.floor() as usize] as f64 + peak.fract();
+        assert!((estimate-delay).abs() < 0.05, "delay {}, estimate {}", delay, estimate);
+    }
+}
+
+#[test]
+fn scaling_by_definition() {
+    let x = Vec::new_from_vec(vec![1f64, -2f64, 3f64, 0.5f64, 4f64]);
+    let y = Vec::new_from_vec(vec![2f64, 1f64, -1f64]);
+    let n = 5;
+
+    let (lags, raw) = x.xcorr(&y, None, XcorrScaling::None);
+    let (_, biased) = x.xcorr(&y, None, XcorrScaling::Biased);
+    let (_, unbiased) = x.xcorr(&y, None, XcorrScaling::Unbiased);
+    let (_, coefficient) = x.xcorr(&y, None, XcorrScaling::Coefficient);
+    assert_eq!(lags.len(), 2*n-1);
+
+    let energy = (x.energy()*y.energy()).sqrt();
+    for (idx, lag) in lags.to_alloc_vec().iter().enumerate()
+    {
+        // R[k] = Σx[n+k]y[n], with y zero padded to the length of x.
+        let mut expected = 0f64;
+        for m in 0..y.len()
+        {
+            let k = m as isize + lag;
+            if (0..n as isize).contains(&k)
+            {
+                expected += x[k as usize]*y[m];
+            }
+        }
+        assert!((raw[idx]-expected).abs() < 1e-12);
+        assert!((biased[idx]-expected/n as f64).abs() < 1e-12);
+        assert!((unbiased[idx]-expected/(n-lag.unsigned_abs()) as f64).abs() < 1e-12);
+        assert!((coefficient[idx]-expected/energy).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn max_lag_beyond_length_is_zero_filled() {
+    let x = Vec::new_from_vec(vec![1f64, 2f64, 3f64]);
+    let (lags, r) = x.autocorr(Some(4), XcorrScaling::None);
+
+    assert_eq!(lags.to_string(), "[ -4, -3, -2, -1, 0, 1, 2, 3, 4 ]");
+    assert_eq!(r.to_string(), "[ 0, 0, 3, 8, 14, 8, 3, 0, 0 ]");
+
+    let (lags, r) = x.autocorr(Some(0), XcorrScaling::Biased);
+    assert_eq!(lags.len(), 1);
+    assert!((r[0]-14f64/3f64).abs() < 1e-12);
+}
+
+#[test]
+fn complex_autocorrelation_is_hermitian() {
+    let mut x = Vec::<Cartesian<f64>>::new_with_capacity(20);
+    for idx in 0..20
+    {
+        let t = idx as f64;
+        x.push_back(Cartesian::new((0.4*t).cos() + 0.1*t, (0.9*t).sin() - 0.05*t));
+    }
+
+    let (lags, r) = x.autocorr(None, XcorrScaling::Coefficient);
+    let zero = (lags.len()-1)/2;
+    assert_eq!(lags[zero], 0);
+    assert!((r[zero].re-1f64).abs() < 1e-12);
+    assert!(r[zero].im.abs() < 1e-12);
+
+    for k in 1..20
+    {
+        assert!((r[zero+k].re-r[zero-k].re).abs() < 1e-12);
+        assert!((r[zero+k].im+r[zero-k].im).abs() < 1e-12);
+        assert!(r[zero+k].re.powi(2)+r[zero+k].im.powi(2) <= 1f64);
+    }
+}